@@ -4,8 +4,6 @@ use log4rs::config::{Appender, Root};
 use log4rs::Config;
 use std::sync::Once;
 use std::time::Duration;
-use ton_lib::block_tlb::{Coins, CommonMsgInfoInt, Msg};
-use ton_lib::block_tlb::{CommonMsgInfo, CurrencyCollection};
 use ton_lib::clients::tl_client::tl::client::TLClientTrait;
 use ton_lib::clients::tl_client::TLClient;
 use ton_lib::clients::tl_client::TLClientConfig;
@@ -16,16 +14,15 @@ use ton_lib::contracts::ton_wallet::TonWalletContract;
 use ton_lib::sys_utils::sys_tonlib_set_verbosity_level;
 use ton_lib::wallet::Mnemonic;
 use ton_lib::wallet::TonWallet;
+use ton_lib::wallet::TransferBuilder;
 use ton_lib::wallet::WalletVersion;
-use ton_lib_core::cell::TonCell;
 use ton_lib_core::traits::tlb::TLB;
-use ton_lib_core::types::tlb_core::{MsgAddress, TLBEitherRef};
 
 // Transaction: https://testnet.tonviewer.com/transaction/3771a86dd5c5238ac93e7f125817379c7a9d1321c79b27ac5e6b2b2d34749af1
 // How external and internal messages work: https://docs.ton.org/v3/guidelines/smart-contracts/howto/wallet#-external-and-internal-messages
 /* Plan:
     - Ton transfer (We will use wallet v4)
-        - make an internal message with empty sell. It will signal that it is transfer
+        - make an internal message with text comment using TransferBuilder
         - make an correct external message, and put there an internal message
         - send message to ton blockchain
 */
@@ -78,22 +75,10 @@ async fn main() -> anyhow::Result<()> {
     let ctr_cli = ContractClient::new(ctr_config, provider)?;
 
     // ---------- Building transfer_msg ----------
-    let transfer_msg = Msg {
-        info: CommonMsgInfo::Int(CommonMsgInfoInt {
-            ihr_disabled: false,
-            bounce: false,
-            bounced: false,
-            src: MsgAddress::NONE,
-            dst: MsgAddress::Int(wallet.address.to_msg_address_int()),
-            value: CurrencyCollection::new(50010u128),
-            ihr_fee: Coins::ZERO,
-            fwd_fee: Coins::ZERO,
-            created_lt: 0,
-            created_at: 0,
-        }),
-        init: None,
-        body: TLBEitherRef::new(TonCell::EMPTY),
-    };
+    let transfer = TransferBuilder::new(wallet.address.clone(), 50010u32)
+        .with_bounce(false)
+        .with_comment("ton_lib transfer")?
+        .build()?;
 
     let expired_at_time = std::time::SystemTime::now() + Duration::from_secs(600);
     let expire_at = expired_at_time.duration_since(std::time::UNIX_EPOCH)?.as_secs() as u32;
@@ -102,7 +87,7 @@ async fn main() -> anyhow::Result<()> {
     let wallet_ctr = TonWalletContract::new(&ctr_cli, wallet.address.clone(), None).await?;
     let seqno = wallet_ctr.seqno().await?;
 
-    let ext_in_msg = wallet.create_ext_in_msg(vec![transfer_msg.to_cell_ref()?], seqno, expire_at, false)?;
+    let ext_in_msg = wallet.create_ext_in_msg_with_transfers(vec![transfer], seqno, expire_at, false)?;
     // Transaction: https://testnet.tonviewer.com/transaction/3771a86dd5c5238ac93e7f125817379c7a9d1321c79b27ac5e6b2b2d34749af1
     let _msg_hash = tl_client.send_msg(ext_in_msg.to_boc()?).await?;
 
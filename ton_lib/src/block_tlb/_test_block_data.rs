@@ -5,3 +5,11 @@ pub(crate) static CONFIG_BOC_HEX: &str = include_str!("../../resources/tests/con
 // https://explorer.toncoin.org/search?workchain=0&shard=6000000000000000&seqno=52111590
 pub(crate) static SHARD_BLOCK_BOC_HEX: &str =
     include_str!("../../resources/tests/shard_block_6000000000000000_52111590.hex");
+// wallet account and external message with jetton transfer, processed at WALLET_EXT_IN_MSG_UTIME / WALLET_EXT_IN_MSG_LT
+pub(crate) static WALLET_SHARD_ACCOUNT_BOC_HEX: &str = include_str!("../../resources/tests/wallet_shard_account.hex");
+#[cfg(feature = "tonlibjson")] // used by emulator tests only
+pub(crate) static WALLET_EXT_IN_MSG_BOC_HEX: &str = include_str!("../../resources/tests/wallet_ext_in_msg.hex");
+#[cfg(feature = "tonlibjson")]
+pub(crate) const WALLET_EXT_IN_MSG_UTIME: u32 = 1738323935;
+#[cfg(feature = "tonlibjson")]
+pub(crate) const WALLET_EXT_IN_MSG_LT: u64 = 53483578000001;
@@ -1,7 +1,11 @@
 use crate::block_tlb::{Coins, CurrencyCollection, StateInit};
 use crate::tlb_adapters::TLBRef;
-use ton_lib_core::cell::{TonCellRef, TonHash};
+use ton_lib_core::cell::{TonCellRef, TonCellUtils, TonHash};
+use ton_lib_core::error::TLCoreError;
+use ton_lib_core::traits::contract_provider::ContractState;
+use ton_lib_core::traits::tlb::TLB;
 use ton_lib_core::types::tlb_core::{MsgAddressInt, VarLenBytes};
+use ton_lib_core::types::{TonAddress, TxIdLTHash};
 use ton_lib_core::TLBDerive;
 
 #[derive(Default, Debug, Clone, PartialEq, TLBDerive)]
@@ -124,8 +128,77 @@ impl ShardAccount {
         last_tx_hash: TonHash::ZERO,
         last_tx_lt: 0,
     };
+
+    /// ContractState doesn't contain storage stats, so they're calculated from AccountStorage
+    ///
+    /// `last_paid` - set to current time to skip storage fees for the first tx
+    pub fn from_contract_state(state: &ContractState, last_paid: u32) -> Result<Self, TLCoreError> {
+        let state_init = match (&state.code_boc, &state.data_boc) {
+            (None, None) => None,
+            (code, data) => Some(StateInit {
+                split_depth: None,
+                tick_tock: None,
+                code: code.as_deref().map(TonCellRef::from_boc).transpose()?,
+                data: data.as_deref().map(TonCellRef::from_boc).transpose()?,
+                library: Default::default(),
+            }),
+        };
+        let account_state = match (state_init, &state.frozen_hash) {
+            (Some(state_init), _) => AccountState::Active(AccountStateActive { state_init }),
+            (None, Some(frozen_hash)) => AccountState::Frozen(AccountStateFrozen {
+                state_hash: frozen_hash.clone(),
+            }),
+            (None, None) if state.balance == 0 && state.last_tx_id.lt == 0 => return Ok(Self::NON_EXIST),
+            (None, None) => AccountState::Uninit(AccountStateUninit),
+        };
+        let storage = AccountStorage {
+            last_tx_lt: state.last_tx_id.lt as u64,
+            balance: CurrencyCollection {
+                grams: Coins::from_signed(state.balance)?,
+                other: Default::default(),
+            },
+            state: account_state,
+        };
+        let (cells, bits) = TonCellUtils::storage_stat([&storage.to_cell()?])?;
+        let account = Account {
+            addr: state.address.to_msg_address_int(),
+            storage_stat: StorageInfo {
+                used: StorageUsed {
+                    cells: VarLenBytes::new(cells, var_len_bits(cells)),
+                    bits: VarLenBytes::new(bits, var_len_bits(bits)),
+                },
+                storage_extra: MaybeStorageExtraInfo::None(StorageExtraInfoNone),
+                last_paid,
+                due_payment: None,
+            },
+            storage,
+        };
+        Ok(Self {
+            account: MaybeAccount::Account(Box::new(account)),
+            last_tx_hash: state.last_tx_id.hash.clone(),
+            last_tx_lt: state.last_tx_id.lt as u64,
+        })
+    }
 }
 
+impl ShardAccount {
+    /// Reverse to `from_contract_state`, mc_seqno & sync_utime are not set
+    pub fn to_contract_state(&self, address: TonAddress) -> Result<ContractState, TLCoreError> {
+        Ok(ContractState {
+            mc_seqno: None,
+            address,
+            last_tx_id: TxIdLTHash::new(self.last_tx_lt as i64, self.last_tx_hash.clone()),
+            code_boc: self.account.get_code().map(|x| x.to_boc()).transpose()?,
+            data_boc: self.account.get_data().map(|x| x.to_boc()).transpose()?,
+            frozen_hash: self.account.as_frozen().map(|x| x.state_hash.clone()),
+            balance: self.account.get_balance().map(|x| x.to_u64()).transpose()?.unwrap_or(0) as i64,
+            sync_utime: None,
+        })
+    }
+}
+
+fn var_len_bits(value: u64) -> usize { ((64 - value.leading_zeros()).div_ceil(8) * 8) as usize }
+
 impl Default for AccountStatus {
     fn default() -> Self { AccountStatus::NonExist(AccountStatusNotExist) }
 }
@@ -152,6 +225,7 @@ impl MaybeAccount {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::block_tlb::_test_block_data::WALLET_SHARD_ACCOUNT_BOC_HEX;
     use crate::block_tlb::{SimpleLib, TickTock};
     use std::collections::HashMap;
 
@@ -160,6 +234,7 @@ mod tests {
     use ton_lib_core::cell::TonCell;
     use ton_lib_core::traits::tlb::TLB;
     use ton_lib_core::types::tlb_core::{MsgAddressIntStd, VarLen};
+    use ton_lib_core::types::{TonAddress, TxIdLTHash};
 
     #[test]
     fn test_block_tlb_account_some() -> anyhow::Result<()> {
@@ -196,6 +271,61 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_shard_account_from_contract_state() -> anyhow::Result<()> {
+        let shard_account = ShardAccount::from_boc_hex(WALLET_SHARD_ACCOUNT_BOC_HEX)?;
+        let MaybeAccount::Account(account) = &shard_account.account else {
+            panic!("Expected account")
+        };
+        let state = ContractState {
+            mc_seqno: None,
+            address: TonAddress::from_msg_address(account.addr.clone())?,
+            last_tx_id: TxIdLTHash::new(shard_account.last_tx_lt as i64, shard_account.last_tx_hash.clone()),
+            code_boc: Some(shard_account.account.get_code().unwrap().to_boc()?),
+            data_boc: Some(shard_account.account.get_data().unwrap().to_boc()?),
+            frozen_hash: None,
+            balance: shard_account.account.get_balance().unwrap().to_u64()? as i64,
+            sync_utime: None,
+        };
+        let restored = ShardAccount::from_contract_state(&state, account.storage_stat.last_paid)?;
+        assert_eq!(restored.last_tx_hash, shard_account.last_tx_hash);
+        let MaybeAccount::Account(restored_account) = &restored.account else {
+            panic!("Expected account")
+        };
+        assert_eq!(restored_account.addr, account.addr);
+        assert_eq!(restored_account.storage.balance, account.storage.balance);
+        assert_eq!(restored_account.storage.state, account.storage.state);
+        assert_eq!(restored_account.storage_stat.used, account.storage_stat.used);
+
+        let non_exist = ContractState {
+            last_tx_id: TxIdLTHash::new(0, TonHash::ZERO),
+            code_boc: None,
+            data_boc: None,
+            balance: 0,
+            ..state
+        };
+        assert_eq!(ShardAccount::from_contract_state(&non_exist, 0)?, ShardAccount::NON_EXIST);
+        Ok(())
+    }
+
+    #[test]
+    fn test_shard_account_to_contract_state() -> anyhow::Result<()> {
+        let shard_account = ShardAccount::from_boc_hex(WALLET_SHARD_ACCOUNT_BOC_HEX)?;
+        let MaybeAccount::Account(account) = &shard_account.account else {
+            panic!("Expected account")
+        };
+        let address = TonAddress::from_msg_address(account.addr.clone())?;
+        let state = shard_account.to_contract_state(address.clone())?;
+        assert_eq!(state.last_tx_id.lt as u64, shard_account.last_tx_lt);
+        assert_eq!(state.code_boc, Some(shard_account.account.get_code().unwrap().to_boc()?));
+        assert_eq!(state.balance as u64, shard_account.account.get_balance().unwrap().to_u64()?);
+        assert_eq!(ShardAccount::from_contract_state(&state, 0)?.to_contract_state(address.clone())?, state);
+
+        let non_exist = ShardAccount::NON_EXIST.to_contract_state(address)?;
+        assert_eq!((non_exist.code_boc, non_exist.balance, non_exist.last_tx_id.lt), (None, 0, 0));
+        Ok(())
+    }
+
     #[test]
     fn test_block_tlb_shard_account_tick_tock() -> anyhow::Result<()> {
         let boc_hex = "b5ee9c7201020d0100017500015099602ce40fd84286bddb06f8bcc9fceb7e3027f9826c8985017f16cba12363cc000016e2cc89c18101036fcff34517c7bdf5187c55af4f8b61fdc321588c7ab768dee24b006df29106458d7cf21881f4800000000000005b8b322706090311d3e017f009080202016206030142bf412429205ea66d6f2004edfa570f6f56b3e85e59baa1befbc73b7da5d55bdc61040104123405000456780142bf5a2eef5056775f5b9572ff3ad63dd2a71d1fb281ca177a5e1c74730eccb2e51307000fabacabadabacaba8004811fd096c00000000000000000000000000000000000000000000000000000000000000000114ff00f4a413f4a0f2c80b0a0201200c0b00dfa5ffff76a268698fe9ffe8e42c5267858f90e785ffe4f6aa6467c444ffb365ffc10802faf0807d014035e7a064b87d804077e7857fc10803dfd2407d014035e7a064b86467cd8903a32b9ba4410803ade68afd014035e7a045ea432b6363796103bb7b9363210c678b64b87d807d80400002d2";
@@ -0,0 +1,120 @@
+use crate::tlb_adapters::{ConstLen, DictKeyAdapterInto, DictValAdapterTLB, TLBHashMapE};
+use std::collections::HashMap;
+use ton_lib_core::cell::TonHash;
+use ton_lib_core::TLBDerive;
+
+/// ConfigParam 12
+// https://github.com/ton-blockchain/ton/blob/6f745c04daf8861bb1791cffce6edb1beec62204/crypto/block/block.tlb
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+pub struct ConfigParam12 {
+    /// workchain_id is int32, stored as u32 key
+    #[tlb_derive(adapter = "TLBHashMapE::<DictKeyAdapterInto, DictValAdapterTLB, _, _>::new(32)")]
+    pub workchains: HashMap<u32, WorkchainDescr>,
+}
+
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+pub enum WorkchainDescr {
+    V1(WorkchainDescrV1),
+    V2(WorkchainDescrV2),
+}
+
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+#[tlb_derive(prefix = 0xa6, bits_len = 8)]
+pub struct WorkchainDescrV1 {
+    pub enabled_since: u32,
+    pub actual_min_split: u8,
+    pub min_split: u8,
+    pub max_split: u8,
+    pub basic: bool,
+    pub active: bool,
+    pub accept_msgs: bool,
+    #[tlb_derive(bits_len = 13)]
+    pub flags: u16,
+    pub zerostate_root_hash: TonHash,
+    pub zerostate_file_hash: TonHash,
+    pub version: u32,
+    pub format: WorkchainFormat,
+}
+
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+#[tlb_derive(prefix = 0xa7, bits_len = 8)]
+pub struct WorkchainDescrV2 {
+    pub enabled_since: u32,
+    pub actual_min_split: u8,
+    pub min_split: u8,
+    pub max_split: u8,
+    pub basic: bool,
+    pub active: bool,
+    pub accept_msgs: bool,
+    #[tlb_derive(bits_len = 13)]
+    pub flags: u16,
+    pub zerostate_root_hash: TonHash,
+    pub zerostate_file_hash: TonHash,
+    pub version: u32,
+    pub format: WorkchainFormat,
+    pub split_merge_timings: WcSplitMergeTimings,
+}
+
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+pub enum WorkchainFormat {
+    Basic(WorkchainFormatBasic),
+    Ext(WorkchainFormatExt),
+}
+
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+#[tlb_derive(prefix = 0x1, bits_len = 4)]
+pub struct WorkchainFormatBasic {
+    pub vm_version: i32,
+    pub vm_mode: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+#[tlb_derive(prefix = 0x0, bits_len = 4)]
+pub struct WorkchainFormatExt {
+    #[tlb_derive(bits_len = 12)]
+    pub min_addr_len: u16,
+    #[tlb_derive(bits_len = 12)]
+    pub max_addr_len: u16,
+    #[tlb_derive(bits_len = 12)]
+    pub addr_len_step: u16,
+    pub workchain_type_id: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+#[tlb_derive(prefix = 0x0, bits_len = 4)]
+pub struct WcSplitMergeTimings {
+    pub split_merge_delay: u32,
+    pub split_merge_interval: u32,
+    pub min_split_merge_interval: u32,
+    pub max_split_merge_delay: u32,
+}
+
+impl WorkchainDescr {
+    pub fn enabled_since(&self) -> u32 {
+        match self {
+            WorkchainDescr::V1(descr) => descr.enabled_since,
+            WorkchainDescr::V2(descr) => descr.enabled_since,
+        }
+    }
+
+    pub fn active(&self) -> bool {
+        match self {
+            WorkchainDescr::V1(descr) => descr.active,
+            WorkchainDescr::V2(descr) => descr.active,
+        }
+    }
+
+    pub fn accept_msgs(&self) -> bool {
+        match self {
+            WorkchainDescr::V1(descr) => descr.accept_msgs,
+            WorkchainDescr::V2(descr) => descr.accept_msgs,
+        }
+    }
+
+    pub fn format(&self) -> &WorkchainFormat {
+        match self {
+            WorkchainDescr::V1(descr) => &descr.format,
+            WorkchainDescr::V2(descr) => &descr.format,
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use crate::block_tlb::Coins;
+use ton_lib_core::TLBDerive;
+
+/// ConfigParam 15: elections timings (in seconds)
+// https://github.com/ton-blockchain/ton/blob/6f745c04daf8861bb1791cffce6edb1beec62204/crypto/block/block.tlb
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+pub struct ConfigParam15 {
+    pub validators_elected_for: u32,
+    pub elections_start_before: u32,
+    pub elections_end_before: u32,
+    pub stake_held_for: u32,
+}
+
+/// ConfigParam 16: validators count limits
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+pub struct ConfigParam16 {
+    pub max_validators: u16,
+    pub max_main_validators: u16,
+    pub min_validators: u16,
+}
+
+/// ConfigParam 17: stake limits
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+pub struct ConfigParam17 {
+    pub min_stake: Coins,
+    pub max_stake: Coins,
+    pub min_total_stake: Coins,
+    /// fixed point, 65536 = 1.0
+    pub max_stake_factor: u32,
+}
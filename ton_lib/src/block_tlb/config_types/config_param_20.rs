@@ -0,0 +1,76 @@
+use ton_lib_core::TLBDerive;
+
+/// ConfigParam 20 (masterchain) and 21 (basechain)
+// https://github.com/ton-blockchain/ton/blame/6f745c04daf8861bb1791cffce6edb1beec62204/crypto/block/block.tlb
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+pub enum GasLimitsPrices {
+    Std(GasPrices),
+    Ext(GasPricesExt),
+    Flat(GasFlatPfx),
+}
+
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+#[tlb_derive(prefix = 0xdd, bits_len = 8)]
+pub struct GasPrices {
+    pub gas_price: u64,
+    pub gas_limit: u64,
+    pub gas_credit: u64,
+    pub block_gas_limit: u64,
+    pub freeze_due_limit: u64,
+    pub delete_due_limit: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+#[tlb_derive(prefix = 0xde, bits_len = 8)]
+pub struct GasPricesExt {
+    pub gas_price: u64,
+    pub gas_limit: u64,
+    pub special_gas_limit: u64,
+    pub gas_credit: u64,
+    pub block_gas_limit: u64,
+    pub freeze_due_limit: u64,
+    pub delete_due_limit: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+#[tlb_derive(prefix = 0xd1, bits_len = 8)]
+pub struct GasFlatPfx {
+    pub flat_gas_limit: u64,
+    pub flat_gas_price: u64,
+    pub other: Box<GasLimitsPrices>,
+}
+
+impl GasLimitsPrices {
+    /// nanotons per 2^16 gas units
+    pub fn gas_price(&self) -> u64 {
+        match self {
+            GasLimitsPrices::Std(prices) => prices.gas_price,
+            GasLimitsPrices::Ext(prices) => prices.gas_price,
+            GasLimitsPrices::Flat(flat) => flat.other.gas_price(),
+        }
+    }
+
+    pub fn gas_limit(&self) -> u64 {
+        match self {
+            GasLimitsPrices::Std(prices) => prices.gas_limit,
+            GasLimitsPrices::Ext(prices) => prices.gas_limit,
+            GasLimitsPrices::Flat(flat) => flat.other.gas_limit(),
+        }
+    }
+
+    pub fn gas_credit(&self) -> u64 {
+        match self {
+            GasLimitsPrices::Std(prices) => prices.gas_credit,
+            GasLimitsPrices::Ext(prices) => prices.gas_credit,
+            GasLimitsPrices::Flat(flat) => flat.other.gas_credit(),
+        }
+    }
+
+    /// (flat_gas_limit, flat_gas_price), zeros if not set
+    pub fn flat_gas(&self) -> (u64, u64) {
+        match self {
+            GasLimitsPrices::Flat(flat) => (flat.flat_gas_limit, flat.flat_gas_price),
+            _ => (0, 0),
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use ton_lib_core::TLBDerive;
+
+/// ConfigParam 22 (masterchain) and 23 (basechain)
+// https://github.com/ton-blockchain/ton/blob/6f745c04daf8861bb1791cffce6edb1beec62204/crypto/block/block.tlb
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+pub enum BlockLimits {
+    V1(BlockLimitsV1),
+    V2(BlockLimitsV2),
+}
+
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+#[tlb_derive(prefix = 0x5d, bits_len = 8)]
+pub struct BlockLimitsV1 {
+    pub bytes: ParamLimits,
+    pub gas: ParamLimits,
+    pub lt_delta: ParamLimits,
+}
+
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+#[tlb_derive(prefix = 0x5e, bits_len = 8)]
+pub struct BlockLimitsV2 {
+    pub bytes: ParamLimits,
+    pub gas: ParamLimits,
+    pub lt_delta: ParamLimits,
+    pub collated_data: ParamLimits,
+    pub imported_msg_queue: ImportedMsgQueueLimits,
+}
+
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+#[tlb_derive(prefix = 0xc3, bits_len = 8)]
+pub struct ParamLimits {
+    pub underload: u32,
+    pub soft_limit: u32,
+    pub hard_limit: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+#[tlb_derive(prefix = 0xd3, bits_len = 8)]
+pub struct ImportedMsgQueueLimits {
+    pub max_bytes: u32,
+    pub max_msgs: u32,
+}
+
+impl BlockLimits {
+    pub fn bytes(&self) -> &ParamLimits {
+        match self {
+            BlockLimits::V1(limits) => &limits.bytes,
+            BlockLimits::V2(limits) => &limits.bytes,
+        }
+    }
+
+    pub fn gas(&self) -> &ParamLimits {
+        match self {
+            BlockLimits::V1(limits) => &limits.gas,
+            BlockLimits::V2(limits) => &limits.gas,
+        }
+    }
+
+    pub fn lt_delta(&self) -> &ParamLimits {
+        match self {
+            BlockLimits::V1(limits) => &limits.lt_delta,
+            BlockLimits::V2(limits) => &limits.lt_delta,
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use ton_lib_core::TLBDerive;
+
+/// ConfigParam 24 (masterchain) and 25 (basechain)
+// https://github.com/ton-blockchain/ton/blame/6f745c04daf8861bb1791cffce6edb1beec62204/crypto/block/block.tlb
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+#[tlb_derive(prefix = 0xea, bits_len = 8)]
+pub struct MsgForwardPrices {
+    pub lump_price: u64,
+    /// nanotons per 2^16 bits
+    pub bit_price: u64,
+    /// nanotons per 2^16 cells
+    pub cell_price: u64,
+    pub ihr_price_factor: u32,
+    pub first_frac: u16,
+    pub next_frac: u16,
+}
@@ -0,0 +1,16 @@
+use crate::tlb_adapters::{DictKeyAdapterTonHash, DictValAdapterTLB, TLBHashMapE};
+use std::collections::HashMap;
+use ton_lib_core::cell::TonHash;
+use ton_lib_core::TLBDerive;
+
+/// ConfigParam 31: fundamental smart contracts in masterchain (set of address hashes)
+// https://github.com/ton-blockchain/ton/blob/6f745c04daf8861bb1791cffce6edb1beec62204/crypto/block/block.tlb
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+pub struct ConfigParam31 {
+    #[tlb_derive(adapter = "TLBHashMapE::<DictKeyAdapterTonHash, DictValAdapterTLB, _, _>::new(256)")]
+    pub fundamental_smc_addr: HashMap<TonHash, ()>,
+}
+
+impl ConfigParam31 {
+    pub fn contains(&self, addr_hash: &TonHash) -> bool { self.fundamental_smc_addr.contains_key(addr_hash) }
+}
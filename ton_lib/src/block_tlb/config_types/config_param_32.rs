@@ -0,0 +1,108 @@
+use crate::tlb_adapters::{DictKeyAdapterInto, DictValAdapterTLB, TLBHashMap, TLBHashMapE};
+use std::collections::HashMap;
+use ton_lib_core::cell::TonHash;
+use ton_lib_core::TLBDerive;
+
+/// ConfigParams 32-37: previous, current and next validator sets (including temporary ones)
+// https://github.com/ton-blockchain/ton/blob/6f745c04daf8861bb1791cffce6edb1beec62204/crypto/block/block.tlb
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+pub enum ValidatorSet {
+    Simple(ValidatorSetSimple),
+    Ext(ValidatorSetExt),
+}
+
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+#[tlb_derive(prefix = 0x11, bits_len = 8)]
+pub struct ValidatorSetSimple {
+    pub utime_since: u32,
+    pub utime_until: u32,
+    pub total: u16,
+    pub main: u16,
+    #[tlb_derive(adapter = "TLBHashMap::<DictKeyAdapterInto, DictValAdapterTLB, _, _>::new(16)")]
+    pub list: HashMap<u16, ValidatorDescr>,
+}
+
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+#[tlb_derive(prefix = 0x12, bits_len = 8)]
+pub struct ValidatorSetExt {
+    pub utime_since: u32,
+    pub utime_until: u32,
+    pub total: u16,
+    pub main: u16,
+    pub total_weight: u64,
+    #[tlb_derive(adapter = "TLBHashMapE::<DictKeyAdapterInto, DictValAdapterTLB, _, _>::new(16)")]
+    pub list: HashMap<u16, ValidatorDescr>,
+}
+
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+pub enum ValidatorDescr {
+    Simple(ValidatorDescrSimple),
+    Addr(ValidatorDescrAddr),
+}
+
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+#[tlb_derive(prefix = 0x53, bits_len = 8)]
+pub struct ValidatorDescrSimple {
+    pub public_key: SigPubKey,
+    pub weight: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+#[tlb_derive(prefix = 0x73, bits_len = 8)]
+pub struct ValidatorDescrAddr {
+    pub public_key: SigPubKey,
+    pub weight: u64,
+    pub adnl_addr: TonHash,
+}
+
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+#[tlb_derive(prefix = 0x8e81278a, bits_len = 32)]
+pub struct SigPubKey {
+    pub pubkey: TonHash,
+}
+
+impl ValidatorSet {
+    pub fn utime_since(&self) -> u32 {
+        match self {
+            ValidatorSet::Simple(set) => set.utime_since,
+            ValidatorSet::Ext(set) => set.utime_since,
+        }
+    }
+
+    pub fn utime_until(&self) -> u32 {
+        match self {
+            ValidatorSet::Simple(set) => set.utime_until,
+            ValidatorSet::Ext(set) => set.utime_until,
+        }
+    }
+
+    pub fn total(&self) -> u16 {
+        match self {
+            ValidatorSet::Simple(set) => set.total,
+            ValidatorSet::Ext(set) => set.total,
+        }
+    }
+
+    pub fn list(&self) -> &HashMap<u16, ValidatorDescr> {
+        match self {
+            ValidatorSet::Simple(set) => &set.list,
+            ValidatorSet::Ext(set) => &set.list,
+        }
+    }
+}
+
+impl ValidatorDescr {
+    pub fn public_key(&self) -> &TonHash {
+        match self {
+            ValidatorDescr::Simple(descr) => &descr.public_key.pubkey,
+            ValidatorDescr::Addr(descr) => &descr.public_key.pubkey,
+        }
+    }
+
+    pub fn weight(&self) -> u64 {
+        match self {
+            ValidatorDescr::Simple(descr) => descr.weight,
+            ValidatorDescr::Addr(descr) => descr.weight,
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use crate::block_tlb::Coins;
+use ton_lib_core::TLBDerive;
+
+/// ConfigParam 40
+// https://github.com/ton-blockchain/ton/blob/6f745c04daf8861bb1791cffce6edb1beec62204/crypto/block/block.tlb
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+#[tlb_derive(prefix = 0x01, bits_len = 8)]
+pub struct MisbehaviourPunishmentConfig {
+    pub default_flat_fine: Coins,
+    pub default_proportional_fine: u32,
+    pub severity_flat_mult: u16,
+    pub severity_proportional_mult: u16,
+    pub unpunishable_interval: u16,
+    pub long_interval: u16,
+    pub long_flat_mult: u16,
+    pub long_proportional_mult: u16,
+    pub medium_interval: u16,
+    pub medium_flat_mult: u16,
+    pub medium_proportional_mult: u16,
+}
@@ -0,0 +1,67 @@
+use ton_lib_core::TLBDerive;
+
+/// ConfigParam 43
+// https://github.com/ton-blockchain/ton/blob/6f745c04daf8861bb1791cffce6edb1beec62204/crypto/block/block.tlb
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+pub enum SizeLimitsConfig {
+    V1(SizeLimitsConfigV1),
+    V2(SizeLimitsConfigV2),
+}
+
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+#[tlb_derive(prefix = 0x01, bits_len = 8)]
+pub struct SizeLimitsConfigV1 {
+    pub max_msg_bits: u32,
+    pub max_msg_cells: u32,
+    pub max_library_cells: u32,
+    pub max_vm_data_depth: u16,
+    pub max_ext_msg_size: u32,
+    pub max_ext_msg_depth: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+#[tlb_derive(prefix = 0x02, bits_len = 8)]
+pub struct SizeLimitsConfigV2 {
+    pub max_msg_bits: u32,
+    pub max_msg_cells: u32,
+    pub max_library_cells: u32,
+    pub max_vm_data_depth: u16,
+    pub max_ext_msg_size: u32,
+    pub max_ext_msg_depth: u16,
+    pub max_acc_state_cells: u32,
+    pub max_acc_state_bits: u32,
+    pub max_acc_public_libraries: u32,
+    pub defer_out_queue_size_limit: u32,
+    pub max_msg_extra_currencies: u32,
+    pub max_acc_fixed_prefix_length: u8,
+}
+
+impl SizeLimitsConfig {
+    pub fn max_msg_bits(&self) -> u32 {
+        match self {
+            SizeLimitsConfig::V1(limits) => limits.max_msg_bits,
+            SizeLimitsConfig::V2(limits) => limits.max_msg_bits,
+        }
+    }
+
+    pub fn max_msg_cells(&self) -> u32 {
+        match self {
+            SizeLimitsConfig::V1(limits) => limits.max_msg_cells,
+            SizeLimitsConfig::V2(limits) => limits.max_msg_cells,
+        }
+    }
+
+    pub fn max_ext_msg_size(&self) -> u32 {
+        match self {
+            SizeLimitsConfig::V1(limits) => limits.max_ext_msg_size,
+            SizeLimitsConfig::V2(limits) => limits.max_ext_msg_size,
+        }
+    }
+
+    pub fn max_ext_msg_depth(&self) -> u16 {
+        match self {
+            SizeLimitsConfig::V1(limits) => limits.max_ext_msg_depth,
+            SizeLimitsConfig::V2(limits) => limits.max_ext_msg_depth,
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use crate::tlb_adapters::{DictKeyAdapterInto, DictValAdapterTLB, TLBHashMapE};
+use num_bigint::BigUint;
+use std::collections::HashMap;
+use ton_lib_core::cell::TonHash;
+use ton_lib_core::types::TonAddress;
+use ton_lib_core::TLBDerive;
+
+/// ConfigParam 44
+// https://github.com/ton-blockchain/ton/blob/6f745c04daf8861bb1791cffce6edb1beec62204/crypto/block/block.tlb
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+#[tlb_derive(prefix = 0x00, bits_len = 8)]
+pub struct SuspendedAddressList {
+    /// key is [wc:int32 addr:uint256], use `addresses()` to get parsed values
+    #[tlb_derive(adapter = "TLBHashMapE::<DictKeyAdapterInto, DictValAdapterTLB, _, _>::new(288)")]
+    pub addresses: HashMap<BigUint, ()>,
+    pub suspended_until: u32,
+}
+
+impl SuspendedAddressList {
+    pub fn addresses(&self) -> Vec<TonAddress> {
+        self.addresses
+            .keys()
+            .map(|key| {
+                let mut bytes = [0u8; 36];
+                let key_bytes = key.to_bytes_be();
+                bytes[36 - key_bytes.len()..].copy_from_slice(&key_bytes);
+                let workchain = i32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+                let mut hash = [0u8; 32];
+                hash.copy_from_slice(&bytes[4..]);
+                TonAddress::new(workchain, TonHash::from(hash))
+            })
+            .collect()
+    }
+
+    pub fn contains(&self, address: &TonAddress) -> bool {
+        let mut key_bytes = address.workchain.to_be_bytes().to_vec();
+        key_bytes.extend_from_slice(address.hash.as_slice());
+        self.addresses.contains_key(&BigUint::from_bytes_be(&key_bytes))
+    }
+}
@@ -0,0 +1,19 @@
+use crate::tlb_adapters::{DictKeyAdapterTonHash, DictValAdapterTLB, TLBHashMapE};
+use std::collections::HashMap;
+use ton_lib_core::cell::TonHash;
+use ton_lib_core::TLBDerive;
+
+/// ConfigParam 45: contracts with fixed gas usage, key is code hash
+// https://github.com/ton-blockchain/ton/blob/6f745c04daf8861bb1791cffce6edb1beec62204/crypto/block/block.tlb
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+#[tlb_derive(prefix = 0xc0, bits_len = 8)]
+pub struct PrecompiledContractsConfig {
+    #[tlb_derive(adapter = "TLBHashMapE::<DictKeyAdapterTonHash, DictValAdapterTLB, _, _>::new(256)")]
+    pub list: HashMap<TonHash, PrecompiledSmc>,
+}
+
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+#[tlb_derive(prefix = 0xb0, bits_len = 8)]
+pub struct PrecompiledSmc {
+    pub gas_usage: u64,
+}
@@ -0,0 +1,12 @@
+use ton_lib_core::cell::TonHash;
+use ton_lib_core::TLBDerive;
+
+/// ConfigParam 5
+// https://github.com/ton-blockchain/ton/blob/6f745c04daf8861bb1791cffce6edb1beec62204/crypto/block/block.tlb
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+#[tlb_derive(prefix = 0x01, bits_len = 8)]
+pub struct BurningConfig {
+    pub blackhole_addr: Option<TonHash>,
+    pub fee_burn_num: u32,
+    pub fee_burn_denom: u32,
+}
@@ -0,0 +1,57 @@
+use crate::block_tlb::Coins;
+use crate::tlb_adapters::{DictKeyAdapterTonHash, DictValAdapterTLB, TLBHashMapE, TLBRef};
+use std::collections::HashMap;
+use ton_lib_core::cell::TonHash;
+use ton_lib_core::TLBDerive;
+
+/// ConfigParams 71-73: oracle bridges (Ethereum, BSC, Polygon)
+// https://github.com/ton-blockchain/ton/blob/6f745c04daf8861bb1791cffce6edb1beec62204/crypto/block/block.tlb
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+pub struct OracleBridgeParams {
+    pub bridge_address: TonHash,
+    pub oracle_multisig_address: TonHash,
+    #[tlb_derive(adapter = "TLBHashMapE::<DictKeyAdapterTonHash, DictValAdapterTLB, _, _>::new(256)")]
+    pub oracles: HashMap<TonHash, TonHash>,
+    pub external_chain_address: TonHash,
+}
+
+/// ConfigParams 79, 81, 82: jetton bridges (ETH, BNB, Polygon -> TON)
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+pub enum JettonBridgeParams {
+    V0(JettonBridgeParamsV0),
+    V1(JettonBridgeParamsV1),
+}
+
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+#[tlb_derive(prefix = 0x00, bits_len = 8)]
+pub struct JettonBridgeParamsV0 {
+    pub bridge_address: TonHash,
+    pub oracles_address: TonHash,
+    #[tlb_derive(adapter = "TLBHashMapE::<DictKeyAdapterTonHash, DictValAdapterTLB, _, _>::new(256)")]
+    pub oracles: HashMap<TonHash, TonHash>,
+    pub state_flags: u8,
+    pub burn_bridge_fee: Coins,
+}
+
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+#[tlb_derive(prefix = 0x01, bits_len = 8)]
+pub struct JettonBridgeParamsV1 {
+    pub bridge_address: TonHash,
+    pub oracles_address: TonHash,
+    #[tlb_derive(adapter = "TLBHashMapE::<DictKeyAdapterTonHash, DictValAdapterTLB, _, _>::new(256)")]
+    pub oracles: HashMap<TonHash, TonHash>,
+    pub state_flags: u8,
+    #[tlb_derive(adapter = "TLBRef")]
+    pub prices: JettonBridgePrices,
+    pub external_chain_address: TonHash,
+}
+
+#[derive(Debug, Clone, PartialEq, TLBDerive)]
+pub struct JettonBridgePrices {
+    pub bridge_burn_fee: Coins,
+    pub bridge_mint_fee: Coins,
+    pub wallet_min_tons_for_storage: Coins,
+    pub wallet_gas_consumption: Coins,
+    pub minter_min_tons_for_storage: Coins,
+    pub discover_gas_consumption: Coins,
+}
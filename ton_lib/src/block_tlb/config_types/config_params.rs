@@ -1,4 +1,4 @@
-use crate::block_tlb::{ConfigParam18, GlobalVersion};
+use crate::block_tlb::*;
 use crate::tlb_adapters::{DictKeyAdapterInto, DictValAdapterTLB, TLBHashMap};
 use parking_lot::RwLock;
 use std::collections::HashMap;
@@ -13,15 +13,132 @@ use ton_lib_core::traits::tlb::TLB;
 pub struct ConfigParams {
     pub config_addr: TonHash,
     pub config: HashMap<u32, TonCellRef>,
-    storage_prices: RwLock<Option<Arc<ConfigParam18>>>,
+    config_smc_addr: RwLock<Option<Arc<TonHash>>>,
+    elector_addr: RwLock<Option<Arc<TonHash>>>,
+    minter_addr: RwLock<Option<Arc<TonHash>>>,
+    fee_collector_addr: RwLock<Option<Arc<TonHash>>>,
+    dns_root_addr: RwLock<Option<Arc<TonHash>>>,
+    burning_config: RwLock<Option<Arc<BurningConfig>>>,
     global_version: RwLock<Option<Arc<GlobalVersion>>>,
+    workchains: RwLock<Option<Arc<ConfigParam12>>>,
+    election_timings: RwLock<Option<Arc<ConfigParam15>>>,
+    validators_count: RwLock<Option<Arc<ConfigParam16>>>,
+    stakes_config: RwLock<Option<Arc<ConfigParam17>>>,
+    storage_prices: RwLock<Option<Arc<ConfigParam18>>>,
+    gas_prices_mc: RwLock<Option<Arc<GasLimitsPrices>>>,
+    gas_prices: RwLock<Option<Arc<GasLimitsPrices>>>,
+    block_limits_mc: RwLock<Option<Arc<BlockLimits>>>,
+    block_limits: RwLock<Option<Arc<BlockLimits>>>,
+    fwd_prices_mc: RwLock<Option<Arc<MsgForwardPrices>>>,
+    fwd_prices: RwLock<Option<Arc<MsgForwardPrices>>>,
+    fundamental_smc_addr: RwLock<Option<Arc<ConfigParam31>>>,
+    prev_validators: RwLock<Option<Arc<ValidatorSet>>>,
+    prev_temp_validators: RwLock<Option<Arc<ValidatorSet>>>,
+    cur_validators: RwLock<Option<Arc<ValidatorSet>>>,
+    cur_temp_validators: RwLock<Option<Arc<ValidatorSet>>>,
+    next_validators: RwLock<Option<Arc<ValidatorSet>>>,
+    next_temp_validators: RwLock<Option<Arc<ValidatorSet>>>,
+    misbehaviour_punishment: RwLock<Option<Arc<MisbehaviourPunishmentConfig>>>,
+    size_limits: RwLock<Option<Arc<SizeLimitsConfig>>>,
+    suspended_addresses: RwLock<Option<Arc<SuspendedAddressList>>>,
+    precompiled_contracts: RwLock<Option<Arc<PrecompiledContractsConfig>>>,
+    eth_bridge: RwLock<Option<Arc<OracleBridgeParams>>>,
+    bsc_bridge: RwLock<Option<Arc<OracleBridgeParams>>>,
+    polygon_bridge: RwLock<Option<Arc<OracleBridgeParams>>>,
+    eth_jetton_bridge: RwLock<Option<Arc<JettonBridgeParams>>>,
+    bnb_jetton_bridge: RwLock<Option<Arc<JettonBridgeParams>>>,
+    polygon_jetton_bridge: RwLock<Option<Arc<JettonBridgeParams>>>,
 }
 
 #[rustfmt::skip]
 impl ConfigParams {
     // lazy_load for params
-    pub fn storage_prices(&self) -> Result<Arc<ConfigParam18>, TLCoreError> { self.load_param(18, &self.storage_prices) }
+    pub fn config_smc_addr(&self) -> Result<Arc<TonHash>, TLCoreError> { self.load_param(0, &self.config_smc_addr) }
+    pub fn elector_addr(&self) -> Result<Arc<TonHash>, TLCoreError> { self.load_param(1, &self.elector_addr) }
+    pub fn minter_addr(&self) -> Result<Arc<TonHash>, TLCoreError> { self.load_param_or(2, &self.minter_addr, Self::config_smc_addr) }
+    pub fn fee_collector_addr(&self) -> Result<Arc<TonHash>, TLCoreError> { self.load_param_or(3, &self.fee_collector_addr, Self::elector_addr) }
+    pub fn dns_root_addr(&self) -> Result<Arc<TonHash>, TLCoreError> { self.load_param(4, &self.dns_root_addr) }
+    pub fn burning_config(&self) -> Result<Arc<BurningConfig>, TLCoreError> { self.load_param(5, &self.burning_config) }
     pub fn global_version(&self) -> Result<Arc<GlobalVersion>, TLCoreError> { self.load_param(8, &self.global_version) }
+    pub fn workchains(&self) -> Result<Arc<ConfigParam12>, TLCoreError> { self.load_param(12, &self.workchains) }
+    pub fn election_timings(&self) -> Result<Arc<ConfigParam15>, TLCoreError> { self.load_param(15, &self.election_timings) }
+    pub fn validators_count(&self) -> Result<Arc<ConfigParam16>, TLCoreError> { self.load_param(16, &self.validators_count) }
+    pub fn stakes_config(&self) -> Result<Arc<ConfigParam17>, TLCoreError> { self.load_param(17, &self.stakes_config) }
+    pub fn storage_prices(&self) -> Result<Arc<ConfigParam18>, TLCoreError> { self.load_param(18, &self.storage_prices) }
+    pub fn gas_prices_mc(&self) -> Result<Arc<GasLimitsPrices>, TLCoreError> { self.load_param(20, &self.gas_prices_mc) }
+    pub fn gas_prices(&self) -> Result<Arc<GasLimitsPrices>, TLCoreError> { self.load_param(21, &self.gas_prices) }
+    pub fn block_limits_mc(&self) -> Result<Arc<BlockLimits>, TLCoreError> { self.load_param(22, &self.block_limits_mc) }
+    pub fn block_limits(&self) -> Result<Arc<BlockLimits>, TLCoreError> { self.load_param(23, &self.block_limits) }
+    pub fn fwd_prices_mc(&self) -> Result<Arc<MsgForwardPrices>, TLCoreError> { self.load_param(24, &self.fwd_prices_mc) }
+    pub fn fwd_prices(&self) -> Result<Arc<MsgForwardPrices>, TLCoreError> { self.load_param(25, &self.fwd_prices) }
+    pub fn fundamental_smc_addr(&self) -> Result<Arc<ConfigParam31>, TLCoreError> { self.load_param(31, &self.fundamental_smc_addr) }
+    pub fn prev_validators(&self) -> Result<Arc<ValidatorSet>, TLCoreError> { self.load_param(32, &self.prev_validators) }
+    pub fn prev_temp_validators(&self) -> Result<Arc<ValidatorSet>, TLCoreError> { self.load_param(33, &self.prev_temp_validators) }
+    pub fn cur_validators(&self) -> Result<Arc<ValidatorSet>, TLCoreError> { self.load_param(34, &self.cur_validators) }
+    pub fn cur_temp_validators(&self) -> Result<Arc<ValidatorSet>, TLCoreError> { self.load_param(35, &self.cur_temp_validators) }
+    pub fn next_validators(&self) -> Result<Arc<ValidatorSet>, TLCoreError> { self.load_param(36, &self.next_validators) }
+    pub fn next_temp_validators(&self) -> Result<Arc<ValidatorSet>, TLCoreError> { self.load_param(37, &self.next_temp_validators) }
+    pub fn misbehaviour_punishment(&self) -> Result<Arc<MisbehaviourPunishmentConfig>, TLCoreError> { self.load_param(40, &self.misbehaviour_punishment) }
+    pub fn size_limits(&self) -> Result<Arc<SizeLimitsConfig>, TLCoreError> { self.load_param(43, &self.size_limits) }
+    pub fn suspended_addresses(&self) -> Result<Arc<SuspendedAddressList>, TLCoreError> { self.load_param(44, &self.suspended_addresses) }
+    pub fn precompiled_contracts(&self) -> Result<Arc<PrecompiledContractsConfig>, TLCoreError> { self.load_param(45, &self.precompiled_contracts) }
+    pub fn eth_bridge(&self) -> Result<Arc<OracleBridgeParams>, TLCoreError> { self.load_param(71, &self.eth_bridge) }
+    pub fn bsc_bridge(&self) -> Result<Arc<OracleBridgeParams>, TLCoreError> { self.load_param(72, &self.bsc_bridge) }
+    pub fn polygon_bridge(&self) -> Result<Arc<OracleBridgeParams>, TLCoreError> { self.load_param(73, &self.polygon_bridge) }
+    pub fn eth_jetton_bridge(&self) -> Result<Arc<JettonBridgeParams>, TLCoreError> { self.load_param(79, &self.eth_jetton_bridge) }
+    pub fn bnb_jetton_bridge(&self) -> Result<Arc<JettonBridgeParams>, TLCoreError> { self.load_param(81, &self.bnb_jetton_bridge) }
+    pub fn polygon_jetton_bridge(&self) -> Result<Arc<JettonBridgeParams>, TLCoreError> { self.load_param(82, &self.polygon_jetton_bridge) }
+
+    // setters re-serialize value into config dict
+    pub fn set_elector_addr(&mut self, value: TonHash) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 1, &self.elector_addr, value) }
+    pub fn set_minter_addr(&mut self, value: TonHash) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 2, &self.minter_addr, value) }
+    pub fn set_fee_collector_addr(&mut self, value: TonHash) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 3, &self.fee_collector_addr, value) }
+    pub fn set_dns_root_addr(&mut self, value: TonHash) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 4, &self.dns_root_addr, value) }
+    pub fn set_burning_config(&mut self, value: BurningConfig) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 5, &self.burning_config, value) }
+    pub fn set_global_version(&mut self, value: GlobalVersion) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 8, &self.global_version, value) }
+    pub fn set_workchains(&mut self, value: ConfigParam12) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 12, &self.workchains, value) }
+    pub fn set_election_timings(&mut self, value: ConfigParam15) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 15, &self.election_timings, value) }
+    pub fn set_validators_count(&mut self, value: ConfigParam16) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 16, &self.validators_count, value) }
+    pub fn set_stakes_config(&mut self, value: ConfigParam17) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 17, &self.stakes_config, value) }
+    pub fn set_storage_prices(&mut self, value: ConfigParam18) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 18, &self.storage_prices, value) }
+    pub fn set_gas_prices_mc(&mut self, value: GasLimitsPrices) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 20, &self.gas_prices_mc, value) }
+    pub fn set_gas_prices(&mut self, value: GasLimitsPrices) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 21, &self.gas_prices, value) }
+    pub fn set_block_limits_mc(&mut self, value: BlockLimits) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 22, &self.block_limits_mc, value) }
+    pub fn set_block_limits(&mut self, value: BlockLimits) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 23, &self.block_limits, value) }
+    pub fn set_fwd_prices_mc(&mut self, value: MsgForwardPrices) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 24, &self.fwd_prices_mc, value) }
+    pub fn set_fwd_prices(&mut self, value: MsgForwardPrices) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 25, &self.fwd_prices, value) }
+    pub fn set_fundamental_smc_addr(&mut self, value: ConfigParam31) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 31, &self.fundamental_smc_addr, value) }
+    pub fn set_prev_validators(&mut self, value: ValidatorSet) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 32, &self.prev_validators, value) }
+    pub fn set_prev_temp_validators(&mut self, value: ValidatorSet) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 33, &self.prev_temp_validators, value) }
+    pub fn set_cur_validators(&mut self, value: ValidatorSet) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 34, &self.cur_validators, value) }
+    pub fn set_cur_temp_validators(&mut self, value: ValidatorSet) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 35, &self.cur_temp_validators, value) }
+    pub fn set_next_validators(&mut self, value: ValidatorSet) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 36, &self.next_validators, value) }
+    pub fn set_next_temp_validators(&mut self, value: ValidatorSet) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 37, &self.next_temp_validators, value) }
+    pub fn set_misbehaviour_punishment(&mut self, value: MisbehaviourPunishmentConfig) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 40, &self.misbehaviour_punishment, value) }
+    pub fn set_size_limits(&mut self, value: SizeLimitsConfig) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 43, &self.size_limits, value) }
+    pub fn set_suspended_addresses(&mut self, value: SuspendedAddressList) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 44, &self.suspended_addresses, value) }
+    pub fn set_precompiled_contracts(&mut self, value: PrecompiledContractsConfig) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 45, &self.precompiled_contracts, value) }
+    pub fn set_eth_bridge(&mut self, value: OracleBridgeParams) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 71, &self.eth_bridge, value) }
+    pub fn set_bsc_bridge(&mut self, value: OracleBridgeParams) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 72, &self.bsc_bridge, value) }
+    pub fn set_polygon_bridge(&mut self, value: OracleBridgeParams) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 73, &self.polygon_bridge, value) }
+    pub fn set_eth_jetton_bridge(&mut self, value: JettonBridgeParams) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 79, &self.eth_jetton_bridge, value) }
+    pub fn set_bnb_jetton_bridge(&mut self, value: JettonBridgeParams) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 81, &self.bnb_jetton_bridge, value) }
+    pub fn set_polygon_jetton_bridge(&mut self, value: JettonBridgeParams) -> Result<(), TLCoreError> { Self::store_param(&mut self.config, 82, &self.polygon_jetton_bridge, value) }
+
+    // param 2 & 3 fallback to 0 & 1 if absent
+    fn load_param_or<T: TLB, F>(&self, index: u32, dst: &RwLock<Option<Arc<T>>>, fallback: F) -> Result<Arc<T>, TLCoreError>
+    where F: FnOnce(&Self) -> Result<Arc<T>, TLCoreError> {
+        match self.config.contains_key(&index) {
+            true => self.load_param(index, dst),
+            false => fallback(self),
+        }
+    }
+
+    fn store_param<T: TLB>(config: &mut HashMap<u32, TonCellRef>, index: u32, dst: &RwLock<Option<Arc<T>>>, value: T) -> Result<(), TLCoreError> {
+        config.insert(index, value.to_cell_ref()?);
+        *dst.write() = Some(Arc::new(value));
+        Ok(())
+    }
 
     fn load_param<T: TLB>(&self, index: u32, dst: &RwLock<Option<Arc<T>>>) -> Result<Arc<T>, TLCoreError> {
         if let Some(param) = dst.read().deref() {
@@ -41,6 +158,49 @@ impl ConfigParams {
     }
 }
 
+impl ConfigParams {
+    /// Build from config dict (`Hashmap 32 ^Cell`) - the format used by emulators and `getConfigAll`
+    pub fn from_dict_cell(config_dict: &TonCell) -> Result<Self, TLCoreError> {
+        let config: HashMap<u32, TonCellRef> =
+            TLBHashMap::<DictKeyAdapterInto, DictValAdapterTLB, _, _>::new(32).read(&mut config_dict.parser())?;
+        let config_addr = match config.get(&0) {
+            Some(cell) => TonHash::from_cell(cell)?,
+            None => TonHash::ZERO,
+        };
+        Ok(Self {
+            config_addr,
+            config,
+            ..Default::default()
+        })
+    }
+
+    pub fn to_dict_cell(&self) -> Result<TonCell, TLCoreError> {
+        let mut builder = TonCell::builder();
+        TLBHashMap::<DictKeyAdapterInto, DictValAdapterTLB, _, _>::new(32).write(&mut builder, &self.config)?;
+        builder.build()
+    }
+
+    /// Sets raw param value. All typed values are reloaded on next access
+    pub fn set_param_raw(&mut self, index: u32, value: TonCellRef) {
+        self.config.insert(index, value);
+        self.reset_cache();
+    }
+
+    pub fn remove_param(&mut self, index: u32) -> Option<TonCellRef> {
+        let removed = self.config.remove(&index);
+        self.reset_cache();
+        removed
+    }
+
+    fn reset_cache(&mut self) {
+        *self = Self {
+            config_addr: self.config_addr.clone(),
+            config: std::mem::take(&mut self.config),
+            ..Default::default()
+        };
+    }
+}
+
 impl PartialEq for ConfigParams {
     fn eq(&self, other: &Self) -> bool { self.config_addr == other.config_addr && self.config == other.config }
 }
@@ -50,8 +210,7 @@ impl Clone for ConfigParams {
         Self {
             config_addr: self.config_addr.clone(),
             config: self.config.clone(),
-            storage_prices: RwLock::new(None),
-            global_version: RwLock::new(None),
+            ..Default::default()
         }
     }
 }
@@ -60,20 +219,15 @@ impl TLB for ConfigParams {
     fn read_definition(parser: &mut CellParser) -> Result<Self, TLCoreError> {
         let config_addr = TLB::read(parser)?;
         let config_ref = parser.read_next_ref()?;
-        let config =
-            TLBHashMap::<DictKeyAdapterInto, DictValAdapterTLB, _, _>::new(32).read(&mut config_ref.parser())?;
         Ok(Self {
             config_addr,
-            config,
-            ..Default::default()
+            ..Self::from_dict_cell(config_ref)?
         })
     }
 
     fn write_definition(&self, dst: &mut CellBuilder) -> Result<(), TLCoreError> {
         self.config_addr.write(dst)?;
-        let mut config_cell = TonCell::builder();
-        TLBHashMap::<DictKeyAdapterInto, DictValAdapterTLB, _, _>::new(32).write(&mut config_cell, &self.config)?;
-        dst.write_ref(config_cell.build()?.into_ref())?;
+        dst.write_ref(self.to_dict_cell()?.into_ref())?;
         Ok(())
     }
 }
@@ -81,9 +235,15 @@ impl TLB for ConfigParams {
 #[cfg(test)]
 mod tests {
     use crate::block_tlb::_test_block_data::CONFIG_BOC_HEX;
-    use crate::block_tlb::{ConfigParam18, ConfigParams, GlobalVersion, StoragePrices};
+    use crate::block_tlb::{
+        Coins, ConfigParam18, ConfigParams, GasFlatPfx, GasLimitsPrices, GasPricesExt, GlobalVersion,
+        JettonBridgeParams, JettonBridgeParamsV0, JettonBridgeParamsV1, JettonBridgePrices,
+        MisbehaviourPunishmentConfig, MsgForwardPrices, OracleBridgeParams, PrecompiledSmc, SizeLimitsConfig,
+        SizeLimitsConfigV2, StoragePrices, WorkchainFormat,
+    };
     use std::collections::HashMap;
     use std::ops::Deref;
+    use ton_lib_core::cell::{TonCell, TonHash};
     use ton_lib_core::traits::tlb::TLB;
 
     #[test]
@@ -124,4 +284,263 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_config_param_20_21_24_25() -> anyhow::Result<()> {
+        let config_params = ConfigParams::from_boc_hex(CONFIG_BOC_HEX)?;
+        let expected_gas_prices = GasLimitsPrices::Flat(GasFlatPfx {
+            flat_gas_limit: 100,
+            flat_gas_price: 40000,
+            other: Box::new(GasLimitsPrices::Ext(GasPricesExt {
+                gas_price: 26214400,
+                gas_limit: 1000000,
+                special_gas_limit: 1000000,
+                gas_credit: 10000,
+                block_gas_limit: 10000000,
+                freeze_due_limit: 100000000,
+                delete_due_limit: 1000000000,
+            })),
+        });
+        assert_eq!(config_params.gas_prices()?.deref(), &expected_gas_prices);
+        assert_eq!(config_params.gas_prices_mc()?.flat_gas(), (100, 1000000));
+        assert_eq!(config_params.gas_prices_mc()?.gas_price(), 655360000);
+
+        let expected_fwd_prices = MsgForwardPrices {
+            lump_price: 400000,
+            bit_price: 26214400,
+            cell_price: 2621440000,
+            ihr_price_factor: 98304,
+            first_frac: 21845,
+            next_frac: 21845,
+        };
+        assert_eq!(config_params.fwd_prices()?.deref(), &expected_fwd_prices);
+        assert_eq!(config_params.fwd_prices_mc()?.lump_price, 10000000);
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_params_typed() -> anyhow::Result<()> {
+        fn assert_roundtrip<T: TLB>(config_params: &ConfigParams, index: u32, parsed: &T) -> anyhow::Result<()> {
+            assert_eq!(&parsed.to_cell_ref()?, config_params.config.get(&index).unwrap(), "param {index}");
+            Ok(())
+        }
+        let config_params = ConfigParams::from_boc_hex(CONFIG_BOC_HEX)?;
+
+        assert_eq!(config_params.config_smc_addr()?.deref(), &config_params.config_addr);
+        assert_eq!(config_params.fee_collector_addr()?, config_params.elector_addr()?); // param 3 is absent
+        assert_roundtrip(&config_params, 2, config_params.minter_addr()?.deref())?;
+        assert_roundtrip(&config_params, 4, config_params.dns_root_addr()?.deref())?;
+        assert_roundtrip(&config_params, 5, config_params.burning_config()?.deref())?;
+
+        let workchains = config_params.workchains()?;
+        assert_roundtrip(&config_params, 12, workchains.deref())?;
+        let basechain = workchains.workchains.get(&0).unwrap();
+        assert!(basechain.active() && basechain.accept_msgs());
+        assert!(matches!(basechain.format(), WorkchainFormat::Basic(_)));
+
+        assert_roundtrip(&config_params, 15, config_params.election_timings()?.deref())?;
+        assert_roundtrip(&config_params, 16, config_params.validators_count()?.deref())?;
+        assert_roundtrip(&config_params, 17, config_params.stakes_config()?.deref())?;
+        assert_roundtrip(&config_params, 22, config_params.block_limits_mc()?.deref())?;
+        assert_roundtrip(&config_params, 23, config_params.block_limits()?.deref())?;
+        assert_roundtrip(&config_params, 31, config_params.fundamental_smc_addr()?.deref())?;
+        assert!(config_params.fundamental_smc_addr()?.contains(config_params.elector_addr()?.deref()));
+
+        let prev_validators = config_params.prev_validators()?;
+        let cur_validators = config_params.cur_validators()?;
+        assert_roundtrip(&config_params, 32, prev_validators.deref())?;
+        assert_roundtrip(&config_params, 34, cur_validators.deref())?;
+        assert_eq!(prev_validators.utime_until(), cur_validators.utime_since());
+        assert_eq!(cur_validators.list().len(), cur_validators.total() as usize);
+        assert!(config_params.next_validators().is_err());
+
+        assert_roundtrip(&config_params, 44, config_params.suspended_addresses()?.deref())?;
+        assert_roundtrip(&config_params, 45, config_params.precompiled_contracts()?.deref())?;
+        assert_roundtrip(&config_params, 71, config_params.eth_bridge()?.deref())?;
+        assert_roundtrip(&config_params, 72, config_params.bsc_bridge()?.deref())?;
+        assert_roundtrip(&config_params, 79, config_params.eth_jetton_bridge()?.deref())?;
+
+        Ok(())
+    }
+
+    // params 40, 43, 73, 81 & 82 are absent in test configs, so their cells are built bit by bit
+    #[test]
+    fn test_config_params_typed_absent() -> anyhow::Result<()> {
+        fn assert_roundtrip<T: TLB>(config_params: &ConfigParams, index: u32, parsed: &T) -> anyhow::Result<()> {
+            assert_eq!(&parsed.to_cell_ref()?, config_params.config.get(&index).unwrap(), "param {index}");
+            Ok(())
+        }
+        let mut config_params = ConfigParams::from_boc_hex(CONFIG_BOC_HEX)?;
+        let hash = |byte: u8| TonHash::from([byte; 32]);
+
+        let mut builder = TonCell::builder();
+        builder.write_num(&0x01u8, 8)?;
+        Coins::new(101_000_000_000u64).write(&mut builder)?;
+        builder.write_num(&(1u32 << 26), 32)?;
+        for value in [256u16, 640, 4096, 16384, 4, 2, 8, 1000, 5] {
+            builder.write_num(&value, 16)?;
+        }
+        config_params.set_param_raw(40, builder.build()?.into_ref());
+        let expected_punishment = MisbehaviourPunishmentConfig {
+            default_flat_fine: Coins::new(101_000_000_000u64),
+            default_proportional_fine: 1 << 26,
+            severity_flat_mult: 256,
+            severity_proportional_mult: 640,
+            unpunishable_interval: 4096,
+            long_interval: 16384,
+            long_flat_mult: 4,
+            long_proportional_mult: 2,
+            medium_interval: 8,
+            medium_flat_mult: 1000,
+            medium_proportional_mult: 5,
+        };
+        let punishment = config_params.misbehaviour_punishment()?;
+        assert_eq!(punishment.deref(), &expected_punishment);
+        assert_roundtrip(&config_params, 40, punishment.deref())?;
+
+        let mut builder = TonCell::builder();
+        builder.write_num(&0x02u8, 8)?;
+        builder.write_num(&(1u32 << 21), 32)?;
+        builder.write_num(&(1u32 << 13), 32)?;
+        builder.write_num(&1000u32, 32)?;
+        builder.write_num(&512u16, 16)?;
+        builder.write_num(&65535u32, 32)?;
+        builder.write_num(&512u16, 16)?;
+        for value in [1u32 << 16, (1 << 16) * 1023, 256, 256, 2] {
+            builder.write_num(&value, 32)?;
+        }
+        builder.write_num(&8u8, 8)?;
+        config_params.set_param_raw(43, builder.build()?.into_ref());
+        let expected_limits = SizeLimitsConfig::V2(SizeLimitsConfigV2 {
+            max_msg_bits: 1 << 21,
+            max_msg_cells: 1 << 13,
+            max_library_cells: 1000,
+            max_vm_data_depth: 512,
+            max_ext_msg_size: 65535,
+            max_ext_msg_depth: 512,
+            max_acc_state_cells: 1 << 16,
+            max_acc_state_bits: (1 << 16) * 1023,
+            max_acc_public_libraries: 256,
+            defer_out_queue_size_limit: 256,
+            max_msg_extra_currencies: 2,
+            max_acc_fixed_prefix_length: 8,
+        });
+        let size_limits = config_params.size_limits()?;
+        assert_eq!(size_limits.deref(), &expected_limits);
+        assert_eq!((size_limits.max_msg_bits(), size_limits.max_ext_msg_depth()), (1 << 21, 512));
+        assert_roundtrip(&config_params, 43, size_limits.deref())?;
+
+        let mut builder = TonCell::builder();
+        builder.write_bits(hash(1).as_slice(), 256)?;
+        builder.write_bits(hash(2).as_slice(), 256)?;
+        builder.write_bit(false)?; // no oracles
+        builder.write_bits(hash(3).as_slice(), 256)?;
+        config_params.set_param_raw(73, builder.build()?.into_ref());
+        let expected_bridge = OracleBridgeParams {
+            bridge_address: hash(1),
+            oracle_multisig_address: hash(2),
+            oracles: HashMap::new(),
+            external_chain_address: hash(3),
+        };
+        let polygon_bridge = config_params.polygon_bridge()?;
+        assert_eq!(polygon_bridge.deref(), &expected_bridge);
+        assert_roundtrip(&config_params, 73, polygon_bridge.deref())?;
+
+        let mut builder = TonCell::builder();
+        builder.write_num(&0x00u8, 8)?;
+        builder.write_bits(hash(4).as_slice(), 256)?;
+        builder.write_bits(hash(5).as_slice(), 256)?;
+        builder.write_bit(false)?;
+        builder.write_num(&1u8, 8)?;
+        Coins::new(1_000_000u64).write(&mut builder)?;
+        config_params.set_param_raw(81, builder.build()?.into_ref());
+        let expected_bnb = JettonBridgeParams::V0(JettonBridgeParamsV0 {
+            bridge_address: hash(4),
+            oracles_address: hash(5),
+            oracles: HashMap::new(),
+            state_flags: 1,
+            burn_bridge_fee: Coins::new(1_000_000u64),
+        });
+        let bnb_bridge = config_params.bnb_jetton_bridge()?;
+        assert_eq!(bnb_bridge.deref(), &expected_bnb);
+        assert_roundtrip(&config_params, 81, bnb_bridge.deref())?;
+
+        let mut prices_builder = TonCell::builder();
+        for value in [1u64, 2, 3, 4, 5, 6] {
+            Coins::new(value * 1_000_000).write(&mut prices_builder)?;
+        }
+        let mut builder = TonCell::builder();
+        builder.write_num(&0x01u8, 8)?;
+        builder.write_bits(hash(6).as_slice(), 256)?;
+        builder.write_bits(hash(7).as_slice(), 256)?;
+        builder.write_bit(false)?;
+        builder.write_num(&0u8, 8)?;
+        builder.write_ref(prices_builder.build()?.into_ref())?;
+        builder.write_bits(hash(8).as_slice(), 256)?;
+        config_params.set_param_raw(82, builder.build()?.into_ref());
+        let expected_polygon = JettonBridgeParams::V1(JettonBridgeParamsV1 {
+            bridge_address: hash(6),
+            oracles_address: hash(7),
+            oracles: HashMap::new(),
+            state_flags: 0,
+            prices: JettonBridgePrices {
+                bridge_burn_fee: Coins::new(1_000_000u64),
+                bridge_mint_fee: Coins::new(2_000_000u64),
+                wallet_min_tons_for_storage: Coins::new(3_000_000u64),
+                wallet_gas_consumption: Coins::new(4_000_000u64),
+                minter_min_tons_for_storage: Coins::new(5_000_000u64),
+                discover_gas_consumption: Coins::new(6_000_000u64),
+            },
+            external_chain_address: hash(8),
+        });
+        let polygon_jetton_bridge = config_params.polygon_jetton_bridge()?;
+        assert_eq!(polygon_jetton_bridge.deref(), &expected_polygon);
+        assert_roundtrip(&config_params, 82, polygon_jetton_bridge.deref())?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_params_setters() -> anyhow::Result<()> {
+        let dict_hex = include_str!("../../../../resources/tests/bc_config_key_block_42123611.hex");
+        let dict_cell = TonCell::from_boc_hex(dict_hex)?;
+        let mut config_params = ConfigParams::from_dict_cell(&dict_cell)?;
+        assert_eq!(config_params.to_dict_cell()?, dict_cell);
+        assert_eq!(config_params.config_addr, config_params.config_smc_addr()?.deref().clone());
+
+        let mut gas_prices = config_params.gas_prices()?.deref().clone();
+        let GasLimitsPrices::Flat(flat) = &mut gas_prices else {
+            panic!("unexpected gas prices format")
+        };
+        flat.flat_gas_price = 1;
+        config_params.set_gas_prices(gas_prices.clone())?;
+        let global_version = GlobalVersion {
+            version: 100,
+            capabilities: config_params.global_version()?.capabilities | 1 << 20,
+        };
+        config_params.set_global_version(global_version.clone())?;
+        let mut precompiled = config_params.precompiled_contracts()?.deref().clone();
+        precompiled.list.insert(TonHash::from([1u8; 32]), PrecompiledSmc { gas_usage: 777 });
+        config_params.set_precompiled_contracts(precompiled.clone())?;
+
+        assert_eq!(config_params.gas_prices()?.deref(), &gas_prices);
+        let parsed = ConfigParams::from_dict_cell(&config_params.to_dict_cell()?)?;
+        assert_eq!(parsed, config_params);
+        assert_eq!(parsed.gas_prices()?.deref(), &gas_prices);
+        assert_eq!(parsed.global_version()?.deref(), &global_version);
+        assert_eq!(parsed.precompiled_contracts()?.deref(), &precompiled);
+        assert_eq!(parsed.gas_prices_mc()?, config_params.gas_prices_mc()?);
+
+        config_params.set_param_raw(
+            8,
+            GlobalVersion {
+                version: 1,
+                capabilities: 0,
+            }
+            .to_cell_ref()?,
+        );
+        assert_eq!(config_params.global_version()?.version, 1);
+        assert!(config_params.remove_param(8).is_some());
+        assert!(config_params.global_version().is_err());
+        Ok(())
+    }
 }
@@ -1,7 +1,33 @@
+mod config_param_12;
+mod config_param_15;
 mod config_param_18;
+mod config_param_20;
+mod config_param_22;
+mod config_param_24;
+mod config_param_31;
+mod config_param_32;
+mod config_param_40;
+mod config_param_43;
+mod config_param_44;
+mod config_param_45;
+mod config_param_5;
+mod config_param_71;
 mod config_param_8;
 mod config_params;
 
+pub use config_param_12::*;
+pub use config_param_15::*;
 pub use config_param_18::*;
+pub use config_param_20::*;
+pub use config_param_22::*;
+pub use config_param_24::*;
+pub use config_param_31::*;
+pub use config_param_32::*;
+pub use config_param_40::*;
+pub use config_param_43::*;
+pub use config_param_44::*;
+pub use config_param_45::*;
+pub use config_param_5::*;
+pub use config_param_71::*;
 pub use config_param_8::*;
 pub use config_params::*;
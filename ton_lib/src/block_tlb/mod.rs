@@ -1,5 +1,5 @@
 #[cfg(test)]
-mod _test_block_data;
+pub(crate) mod _test_block_data;
 
 mod account;
 mod block_types;
@@ -8,6 +8,7 @@ mod config_types;
 mod hash_update;
 mod msg_types;
 mod out_action;
+mod send_mode;
 mod shard_types;
 mod state_init;
 mod tvm_types;
@@ -20,6 +21,7 @@ pub use config_types::*;
 pub use hash_update::*;
 pub use msg_types::*;
 pub use out_action::*;
+pub use send_mode::*;
 pub use shard_types::*;
 pub use state_init::*;
 pub use tvm_types::*;
@@ -0,0 +1,56 @@
+use std::ops::{BitOr, BitOrAssign};
+
+/// Mode of `OutAction::SendMsg`
+/// https://docs.ton.org/v3/documentation/smart-contracts/message-management/sending-messages#message-modes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SendMode(pub u8);
+
+impl SendMode {
+    pub const ORDINARY: SendMode = SendMode(0);
+    pub const PAY_FEES_SEPARATELY: SendMode = SendMode(1);
+    pub const IGNORE_ERRORS: SendMode = SendMode(2);
+    pub const BOUNCE_ON_ACTION_FAIL: SendMode = SendMode(16);
+    pub const DESTROY_IF_ZERO: SendMode = SendMode(32);
+    pub const CARRY_REMAINING_VALUE: SendMode = SendMode(64);
+    pub const CARRY_ALL_BALANCE: SendMode = SendMode(128);
+    /// Mode used by wallets by default
+    pub const DEFAULT: SendMode = SendMode(Self::PAY_FEES_SEPARATELY.0 | Self::IGNORE_ERRORS.0);
+
+    pub const fn bits(self) -> u8 { self.0 }
+    pub const fn contains(self, other: SendMode) -> bool { self.0 & other.0 == other.0 }
+}
+
+impl Default for SendMode {
+    fn default() -> Self { Self::DEFAULT }
+}
+
+impl BitOr for SendMode {
+    type Output = SendMode;
+    fn bitor(self, rhs: Self) -> Self::Output { SendMode(self.0 | rhs.0) }
+}
+
+impl BitOrAssign for SendMode {
+    fn bitor_assign(&mut self, rhs: Self) { self.0 |= rhs.0 }
+}
+
+impl From<u8> for SendMode {
+    fn from(value: u8) -> Self { SendMode(value) }
+}
+
+impl From<SendMode> for u8 {
+    fn from(value: SendMode) -> Self { value.0 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_send_mode() {
+        assert_eq!(SendMode::DEFAULT.bits(), 3);
+        let mode = SendMode::CARRY_ALL_BALANCE | SendMode::DESTROY_IF_ZERO;
+        assert_eq!(mode.bits(), 160);
+        assert!(mode.contains(SendMode::DESTROY_IF_ZERO));
+        assert!(!mode.contains(SendMode::IGNORE_ERRORS));
+    }
+}
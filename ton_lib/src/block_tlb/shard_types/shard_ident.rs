@@ -72,6 +72,16 @@ impl ShardIdent {
         BitsUtils::equal(&self.shard.to_be_bytes(), addr.address_hash(), pfx_len_bits as usize)
     }
 
+    /// true if one shard is an ancestor (or the same) of another one
+    pub fn intersects(&self, other: &ShardIdent) -> bool {
+        if self.workchain != other.workchain {
+            return false;
+        }
+        let lower_bits = self.prefix_lower_bits().max(other.prefix_lower_bits());
+        let pfx_mask = !(lower_bits << 1).wrapping_sub(1);
+        (self.shard ^ other.shard) & pfx_mask == 0
+    }
+
     fn prefix_lower_bits(&self) -> u64 { self.shard & (!self.shard).wrapping_add(1) }
 }
 
@@ -191,6 +201,22 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_shard_ident_intersects() -> anyhow::Result<()> {
+        let shard_ident = ShardIdent::new(0, 0x8000000000000000);
+        let (left, right) = shard_ident.split()?;
+        let (left2, right2) = left.split()?;
+        assert!(shard_ident.intersects(&left2));
+        assert!(left2.intersects(&shard_ident));
+        assert!(left.intersects(&right2));
+        assert!(left.intersects(&left));
+        assert!(!left.intersects(&right));
+        assert!(!right.intersects(&left2));
+        assert!(!left2.intersects(&right2));
+        assert!(!shard_ident.intersects(&ShardIdent::new_mc()));
+        Ok(())
+    }
+
     #[test]
     fn test_shard_ident_contains_addr() -> anyhow::Result<()> {
         let addr = TonAddress::from_str("EQDc_nrm5oOVCVQM8GRJ5q_hr1jgpNQjsGkIGE-uztt26_Ep")?;
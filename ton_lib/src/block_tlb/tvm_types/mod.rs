@@ -1,9 +1,11 @@
 mod tvm_cell_slice;
 mod tvm_stack;
+mod tvm_stack_arg;
 mod tvm_stack_value;
 mod tvm_tuple;
 
 pub use tvm_cell_slice::*;
 pub use tvm_stack::*;
+pub use tvm_stack_arg::*;
 pub use tvm_stack_value::*;
 pub use tvm_tuple::*;
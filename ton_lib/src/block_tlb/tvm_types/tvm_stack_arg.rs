@@ -0,0 +1,109 @@
+use crate::block_tlb::TVMStack;
+use crate::error::TLError;
+use num_bigint::{BigInt, Sign};
+use ton_lib_core::cell::{TonCell, TonCellRef, TonHash};
+use ton_lib_core::traits::tlb::TLB;
+use ton_lib_core::types::TonAddress;
+
+/// Get-method argument, used by `#[get_method]` declarations in `#[ton_contract]` traits
+pub trait TVMStackArg {
+    fn push_to(&self, stack: &mut TVMStack) -> Result<(), TLError>;
+}
+
+impl<T: TVMStackArg + ?Sized> TVMStackArg for &T {
+    fn push_to(&self, stack: &mut TVMStack) -> Result<(), TLError> { (*self).push_to(stack) }
+}
+
+macro_rules! impl_tiny_int_arg {
+    ($($t:ty),*) => {
+        $(impl TVMStackArg for $t {
+            fn push_to(&self, stack: &mut TVMStack) -> Result<(), TLError> {
+                stack.push_tiny_int(*self as i64);
+                Ok(())
+            }
+        })*
+    };
+}
+
+macro_rules! impl_big_int_arg {
+    ($($t:ty),*) => {
+        $(impl TVMStackArg for $t {
+            fn push_to(&self, stack: &mut TVMStack) -> Result<(), TLError> {
+                match i64::try_from(*self) {
+                    Ok(value) => stack.push_tiny_int(value),
+                    Err(_) => stack.push_int(BigInt::from(*self)),
+                }
+                Ok(())
+            }
+        })*
+    };
+}
+
+impl_tiny_int_arg!(i8, i16, i32, i64, u8, u16, u32);
+impl_big_int_arg!(u64, i128, u128);
+
+/// TVM uses -1 for true
+impl TVMStackArg for bool {
+    fn push_to(&self, stack: &mut TVMStack) -> Result<(), TLError> {
+        stack.push_tiny_int(if *self { -1 } else { 0 });
+        Ok(())
+    }
+}
+
+impl TVMStackArg for BigInt {
+    fn push_to(&self, stack: &mut TVMStack) -> Result<(), TLError> {
+        stack.push_int(self.clone());
+        Ok(())
+    }
+}
+
+/// Pushed as uint256
+impl TVMStackArg for TonHash {
+    fn push_to(&self, stack: &mut TVMStack) -> Result<(), TLError> {
+        stack.push_int(BigInt::from_bytes_be(Sign::Plus, self.as_slice()));
+        Ok(())
+    }
+}
+
+impl TVMStackArg for TonCellRef {
+    fn push_to(&self, stack: &mut TVMStack) -> Result<(), TLError> {
+        stack.push_cell(self.clone());
+        Ok(())
+    }
+}
+
+impl TVMStackArg for TonCell {
+    fn push_to(&self, stack: &mut TVMStack) -> Result<(), TLError> {
+        stack.push_cell(self.clone().into_ref());
+        Ok(())
+    }
+}
+
+/// Pushed as slice, like contracts expect in `get_wallet_address(slice owner)`
+impl TVMStackArg for TonAddress {
+    fn push_to(&self, stack: &mut TVMStack) -> Result<(), TLError> {
+        stack.push_cell_slice(self.to_cell_ref()?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tvm_stack_arg() -> anyhow::Result<()> {
+        let mut stack = TVMStack::default();
+        true.push_to(&mut stack)?;
+        u64::MAX.push_to(&mut stack)?;
+        TVMStackArg::push_to(&&42u32, &mut stack)?;
+        TonHash::from([1u8; 32]).push_to(&mut stack)?;
+
+        assert_eq!(stack.pop_int()?, BigInt::from_bytes_be(Sign::Plus, &[1u8; 32]));
+        assert_eq!(stack.pop_tiny_int()?, 42);
+        assert_eq!(stack.pop_int()?, BigInt::from(u64::MAX));
+        assert_eq!(stack.pop_tiny_int()?, -1);
+        assert!(stack.is_empty());
+        Ok(())
+    }
+}
@@ -136,5 +136,17 @@ impl TxDescr {
         }
     }
 
+    pub fn action_phase(&self) -> Option<&TrActionPhase> {
+        match &self {
+            TxDescr::Ord(descr) => descr.action.as_ref(),
+            TxDescr::Storage(_) => None,
+            TxDescr::TickTock(descr) => descr.action.as_ref(),
+            TxDescr::SplitPrepare(descr) => descr.action.as_ref(),
+            TxDescr::SplitInstall(_) => None,
+            TxDescr::MergePrepare(_) => None,
+            TxDescr::MergeInstall(descr) => descr.action.as_ref(),
+        }
+    }
+
     pub fn exit_code(&self) -> Option<i32> { self.compute_phase().map(|x| x.compute_phase_vm_info.exit_code) }
 }
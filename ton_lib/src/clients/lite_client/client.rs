@@ -1,5 +1,5 @@
 use super::connection::Connection;
-use crate::block_tlb::{BlockIdExt, MaybeAccount};
+use crate::block_tlb::{BlockIdExt, ConfigParams, MaybeAccount};
 use crate::clients::client_types::MasterchainInfo;
 use crate::clients::lite_client::config::{LiteClientConfig, LiteReqParams};
 use crate::error::TLError;
@@ -14,14 +14,16 @@ use std::sync::Arc;
 use std::time::Duration;
 use tokio_retry::strategy::FixedInterval;
 use tokio_retry::RetryIf;
-use ton_lib_core::cell::{TonCellRef, TonHash};
+use ton_lib_core::boc::BOC;
+use ton_lib_core::cell::{TonCell, TonCellRef, TonHash};
 use ton_lib_core::constants::{TON_MASTERCHAIN, TON_SHARD_FULL};
 use ton_lib_core::error::TLCoreError;
 use ton_lib_core::traits::tlb::TLB;
-use ton_lib_core::types::TonAddress;
+use ton_lib_core::types::{TonAddress, TxIdLTHash};
 use ton_liteapi::tl::common::{AccountId, Int256};
 use ton_liteapi::tl::request::{
-    GetAccountState, GetBlock, GetLibraries, LookupBlock, Request, WaitMasterchainSeqno, WrappedRequest,
+    GetAccountState, GetBlock, GetConfigAll, GetLibraries, GetTransactions, LookupBlock, Request, WaitMasterchainSeqno,
+    WrappedRequest,
 };
 use ton_liteapi::tl::response::{BlockData, Response};
 
@@ -85,19 +87,79 @@ impl LiteClient {
         address: &TonAddress,
         mc_seqno: u32,
         params: Option<LiteReqParams>,
+    ) -> Result<MaybeAccount, TLError> {
+        let block_id = self.lookup_mc_block(mc_seqno).await?;
+        self.get_account_state_by_block(address, &block_id, params).await
+    }
+
+    /// Returns account state at the end of the block. Both masterchain and shard blocks are supported
+    pub async fn get_account_state_by_block(
+        &self,
+        address: &TonAddress,
+        block_id: &BlockIdExt,
+        params: Option<LiteReqParams>,
     ) -> Result<MaybeAccount, TLError> {
         let req = Request::GetAccountState(GetAccountState {
-            id: self.lookup_mc_block(mc_seqno).await?.into(),
-            account: AccountId {
-                workchain: address.workchain,
-                id: Int256(*address.hash.as_slice_sized()),
-            },
+            id: block_id.clone().into(),
+            account: make_account_id(address),
         });
-        let rsp = self.exec_with_timeout(req, Some(mc_seqno), params).await?;
+        let wait_seqno = block_id.shard_ident.workchain == TON_MASTERCHAIN;
+        let rsp = self.exec_with_timeout(req, wait_seqno.then_some(block_id.seqno), params).await?;
         let account_state_rsp = unwrap_lite_response!(rsp, AccountState)?;
         Ok(MaybeAccount::from_boc(&account_state_rsp.state)?)
     }
 
+    /// Returns up to `count` account txs, starting from `from_tx` (inclusive) and going back in time
+    ///
+    /// Each tx is returned with id of the block it belongs to
+    pub async fn get_txs(
+        &self,
+        address: &TonAddress,
+        from_tx: &TxIdLTHash,
+        count: u32,
+        params: Option<LiteReqParams>,
+    ) -> Result<Vec<(BlockIdExt, TonCellRef)>, TLError> {
+        let req = Request::GetTransactions(GetTransactions {
+            count,
+            account: make_account_id(address),
+            lt: from_tx.lt as u64,
+            hash: Int256(*from_tx.hash.as_slice_sized()),
+        });
+        let rsp = self.exec(req, None, params).await?;
+        let tx_list = unwrap_lite_response!(rsp, TransactionList)?;
+        if tx_list.ids.is_empty() {
+            return Ok(vec![]);
+        }
+        let txs = BOC::from_bytes(&tx_list.transactions)?.into_roots();
+        if txs.len() != tx_list.ids.len() {
+            let err_msg = format!("{} txs for {} block_ids", txs.len(), tx_list.ids.len());
+            return Err(TLError::LiteClientWrongResponse("TransactionList".to_string(), err_msg));
+        }
+        Ok(tx_list.ids.into_iter().map(BlockIdExt::from).zip(txs).collect())
+    }
+
+    /// Returns blockchain config from the state of masterchain block
+    pub async fn get_config(&self, mc_seqno: u32, params: Option<LiteReqParams>) -> Result<ConfigParams, TLError> {
+        let req = Request::GetConfigAll(GetConfigAll {
+            mode: (),
+            id: self.lookup_mc_block(mc_seqno).await?.into(),
+            with_state_root: None,
+            with_libraries: None,
+            with_state_extra_root: None,
+            with_shard_hashes: None,
+            with_validator_set: None,
+            with_special_smc: None,
+            with_accounts_root: None,
+            with_prev_blocks: None,
+            with_workchain_info: None,
+            with_capabilities: None,
+            extract_from_key_block: None,
+        });
+        let rsp = self.exec(req, Some(mc_seqno), params).await?;
+        let config_info = unwrap_lite_response!(rsp, ConfigInfo)?;
+        parse_config_proof(&config_info.config_proof)
+    }
+
     pub async fn get_libs(&self, lib_ids: &[TonHash], params: Option<LiteReqParams>) -> Result<LibsDict, TLError> {
         self.inner.get_libs_impl(lib_ids, params).await
     }
@@ -226,4 +288,33 @@ impl Inner {
     }
 }
 
+fn make_account_id(address: &TonAddress) -> AccountId {
+    AccountId {
+        workchain: address.workchain,
+        id: Int256(*address.hash.as_slice_sized()),
+    }
+}
+
+// config_proof is a merkle proof of ShardStateUnsplit, McStateExtra is stored in its 4th ref (custom field)
+// https://github.com/ton-blockchain/ton/blob/6f745c04daf8861bb1791cffce6edb1beec62204/crypto/block/block.tlb#L412
+fn parse_config_proof(proof_boc: &[u8]) -> Result<ConfigParams, TLError> {
+    let proof = TonCell::from_boc(proof_boc)?;
+    let state = proof.refs.first().ok_or_else(|| TLCoreError::TLBWrongData("empty config proof".to_string()))?;
+    let mc_state_extra =
+        state.refs.get(3).ok_or_else(|| TLCoreError::TLBWrongData("no McStateExtra in config proof".to_string()))?;
+    let mut parser = mc_state_extra.parser();
+    let prefix: u16 = parser.read_num(16)?;
+    if prefix != 0xcc26 {
+        return Err(TLError::UnexpectedValue {
+            expected: "McStateExtra prefix 0xcc26".to_string(),
+            actual: format!("{prefix:#x}"),
+        });
+    }
+    // skip shard_hashes:(HashmapE 32 ^(BinTree ShardDescr))
+    if parser.read_bit()? {
+        parser.read_next_ref()?;
+    }
+    Ok(ConfigParams::read(&mut parser)?)
+}
+
 fn retry_condition(error: &TLError) -> bool { !matches!(error, TLError::LiteClientWrongResponse(..)) }
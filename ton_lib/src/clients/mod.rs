@@ -3,3 +3,4 @@ pub mod lite_client;
 pub mod net_config;
 #[cfg(feature = "tonlibjson")]
 pub mod tl_client;
+pub mod toncenter;
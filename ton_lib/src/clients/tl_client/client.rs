@@ -1,5 +1,8 @@
 use crate::clients::tl_client::connection::TLConnection;
 use crate::clients::tl_client::env::prepare_client_env;
+use crate::clients::tl_client::tl::client::exec_with_retry;
+use crate::clients::tl_client::tl::request::TLRequest;
+use crate::clients::tl_client::tl::response::TLResponse;
 use crate::clients::tl_client::RetryStrategy;
 use crate::clients::tl_client::{config::TLClientConfig, tl::client::TLClientTrait};
 use crate::error::TLError;
@@ -25,12 +28,14 @@ struct Inner {
 
 #[async_trait]
 impl TLClientTrait for TLClient {
-    fn get_connection(&self) -> &TLConnection {
+    fn get_connection(&self) -> &dyn TLClientTrait {
         let mut rng_lock = self.inner.rnd.lock().unwrap();
         self.inner.connections.choose(&mut rng_lock.deref_mut()).unwrap()
     }
 
     fn get_retry_strategy(&self) -> &RetryStrategy { &self.inner.config.retry_strategy }
+
+    async fn exec(&self, req: &TLRequest) -> Result<TLResponse, TLError> { exec_with_retry(self, req).await }
 }
 
 impl TLClient {
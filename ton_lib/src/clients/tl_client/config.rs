@@ -29,6 +29,13 @@ pub struct RetryStrategy {
     pub retry_waiting: Duration,
 }
 
+impl RetryStrategy {
+    pub const NO_RETRY: RetryStrategy = RetryStrategy {
+        retry_count: 0,
+        retry_waiting: Duration::ZERO,
+    };
+}
+
 impl TLClientConfig {
     pub fn new(net_config_json: String, archive_only: bool) -> TLClientConfig {
         let connection_check = match archive_only {
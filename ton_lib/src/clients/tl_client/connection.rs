@@ -2,7 +2,7 @@ use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Weak};
 use std::thread;
-use std::time::{Duration, Instant};
+use std::time::Instant;
 
 use crate::clients::tl_client::config::{LiteNodeFilter, TLClientConfig};
 use crate::clients::tl_client::tl::client::TLClientTrait;
@@ -39,15 +39,11 @@ struct Inner {
 
 #[async_trait]
 impl TLClientTrait for TLConnection {
-    fn get_connection(&self) -> &TLConnection { self }
+    fn get_connection(&self) -> &dyn TLClientTrait { self }
 
-    fn get_retry_strategy(&self) -> &RetryStrategy {
-        static NO_RETRY: RetryStrategy = RetryStrategy {
-            retry_count: 0,
-            retry_waiting: Duration::from_millis(0),
-        };
-        &NO_RETRY
-    }
+    fn get_retry_strategy(&self) -> &RetryStrategy { &RetryStrategy::NO_RETRY }
+
+    async fn exec(&self, req: &TLRequest) -> Result<TLResponse, TLError> { self.exec_impl(req).await }
 }
 
 impl TLConnection {
@@ -6,8 +6,10 @@ mod callback;
 mod client;
 mod config;
 mod connection;
+mod recorder;
 
 pub use callback::*;
 pub use client::*;
 pub use config::*;
 pub use connection::*;
+pub use recorder::*;
@@ -0,0 +1,188 @@
+use crate::clients::tl_client::tl::client::TLClientTrait;
+use crate::clients::tl_client::tl::request::TLRequest;
+use crate::clients::tl_client::tl::response::TLResponse;
+use crate::clients::tl_client::RetryStrategy;
+use crate::error::TLError;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+use std::sync::mpsc;
+use std::thread::{self, JoinHandle};
+
+#[derive(Serialize, Deserialize)]
+struct TLRecord {
+    request: TLRequest,
+    response: TLResponse,
+}
+
+/// Appends every successful `exec` request & response to the file as a json line
+///
+/// Requests sent directly through `get_connection()` bypass the recorder
+///
+/// Lines are written by a background thread, all of them are flushed on drop
+pub struct TLRecorder<C: TLClientTrait> {
+    client: C,
+    sender: Option<mpsc::Sender<Vec<u8>>>,
+    writer: Option<JoinHandle<()>>,
+}
+
+impl<C: TLClientTrait> TLRecorder<C> {
+    /// Appends to the file if it exists
+    pub fn new<P: AsRef<Path>>(client: C, path: P) -> Result<Self, TLError> {
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        let (sender, receiver) = mpsc::channel::<Vec<u8>>();
+        let writer = thread::Builder::new().name("tl_recorder".to_string()).spawn(move || {
+            for line in receiver {
+                if let Err(err) = file.write_all(&line) {
+                    log::error!("[TLRecorder] failed to write record: {err}");
+                }
+            }
+        })?;
+        Ok(Self {
+            client,
+            sender: Some(sender),
+            writer: Some(writer),
+        })
+    }
+}
+
+impl<C: TLClientTrait> Drop for TLRecorder<C> {
+    fn drop(&mut self) {
+        drop(self.sender.take());
+        if let Some(writer) = self.writer.take() {
+            let _ = writer.join();
+        }
+    }
+}
+
+#[async_trait]
+impl<C: TLClientTrait> TLClientTrait for TLRecorder<C> {
+    fn get_connection(&self) -> &dyn TLClientTrait { self.client.get_connection() }
+
+    fn get_retry_strategy(&self) -> &RetryStrategy { self.client.get_retry_strategy() }
+
+    async fn exec(&self, req: &TLRequest) -> Result<TLResponse, TLError> {
+        let response = self.client.exec(req).await?;
+        let record = TLRecord {
+            request: req.clone(),
+            response,
+        };
+        let mut line = serde_json::to_vec(&record)?;
+        line.push(b'\n');
+        if let Some(Err(err)) = self.sender.as_ref().map(|x| x.send(line)) {
+            log::error!("[TLRecorder] writer is stopped, record is lost: {err}");
+        }
+        Ok(record.response)
+    }
+}
+
+/// Serves responses recorded by TLRecorder, the first one is used if request was recorded several times
+///
+/// Can replace TLClient anywhere TLClientTrait is accepted, e.g. in TLProvider
+pub struct TLReplayer {
+    responses: HashMap<TLRequest, TLResponse>,
+}
+
+impl TLReplayer {
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, TLError> {
+        let mut responses = HashMap::new();
+        for line in BufReader::new(File::open(path)?).lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let record: TLRecord = serde_json::from_str(&line)?;
+            responses.entry(record.request).or_insert(record.response);
+        }
+        Ok(Self { responses })
+    }
+}
+
+#[async_trait]
+impl TLClientTrait for TLReplayer {
+    fn get_connection(&self) -> &dyn TLClientTrait { self }
+
+    fn get_retry_strategy(&self) -> &RetryStrategy { &RetryStrategy::NO_RETRY }
+
+    async fn exec(&self, req: &TLRequest) -> Result<TLResponse, TLError> {
+        match self.responses.get(req) {
+            Some(response) => Ok(response.clone()),
+            None => Err(TLError::TLWrongUsage(format!("request is not recorded: {req:?}"))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block_tlb::BlockIdExt;
+    use crate::clients::tl_client::tl::types::TLBlocksMCInfo;
+    use crate::contracts::client::tl_provider::TLProvider;
+    use ton_lib_core::traits::contract_provider::ContractProvider;
+
+    #[tokio::test]
+    async fn test_tl_replayer() -> anyhow::Result<()> {
+        let path = std::env::temp_dir().join(format!("ton_lib_tl_records_{}.jsonl", std::process::id()));
+        let request = TLRequest::GetConfigAll { mode: 0 };
+        let record = serde_json::json!({
+            "request": request,
+            "response": {"@type": "logVerbosityLevel", "verbosity_level": 2},
+        });
+        let mc_info_record = TLRecord {
+            request: TLRequest::BlocksGetMCInfo {},
+            response: TLResponse::TLBlocksMCInfo(TLBlocksMCInfo {
+                last: BlockIdExt {
+                    seqno: 100,
+                    ..BlockIdExt::ZERO_BLOCK_ID
+                },
+                state_root_hash: vec![],
+                init: BlockIdExt::ZERO_BLOCK_ID,
+            }),
+        };
+        let mc_info_record = serde_json::to_string(&mc_info_record)?;
+        std::fs::write(&path, format!("{record}\n\n{record}\n{mc_info_record}\n"))?;
+        let replayer = TLReplayer::from_file(&path);
+        std::fs::remove_file(&path)?;
+        let replayer = replayer?;
+
+        assert!(matches!(replayer.exec(&request).await?, TLResponse::TLLogVerbosityLevel(_)));
+        assert!(replayer.exec(&TLRequest::GetConfigAll { mode: 1 }).await.is_err());
+        assert_eq!(replayer.get_mc_info().await?.last.seqno, 100);
+
+        let provider = TLProvider::new(replayer);
+        assert_eq!(provider.last_mc_seqno().await?, 100);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_tl_recorder() -> anyhow::Result<()> {
+        let src_path = std::env::temp_dir().join(format!("ton_lib_tl_recorder_src_{}.jsonl", std::process::id()));
+        let dst_path = std::env::temp_dir().join(format!("ton_lib_tl_recorder_dst_{}.jsonl", std::process::id()));
+        let record = TLRecord {
+            request: TLRequest::BlocksGetMCInfo {},
+            response: TLResponse::TLBlocksMCInfo(TLBlocksMCInfo {
+                last: BlockIdExt::ZERO_BLOCK_ID,
+                state_root_hash: vec![],
+                init: BlockIdExt::ZERO_BLOCK_ID,
+            }),
+        };
+        std::fs::write(&src_path, serde_json::to_string(&record)?)?;
+        let replayer = TLReplayer::from_file(&src_path)?;
+        std::fs::remove_file(&src_path)?;
+
+        let recorder = TLRecorder::new(replayer, &dst_path)?;
+        assert_eq!(recorder.exec(&record.request).await?, record.response);
+        assert!(recorder.exec(&TLRequest::GetConfigAll { mode: 0 }).await.is_err());
+        drop(recorder);
+
+        let recorded = TLReplayer::from_file(&dst_path);
+        std::fs::remove_file(&dst_path)?;
+        let recorded = recorded?;
+        assert_eq!(recorded.responses.len(), 1);
+        assert_eq!(recorded.exec(&record.request).await?, record.response);
+        Ok(())
+    }
+}
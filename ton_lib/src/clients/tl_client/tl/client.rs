@@ -1,5 +1,4 @@
 use crate::block_tlb::BlockIdExt;
-use crate::clients::tl_client::connection::TLConnection;
 use crate::clients::tl_client::tl::request::TLRequest;
 use crate::clients::tl_client::tl::response::TLResponse;
 use crate::clients::tl_client::tl::types::{
@@ -18,15 +17,12 @@ use ton_lib_core::types::{TonAddress, TxIdLTHash};
 
 #[async_trait]
 pub trait TLClientTrait: Send + Sync {
-    fn get_connection(&self) -> &TLConnection;
+    /// Client bound to a single node, so a sequence of requests sees the same chain state
+    fn get_connection(&self) -> &dyn TLClientTrait;
     fn get_retry_strategy(&self) -> &RetryStrategy;
 
-    async fn exec(&self, req: &TLRequest) -> Result<TLResponse, TLError> {
-        let retry_strat = self.get_retry_strategy();
-        let fi = FixedInterval::new(retry_strat.retry_waiting);
-        let strategy = fi.take(retry_strat.retry_count);
-        RetryIf::spawn(strategy, || self.get_connection().exec_impl(req), retry_condition).await
-    }
+    /// Use [`exec_with_retry`] to send request to `get_connection()` with retries
+    async fn exec(&self, req: &TLRequest) -> Result<TLResponse, TLError>;
 
     async fn get_mc_info(&self) -> Result<TLBlocksMCInfo, TLError> {
         let req = TLRequest::BlocksGetMCInfo {};
@@ -80,6 +76,14 @@ pub trait TLClientTrait: Send + Sync {
         Ok(unwrap_tl_response!(self.exec(&req).await?, TLConfigInfo)?.config.bytes)
     }
 
+    async fn get_config_boc_all_by_block(&self, mode: u32, block_id: BlockIdExt) -> Result<Vec<u8>, TLError> {
+        let req = TLRequest::WithBlock {
+            id: block_id,
+            function: Box::new(TLRequest::GetConfigAll { mode }),
+        };
+        Ok(unwrap_tl_response!(self.exec(&req).await?, TLConfigInfo)?.config.bytes)
+    }
+
     async fn get_account_state(&self, address: TonAddress) -> Result<TLFullAccountState, TLError> {
         let req = TLRequest::GetAccountState {
             account_address: address.into(),
@@ -181,6 +185,16 @@ pub trait TLClientTrait: Send + Sync {
     }
 }
 
+/// Executes request on `get_connection()` according to `get_retry_strategy()`
+///
+/// Must not be used by implementations returning `self` from `get_connection()`
+pub async fn exec_with_retry<C: TLClientTrait + ?Sized>(client: &C, req: &TLRequest) -> Result<TLResponse, TLError> {
+    let retry_strat = client.get_retry_strategy();
+    let fi = FixedInterval::new(retry_strat.retry_waiting);
+    let strategy = fi.take(retry_strat.retry_count);
+    RetryIf::spawn(strategy, || client.get_connection().exec(req), retry_condition).await
+}
+
 fn retry_condition(error: &TLError) -> bool {
     match error {
         TLError::TLClientResponseError { code, .. } => *code == 500,
@@ -208,6 +208,14 @@ pub enum TLRequest {
     #[serde(rename = "liteServer.getInfo")]
     LiteServerGetInfo {},
 
+    // executes function at the specified block
+    #[serde(rename = "withBlock")]
+    WithBlock {
+        #[serde(with = "serde_block_id_ext")]
+        id: BlockIdExt,
+        function: Box<TLRequest>,
+    },
+
     // tonlib_api.tl, line 352
     SetLogVerbosityLevel {
         new_verbosity_level: u32,
@@ -228,6 +236,7 @@ impl TLRequest {
 
 #[cfg(test)]
 mod tests {
+    use crate::block_tlb::BlockIdExt;
     use crate::clients::tl_client::tl::request::TLRequest;
     use std::ffi::CString;
 
@@ -243,4 +252,17 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn test_tl_request_with_block_to_json() -> anyhow::Result<()> {
+        let req = TLRequest::WithBlock {
+            id: BlockIdExt::ZERO_BLOCK_ID,
+            function: Box::new(TLRequest::GetConfigAll { mode: 0 }),
+        };
+        let json = serde_json::to_value(&req)?;
+        assert_eq!(json["@type"], "withBlock");
+        assert_eq!(json["id"]["workchain"], -1);
+        assert_eq!(json["function"], serde_json::json!({"@type": "getConfigAll", "mode": 0}));
+        Ok(())
+    }
 }
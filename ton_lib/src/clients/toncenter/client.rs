@@ -0,0 +1,373 @@
+use crate::clients::toncenter::config::TonCenterConfig;
+use crate::clients::toncenter::types::*;
+use crate::error::TLError;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use parking_lot::Mutex;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::{RequestBuilder, StatusCode};
+use serde::de::DeserializeOwned;
+use serde_json::json;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::Instant;
+use ton_lib_core::cell::TonHash;
+use ton_lib_core::constants::TON_MASTERCHAIN;
+use ton_lib_core::types::{TonAddress, TxIdLTHash};
+
+const V3_PAGE_SIZE: usize = 256;
+const MAX_ERROR_BODY_LEN: usize = 200;
+
+/// Client for toncenter-compatible HTTP APIs (v2 for accounts & blocks, v3 for indexed transactions)
+#[derive(Clone)]
+pub struct TonCenterClient(Arc<Inner>);
+
+struct Inner {
+    config: TonCenterConfig,
+    http_client: reqwest::Client,
+    next_request_at: Mutex<Instant>,
+}
+
+impl TonCenterClient {
+    pub fn new(config: TonCenterConfig) -> Result<Self, TLError> {
+        let http_client = reqwest::Client::builder().timeout(config.request_timeout).build()?;
+        Self::new_with_client(config, http_client)
+    }
+
+    pub fn new_with_client(config: TonCenterConfig, http_client: reqwest::Client) -> Result<Self, TLError> {
+        let inner = Inner {
+            config,
+            http_client,
+            next_request_at: Mutex::new(Instant::now()),
+        };
+        Ok(Self(Arc::new(inner)))
+    }
+
+    pub async fn get_mc_info(&self) -> Result<TonCenterMCInfo, TLError> { self.v2_get("getMasterchainInfo", &[]).await }
+
+    pub async fn get_mc_block_header(&self, mc_seqno: u32) -> Result<TonCenterBlockHeader, TLError> {
+        let query = [
+            ("workchain", TON_MASTERCHAIN.to_string()),
+            ("shard", i64::MIN.to_string()),
+            ("seqno", mc_seqno.to_string()),
+        ];
+        self.v2_get("getBlockHeader", &query).await
+    }
+
+    /// Takes state at the end of mc block if mc_seqno is specified (requires archival node)
+    pub async fn get_address_information(
+        &self,
+        address: &TonAddress,
+        mc_seqno: Option<u32>,
+    ) -> Result<TonCenterAddressInfo, TLError> {
+        let mut query = vec![("address", address.to_hex())];
+        if let Some(seqno) = mc_seqno {
+            query.push(("seqno", seqno.to_string()));
+        }
+        self.v2_get("getAddressInformation", &query).await
+    }
+
+    /// Returns up to `limit` txs starting from `from_tx_id` (latest if None) and going back in time
+    pub async fn get_transactions(
+        &self,
+        address: &TonAddress,
+        limit: u32,
+        from_tx_id: Option<&TxIdLTHash>,
+    ) -> Result<Vec<TonCenterTx>, TLError> {
+        let mut query = vec![("address", address.to_hex()), ("limit", limit.to_string())];
+        if let Some(tx_id) = from_tx_id {
+            query.push(("lt", tx_id.lt.to_string()));
+            query.push(("hash", tx_id.hash.to_hex()));
+        }
+        self.v2_get("getTransactions", &query).await
+    }
+
+    pub async fn run_get_method(
+        &self,
+        address: &TonAddress,
+        method: &str,
+        stack: Vec<serde_json::Value>,
+    ) -> Result<TonCenterRunGetMethodResult, TLError> {
+        let body = json!({"address": address.to_hex(), "method": method, "stack": stack});
+        self.v2_post("runGetMethod", &body, true).await
+    }
+
+    /// Retried only if connection to the server fails, so the message is never sent twice
+    pub async fn send_boc(&self, boc: &[u8]) -> Result<(), TLError> {
+        let _: serde_json::Value = self.v2_post("sendBoc", &json!({"boc": STANDARD.encode(boc)}), false).await?;
+        Ok(())
+    }
+
+    /// Returns config boc
+    pub async fn get_config_all(&self, mc_seqno: Option<u32>) -> Result<Vec<u8>, TLError> {
+        let query: Vec<_> = mc_seqno.map(|x| ("seqno", x.to_string())).into_iter().collect();
+        let config_info: TonCenterConfigInfo = self.v2_get("getConfigAll", &query).await?;
+        Ok(config_info.config.bytes)
+    }
+
+    /// Returns (lib_id, lib_boc) for found libraries
+    pub async fn get_libraries(&self, lib_ids: &[TonHash]) -> Result<Vec<(TonHash, Vec<u8>)>, TLError> {
+        let ids = lib_ids.iter().map(|x| x.to_hex()).collect::<Vec<_>>().join(",");
+        let libs: TonCenterLibraries = self.v2_get("getLibraries", &[("libraries", ids)]).await?;
+        let mut result = Vec::with_capacity(libs.result.len());
+        for lib in libs.result {
+            result.push((TonHash::from_str(&lib.hash)?, lib.data));
+        }
+        Ok(result)
+    }
+
+    /// All txs of mc block and shard blocks committed in it
+    pub async fn get_mc_block_txs(&self, mc_seqno: u32) -> Result<Vec<TonCenterV3Tx>, TLError> {
+        let mut txs = vec![];
+        loop {
+            let query = [
+                ("seqno", mc_seqno.to_string()),
+                ("limit", V3_PAGE_SIZE.to_string()),
+                ("offset", txs.len().to_string()),
+            ];
+            let page: TonCenterV3Txs = self.v3_get("transactionsByMasterchainBlock", &query).await?;
+            let page_len = page.transactions.len();
+            txs.extend(page.transactions);
+            if page_len < V3_PAGE_SIZE {
+                return Ok(txs);
+            }
+        }
+    }
+
+    pub async fn get_tx_by_hash(&self, tx_hash: &TonHash) -> Result<Option<TonCenterV3Tx>, TLError> {
+        let query = [("hash", tx_hash.to_hex()), ("limit", "1".to_string())];
+        let txs: TonCenterV3Txs = self.v3_get("transactions", &query).await?;
+        Ok(txs.transactions.into_iter().next())
+    }
+
+    async fn v2_get<T: DeserializeOwned>(&self, method: &str, query: &[(&str, String)]) -> Result<T, TLError> {
+        let url = format!("{}/{method}", self.0.config.v2_url);
+        let (status, body) = self.send(|| self.0.http_client.get(&url).query(query), true).await?;
+        parse_v2_response(status, &body)
+    }
+
+    async fn v2_post<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        json_body: &serde_json::Value,
+        idempotent: bool,
+    ) -> Result<T, TLError> {
+        let url = format!("{}/{method}", self.0.config.v2_url);
+        let json_body = serde_json::to_vec(json_body)?;
+        let make_request =
+            || self.0.http_client.post(&url).header(CONTENT_TYPE, "application/json").body(json_body.clone());
+        let (status, body) = self.send(make_request, idempotent).await?;
+        parse_v2_response(status, &body)
+    }
+
+    async fn v3_get<T: DeserializeOwned>(&self, method: &str, query: &[(&str, String)]) -> Result<T, TLError> {
+        let url = format!("{}/{method}", self.0.config.v3_url);
+        let (status, body) = self.send(|| self.0.http_client.get(&url).query(query), true).await?;
+        if !status.is_success() {
+            return Err(http_error(status, &body));
+        }
+        Ok(serde_json::from_slice(&body)?)
+    }
+
+    /// Non-idempotent requests are retried only on connection errors, when the request isn't sent yet
+    async fn send<F>(&self, make_request: F, idempotent: bool) -> Result<(StatusCode, Vec<u8>), TLError>
+    where
+        F: Fn() -> RequestBuilder,
+    {
+        let config = &self.0.config;
+        let mut attempt = 0;
+        loop {
+            self.wait_rate_limit().await;
+            let mut request = make_request();
+            if let Some(api_key) = &config.api_key {
+                request = request.header("X-API-Key", api_key);
+            }
+            let response = match request.send().await {
+                Ok(response) => {
+                    let status = response.status();
+                    response.bytes().await.map(|body| (status, body.to_vec()))
+                }
+                Err(err) => Err(err),
+            };
+            let retryable = match &response {
+                Ok((status, _)) => idempotent && (*status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()),
+                Err(err) => idempotent || err.is_connect(),
+            };
+            if !retryable || attempt >= config.retry_count {
+                return Ok(response?);
+            }
+            attempt += 1;
+            let reason = match &response {
+                Ok((status, body)) => http_error(*status, body).to_string(),
+                Err(err) => err.to_string(),
+            };
+            log::warn!("[TonCenterClient] request failed, retrying ({attempt}/{}): {reason}", config.retry_count);
+            tokio::time::sleep(config.retry_waiting).await;
+        }
+    }
+
+    async fn wait_rate_limit(&self) {
+        let Some(rps) = self.0.config.rps_limit.filter(|x| *x > 0) else {
+            return;
+        };
+        let request_at = {
+            let mut next_request_at = self.0.next_request_at.lock();
+            let request_at = (*next_request_at).max(Instant::now());
+            *next_request_at = request_at + Duration::from_secs(1) / rps;
+            request_at
+        };
+        tokio::time::sleep_until(request_at).await;
+    }
+}
+
+fn parse_v2_response<T: DeserializeOwned>(status: StatusCode, body: &[u8]) -> Result<T, TLError> {
+    // toncenter returns error details in the body even for non-2xx statuses
+    let response: TonCenterV2Response<T> = match serde_json::from_slice(body) {
+        Ok(response) => response,
+        Err(_) if !status.is_success() => return Err(http_error(status, body)),
+        Err(err) => return Err(err.into()),
+    };
+    match (response.ok, response.result) {
+        (true, Some(result)) => Ok(result),
+        _ => Err(TLError::TonCenterApiError {
+            code: response.code,
+            message: response.error.unwrap_or_default(),
+        }),
+    }
+}
+
+fn http_error(status: StatusCode, body: &[u8]) -> TLError {
+    let body = String::from_utf8_lossy(body);
+    TLError::TonCenterHttpError {
+        status: status.as_u16(),
+        body: body.chars().take(MAX_ERROR_BODY_LEN).collect(),
+    }
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering::Relaxed;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Minimal HTTP/1.1 server: handler gets "METHOD /path?query" & body, returns (status, json)
+    pub(in crate::clients::toncenter) async fn spawn_mock_server<H>(handler: H) -> anyhow::Result<String>
+    where
+        H: Fn(&str, &str) -> (u16, String) + Send + Sync + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("http://{}", listener.local_addr()?);
+        let handler = Arc::new(handler);
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let handler = handler.clone();
+                tokio::spawn(async move {
+                    let mut request = vec![];
+                    let mut buf = [0u8; 4096];
+                    let (head, body) = loop {
+                        let Ok(read) = stream.read(&mut buf).await else { return };
+                        if read == 0 {
+                            return;
+                        }
+                        request.extend_from_slice(&buf[..read]);
+                        let text = String::from_utf8_lossy(&request).to_string();
+                        let Some((head, body)) = text.split_once("\r\n\r\n") else {
+                            continue;
+                        };
+                        let content_len = head
+                            .lines()
+                            .filter_map(|x| x.split_once(':'))
+                            .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+                            .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+                            .unwrap_or(0);
+                        if body.len() >= content_len {
+                            break (head.to_string(), body.to_string());
+                        }
+                    };
+                    let request_line = head.lines().next().unwrap_or_default();
+                    let request_line = request_line.rsplit_once(' ').map(|x| x.0).unwrap_or_default();
+                    let (status, response_body) = handler(request_line, &body);
+                    let response = format!(
+                        "HTTP/1.1 {status} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{response_body}",
+                        response_body.len()
+                    );
+                    let _ = stream.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+        Ok(url)
+    }
+
+    pub(in crate::clients::toncenter) fn make_config(url: &str) -> TonCenterConfig {
+        let mut config = TonCenterConfig::new(&format!("{url}/api/v2"), &format!("{url}/api/v3")).with_rps_limit(None);
+        config.retry_waiting = Duration::from_millis(1);
+        config
+    }
+
+    #[tokio::test]
+    async fn test_toncenter_client() -> anyhow::Result<()> {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_clone = calls.clone();
+        let url = spawn_mock_server(move |request, body| match request {
+            "GET /api/v2/getMasterchainInfo" => match calls_clone.fetch_add(1, Relaxed) {
+                0 => (429, r#"{"ok": false, "error": "Ratelimit exceed", "code": 429}"#.to_string()),
+                _ => (200, r#"{"ok": true, "result": {"last": {"workchain": -1, "shard": "-9223372036854775808", "seqno": 42, "root_hash": "", "file_hash": ""}}}"#.to_string()),
+            },
+            "POST /api/v2/sendBoc" if body.contains("AQID") => (200, r#"{"ok": true, "result": {"@type": "ok"}}"#.to_string()),
+            "POST /api/v2/runGetMethod" => (200, r#"{"ok": true, "result": {"gas_used": 10, "exit_code": 0, "stack": [["num", "0x5"]]}}"#.to_string()),
+            _ => (500, r#"{"ok": false, "error": "not found", "code": 500}"#.to_string()),
+        })
+        .await?;
+
+        let client = TonCenterClient::new(make_config(&url).with_api_key("key"))?;
+        assert_eq!(client.get_mc_info().await?.last.seqno, 42);
+        assert_eq!(calls.load(Relaxed), 2);
+        client.send_boc(&[1, 2, 3]).await?;
+        let get_method_res = client.run_get_method(&TonAddress::ZERO, "seqno", vec![]).await?;
+        assert_eq!(get_method_res.stack, vec![json!(["num", "0x5"])]);
+
+        let err = client.get_config_all(None).await.unwrap_err();
+        assert!(matches!(err, TLError::TonCenterApiError { code: Some(500), .. }), "{err:?}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_toncenter_client_send_boc_no_retry() -> anyhow::Result<()> {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_clone = calls.clone();
+        let url = spawn_mock_server(move |_, _| {
+            calls_clone.fetch_add(1, Relaxed);
+            (502, r#"{"ok": false, "error": "timeout", "code": 502}"#.to_string())
+        })
+        .await?;
+        let client = TonCenterClient::new(make_config(&url))?;
+        assert!(client.send_boc(&[1, 2, 3]).await.is_err());
+        assert_eq!(calls.load(Relaxed), 1);
+        assert!(client.get_mc_info().await.is_err());
+        assert_eq!(calls.load(Relaxed), 2 + make_config(&url).retry_count);
+
+        // nothing is listening, so the request isn't sent and can be retried
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+        let closed_url = format!("http://{}", listener.local_addr()?);
+        drop(listener);
+        let client = TonCenterClient::new(make_config(&closed_url))?;
+        let err = client.send_boc(&[1, 2, 3]).await.unwrap_err();
+        assert!(!matches!(err, TLError::TonCenterApiError { .. }), "{err:?}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_toncenter_client_rate_limit() -> anyhow::Result<()> {
+        let url = spawn_mock_server(|_, _| (200, r#"{"ok": true, "result": {"@type": "ok"}}"#.to_string())).await?;
+        let client = TonCenterClient::new(make_config(&url).with_rps_limit(Some(20)))?;
+        let started_at = Instant::now();
+        for _ in 0..3 {
+            client.send_boc(&[]).await?;
+        }
+        assert!(started_at.elapsed() >= Duration::from_millis(100));
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct TonCenterConfig {
+    pub v2_url: String,
+    pub v3_url: String,
+    pub api_key: Option<String>,
+    /// requests per second, None for unlimited. Public toncenter allows 1 rps without api key
+    pub rps_limit: Option<u32>,
+    /// retries on 429, 5xx & transport errors
+    pub retry_count: usize,
+    pub retry_waiting: Duration,
+    pub request_timeout: Duration,
+}
+
+impl TonCenterConfig {
+    pub fn new(v2_url: &str, v3_url: &str) -> Self {
+        Self {
+            v2_url: v2_url.trim_end_matches('/').to_string(),
+            v3_url: v3_url.trim_end_matches('/').to_string(),
+            api_key: None,
+            rps_limit: Some(1),
+            retry_count: 3,
+            retry_waiting: Duration::from_millis(1000),
+            request_timeout: Duration::from_secs(10),
+        }
+    }
+
+    pub fn mainnet() -> Self { Self::new("https://toncenter.com/api/v2", "https://toncenter.com/api/v3") }
+
+    pub fn testnet() -> Self {
+        Self::new("https://testnet.toncenter.com/api/v2", "https://testnet.toncenter.com/api/v3")
+    }
+
+    pub fn with_api_key(mut self, api_key: &str) -> Self {
+        self.api_key = Some(api_key.to_string());
+        self
+    }
+
+    pub fn with_rps_limit(mut self, rps_limit: Option<u32>) -> Self {
+        self.rps_limit = rps_limit;
+        self
+    }
+}
@@ -0,0 +1,8 @@
+mod client;
+mod config;
+mod provider;
+pub mod types;
+
+pub use client::*;
+pub use config::*;
+pub use provider::*;
@@ -0,0 +1,216 @@
+use crate::clients::toncenter::client::TonCenterClient;
+use crate::error::TLError;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::str::FromStr;
+use ton_lib_core::cell::TonHash;
+use ton_lib_core::error::TLCoreError;
+use ton_lib_core::traits::contract_provider::{ContractProvider, ContractState, McBlockInfo};
+use ton_lib_core::types::{TonAddress, TxIdLTHash};
+
+/// ContractProvider backed by toncenter HTTP API
+///
+/// States by tx_id are taken at the end of tx mc block, so it requires archival API.
+/// v2 API has no state right after a tx, so it fails if the account has another tx later in the same block
+pub struct TonCenterProvider {
+    client: TonCenterClient,
+}
+
+impl TonCenterProvider {
+    pub fn new(client: TonCenterClient) -> Self { Self { client } }
+
+    async fn load_state_impl(&self, address: TonAddress, tx_id: Option<TxIdLTHash>) -> Result<ContractState, TLError> {
+        let mc_seqno = match &tx_id {
+            Some(id) => match self.client.get_tx_by_hash(&id.hash).await?.and_then(|x| x.mc_block_seqno) {
+                Some(seqno) => Some(seqno),
+                None => return Err(TLError::Custom(format!("tx {id:?} of {address} is not found"))),
+            },
+            None => None,
+        };
+        let info = self.client.get_address_information(&address, mc_seqno).await?;
+        let last_tx_id = TxIdLTHash::try_from(&info.last_transaction_id)?;
+        if let Some(id) = tx_id.filter(|x| x != &last_tx_id) {
+            let actual = match last_tx_id.lt > id.lt {
+                true => format!("state after later tx {last_tx_id:?} from the same mc block {mc_seqno:?}"),
+                false => format!("state after tx {last_tx_id:?}"),
+            };
+            return Err(TLError::UnexpectedValue {
+                expected: format!("state of {address} after tx {id:?}"),
+                actual,
+            });
+        }
+        let mc_seqno = mc_seqno.or(info.block_id.map(|x| x.seqno));
+        let frozen_hash = match info.frozen_hash.is_empty() {
+            true => None,
+            false => Some(TonHash::from_vec(info.frozen_hash)?),
+        };
+        Ok(ContractState {
+            mc_seqno,
+            address,
+            last_tx_id,
+            code_boc: Some(info.code).filter(|x| !x.is_empty()),
+            data_boc: Some(info.data).filter(|x| !x.is_empty()),
+            frozen_hash,
+            balance: info.balance,
+            sync_utime: Some(info.sync_utime as u32),
+        })
+    }
+
+    async fn load_latest_tx_per_address_impl(&self, mc_seqno: u32) -> Result<HashMap<TonAddress, TxIdLTHash>, TLError> {
+        let mut latest_by_address = HashMap::<TonAddress, TxIdLTHash>::new();
+        for tx in self.client.get_mc_block_txs(mc_seqno).await? {
+            let address = TonAddress::from_str(&tx.account)?;
+            if latest_by_address.get(&address).is_some_and(|x| x.lt >= tx.lt) {
+                continue;
+            }
+            latest_by_address.insert(address, TxIdLTHash::new(tx.lt, TonHash::from_str(&tx.hash)?));
+        }
+        Ok(latest_by_address)
+    }
+}
+
+#[async_trait]
+impl ContractProvider for TonCenterProvider {
+    async fn last_mc_seqno(&self) -> Result<u32, TLCoreError> { Ok(self.client.get_mc_info().await?.last.seqno) }
+
+    async fn load_state(&self, address: TonAddress, tx_id: Option<TxIdLTHash>) -> Result<ContractState, TLCoreError> {
+        Ok(self.load_state_impl(address, tx_id).await?)
+    }
+
+    async fn load_bc_config(&self, mc_seqno: Option<u32>) -> Result<Vec<u8>, TLCoreError> {
+        Ok(self.client.get_config_all(mc_seqno).await?)
+    }
+
+    async fn load_key_block_seqno(&self, mc_seqno: Option<u32>) -> Result<u32, TLCoreError> {
+        let seqno = match mc_seqno {
+            Some(seqno) => seqno,
+            None => self.client.get_mc_info().await?.last.seqno,
+        };
+        let header = self.client.get_mc_block_header(seqno).await?;
+        match header.is_key_block {
+            true => Ok(header.id.seqno),
+            false => Ok(header.prev_key_block_seqno),
+        }
+    }
+
+    async fn load_libs(
+        &self,
+        lib_ids: Vec<TonHash>,
+        _mc_seqno: Option<u32>,
+    ) -> Result<Vec<(TonHash, Vec<u8>)>, TLCoreError> {
+        Ok(self.client.get_libraries(&lib_ids).await?)
+    }
+
+    async fn load_latest_tx_per_address(&self, mc_seqno: u32) -> Result<HashMap<TonAddress, TxIdLTHash>, TLCoreError> {
+        Ok(self.load_latest_tx_per_address_impl(mc_seqno).await?)
+    }
+
+    async fn load_tx_utime(&self, address: TonAddress, tx_id: TxIdLTHash) -> Result<u32, TLCoreError> {
+        match self.client.get_tx_by_hash(&tx_id.hash).await?.and_then(|x| x.now) {
+            Some(utime) => Ok(utime),
+            None => Err(TLCoreError::Custom(format!("tx {tx_id:?} of {address} is not found"))),
+        }
+    }
+
+    async fn load_mc_block_info(&self, mc_seqno: Option<u32>) -> Result<McBlockInfo, TLCoreError> {
+        let seqno = match mc_seqno {
+            Some(seqno) => seqno,
+            None => self.client.get_mc_info().await?.last.seqno,
+        };
+        let header = self.client.get_mc_block_header(seqno).await?;
+        Ok(McBlockInfo {
+            seqno: header.id.seqno,
+            root_hash: TonHash::from_str(&header.id.root_hash)?,
+            file_hash: TonHash::from_str(&header.id.file_hash)?,
+            gen_utime: header.gen_utime as u32,
+            prev_key_block_seqno: header.prev_key_block_seqno,
+            rand_seed: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clients::toncenter::client::tests::{make_config, spawn_mock_server};
+    use base64::engine::general_purpose::STANDARD;
+    use base64::Engine;
+
+    #[tokio::test]
+    async fn test_toncenter_provider() -> anyhow::Result<()> {
+        let hash_b64 = |x: u8| STANDARD.encode([x; 32]);
+        let address_hex = format!("0:{}", "AB".repeat(32));
+        let tx_id_json = format!(r#"{{"lt": "100", "hash": "{}"}}"#, hash_b64(1));
+        let address_info = format!(
+            r#"{{"ok": true, "result": {{"balance": "500", "code": "AQI=", "data": "", "last_transaction_id": {tx_id_json}, "frozen_hash": "", "sync_utime": 1700000000, "block_id": {{"workchain": -1, "shard": "-9223372036854775808", "seqno": 52, "root_hash": "", "file_hash": ""}}}}}}"#
+        );
+        let txs_page = format!(
+            r#"{{"transactions": [{{"account": "{address_hex}", "lt": "7", "hash": "{}", "mc_block_seqno": 50}}, {{"account": "{address_hex}", "lt": "9", "hash": "{}", "mc_block_seqno": 50}}]}}"#,
+            hash_b64(7),
+            hash_b64(9)
+        );
+        let tx_by_hash = format!(
+            r#"{{"transactions": [{{"account": "{address_hex}", "lt": "100", "hash": "{}", "mc_block_seqno": 48, "now": 1699999999}}]}}"#,
+            hash_b64(1)
+        );
+        let header = r#"{"ok": true, "result": {"id": {"workchain": -1, "shard": "-9223372036854775808", "seqno": 50, "root_hash": "AQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQE=", "file_hash": "AgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgI="}, "is_key_block": false, "prev_key_block_seqno": 40, "gen_utime": 1700000000}}"#;
+        let lib_hash = TonHash::from([3u8; 32]);
+        let libs =
+            format!(r#"{{"ok": true, "result": {{"result": [{{"hash": "{}", "data": "AQ=="}}]}}}}"#, hash_b64(3));
+
+        let url = spawn_mock_server(move |request, _| {
+            let (path, query) = request.split_once('?').unwrap_or((request, ""));
+            let body = match path {
+                "GET /api/v2/getAddressInformation" if query.contains("seqno=48") || !query.contains("seqno") => {
+                    address_info.clone()
+                }
+                "GET /api/v2/getBlockHeader" if query.contains("seqno=50") => header.to_string(),
+                "GET /api/v2/getConfigAll" => r#"{"ok": true, "result": {"config": {"bytes": "qrs="}}}"#.to_string(),
+                "GET /api/v2/getLibraries" => libs.clone(),
+                "GET /api/v3/transactionsByMasterchainBlock" if query.contains("seqno=50") => txs_page.clone(),
+                "GET /api/v3/transactions" => tx_by_hash.clone(),
+                _ => return (404, r#"{"error": "not found"}"#.to_string()),
+            };
+            (200, body)
+        })
+        .await?;
+
+        let provider = TonCenterProvider::new(TonCenterClient::new(make_config(&url))?);
+        let address = TonAddress::from_str(&address_hex)?;
+        let state = provider.load_state(address.clone(), None).await?;
+        let tx_id = TxIdLTHash::new(100, TonHash::from([1u8; 32]));
+        assert_eq!(state.last_tx_id, tx_id);
+        assert_eq!(state.balance, 500);
+        assert_eq!(state.code_boc, Some(vec![1, 2]));
+        assert_eq!(state.data_boc, None);
+        assert_eq!(state.sync_utime, Some(1700000000));
+        assert_eq!(state.mc_seqno, Some(52));
+        let state_by_tx = provider.load_state(address.clone(), Some(tx_id.clone())).await?;
+        assert_eq!(state_by_tx.mc_seqno, Some(48));
+        assert_eq!(
+            ContractState {
+                mc_seqno: Some(52),
+                ..state_by_tx
+            },
+            state
+        );
+        assert_eq!(provider.load_tx_utime(address.clone(), tx_id.clone()).await?, 1699999999);
+        // tx 99 is followed by tx 100 in the same mc block 48, state right after tx 99 isn't available
+        let same_block_tx_id = TxIdLTHash::new(99, TonHash::from([1u8; 32]));
+        let err = provider.load_state(address.clone(), Some(same_block_tx_id)).await.unwrap_err();
+        assert!(err.to_string().contains("later tx"), "{err}");
+
+        assert_eq!(provider.load_key_block_seqno(Some(50)).await?, 40);
+        let mc_block = provider.load_mc_block_info(Some(50)).await?;
+        assert_eq!((mc_block.seqno, mc_block.gen_utime, mc_block.prev_key_block_seqno), (50, 1700000000, 40));
+        assert_eq!(mc_block.root_hash, TonHash::from([1u8; 32]));
+        assert_eq!(mc_block.file_hash, TonHash::from([2u8; 32]));
+        assert_eq!(provider.load_bc_config(Some(40)).await?, vec![0xaa, 0xbb]);
+        assert_eq!(provider.load_libs(vec![lib_hash.clone()], None).await?, vec![(lib_hash, vec![1])]);
+
+        let latest_txs = provider.load_latest_tx_per_address(50).await?;
+        assert_eq!(latest_txs, HashMap::from([(address, TxIdLTHash::new(9, TonHash::from([9u8; 32])))]));
+        assert!(provider.load_latest_tx_per_address(51).await.is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,133 @@
+use crate::error::TLError;
+use base64_serde::base64_serde_type;
+use serde::{Deserialize, Serialize};
+use serde_aux::prelude::*;
+use std::str::FromStr;
+use ton_lib_core::cell::TonHash;
+use ton_lib_core::types::TxIdLTHash;
+
+base64_serde_type!(Base64Standard, base64::engine::general_purpose::STANDARD);
+
+/// v2 wraps every result: `{"ok": true, "result": ...}` or `{"ok": false, "error": "...", "code": 500}`
+#[derive(Deserialize, Debug)]
+pub(super) struct TonCenterV2Response<T> {
+    pub ok: bool,
+    pub result: Option<T>,
+    pub error: Option<String>,
+    pub code: Option<i32>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TonCenterTxId {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub lt: i64,
+    pub hash: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TonCenterBlockId {
+    pub workchain: i32,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub shard: i64,
+    pub seqno: u32,
+    pub root_hash: String,
+    pub file_hash: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TonCenterMCInfo {
+    pub last: TonCenterBlockId,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TonCenterBlockHeader {
+    pub id: TonCenterBlockId,
+    pub is_key_block: bool,
+    pub prev_key_block_seqno: u32,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub gen_utime: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TonCenterAddressInfo {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub balance: i64,
+    #[serde(with = "Base64Standard")]
+    pub code: Vec<u8>,
+    #[serde(with = "Base64Standard")]
+    pub data: Vec<u8>,
+    pub last_transaction_id: TonCenterTxId,
+    #[serde(with = "Base64Standard", default)]
+    pub frozen_hash: Vec<u8>,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub sync_utime: i64,
+    /// masterchain block the state is taken from
+    #[serde(default)]
+    pub block_id: Option<TonCenterBlockId>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TonCenterTx {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub utime: i64,
+    /// transaction boc
+    #[serde(with = "Base64Standard")]
+    pub data: Vec<u8>,
+    pub transaction_id: TonCenterTxId,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TonCenterRunGetMethodResult {
+    pub gas_used: i64,
+    pub exit_code: i32,
+    /// toncenter stack format, e.g. `[["num", "0x1"], ["cell", {"bytes": "..."}]]`
+    pub stack: Vec<serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub(super) struct TonCenterCell {
+    #[serde(with = "Base64Standard")]
+    pub bytes: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub(super) struct TonCenterConfigInfo {
+    pub config: TonCenterCell,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub(super) struct TonCenterLibEntry {
+    pub hash: String,
+    #[serde(with = "Base64Standard")]
+    pub data: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub(super) struct TonCenterLibraries {
+    pub result: Vec<TonCenterLibEntry>,
+}
+
+/// Transaction from v3 API
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TonCenterV3Tx {
+    /// raw address, e.g. `0:ABCD...`
+    pub account: String,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub lt: i64,
+    pub hash: String,
+    pub mc_block_seqno: Option<u32>,
+    pub now: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub(super) struct TonCenterV3Txs {
+    pub transactions: Vec<TonCenterV3Tx>,
+}
+
+impl TryFrom<&TonCenterTxId> for TxIdLTHash {
+    type Error = TLError;
+
+    fn try_from(value: &TonCenterTxId) -> Result<Self, Self::Error> {
+        Ok(TxIdLTHash::new(value.lt, TonHash::from_str(&value.hash)?))
+    }
+}
@@ -8,10 +8,17 @@ pub(crate) struct CacheStats {
     pub state_latest_miss: AtomicUsize,
     pub state_by_tx_req: AtomicUsize,
     pub state_by_tx_miss: AtomicUsize,
+    pub libs_req: AtomicUsize,
+    pub libs_miss: AtomicUsize,
 }
 
 impl CacheStats {
-    pub(crate) fn export(&self, latest_entry_count: usize, by_tx_entry_count: usize) -> HashMap<String, usize> {
+    pub(crate) fn export(
+        &self,
+        latest_entry_count: usize,
+        by_tx_entry_count: usize,
+        libs_entry_count: usize,
+    ) -> HashMap<String, usize> {
         HashMap::from([
             ("state_latest_req".to_string(), self.state_latest_req.load(Relaxed)),
             ("state_latest_miss".to_string(), self.state_latest_miss.load(Relaxed)),
@@ -19,6 +26,9 @@ impl CacheStats {
             ("state_by_tx_req".to_string(), self.state_by_tx_req.load(Relaxed)),
             ("state_by_tx_miss".to_string(), self.state_by_tx_miss.load(Relaxed)),
             ("state_by_tx_entry_count".to_string(), by_tx_entry_count),
+            ("libs_req".to_string(), self.libs_req.load(Relaxed)),
+            ("libs_miss".to_string(), self.libs_miss.load(Relaxed)),
+            ("libs_entry_count".to_string(), libs_entry_count),
         ])
     }
 }
@@ -1,15 +1,23 @@
+use crate::block_tlb::{CommonMsgInfo, Msg, ShardAccount};
 use crate::contracts::client::contract_client_cache::ContractClientCache;
+use crate::contracts::client::contract_msg_result::ContractMsgResult;
+use crate::contracts::client::contract_subscription::ContractSubscription;
 use crate::emulators::emul_bc_config::EmulBCConfig;
+use crate::emulators::emul_pool::EmulatorPool;
 use crate::emulators::tvm::tvm_c7::TVMEmulatorC7;
-use crate::emulators::tvm::tvm_emulator::TVMEmulator;
+use crate::emulators::tvm::tvm_emul_args::TVMGetMethodArgs;
 use crate::emulators::tvm::tvm_response::TVMGetMethodSuccess;
+use crate::emulators::tx::tx_dry_run::TXDryRunReport;
+use crate::emulators::tx::tx_emul_args::{TXEmulArgs, TXEmulOrdArgs};
+use crate::emulators::tx::tx_emul_response::TXEmulationResponse;
+use crate::emulators::tx::tx_emulator::TXEmulator;
 use crate::error::TLError;
 use crate::libs_dict::LibsDict;
+use futures_util::{Stream, StreamExt};
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
-use tokio::sync::OnceCell;
-use ton_lib_core::cell::{TonCell, TonCellRef, TonCellUtils, TonHash};
+use ton_lib_core::cell::{TonCell, TonHash};
 use ton_lib_core::error::TLCoreError;
 use ton_lib_core::traits::contract_provider::{ContractProvider, ContractState};
 use ton_lib_core::traits::tlb::TLB;
@@ -36,14 +44,18 @@ impl ContractClientConfig {
 pub struct ContractClient(Arc<Inner>);
 
 impl ContractClient {
+    /// Runs emulations on tokio blocking threads
     pub fn new(config: ContractClientConfig, data_provider: impl ContractProvider) -> Result<Self, TLError> {
-        let provider = Arc::new(data_provider);
-        let inner = Inner {
-            provider: provider.clone(),
-            cache: ContractClientCache::new(config, provider.clone())?,
-            bc_config: OnceCell::new(),
-        };
-        Ok(ContractClient(Arc::new(inner)))
+        Self::new_impl(config, data_provider, None)
+    }
+
+    /// Runs emulations on the pool threads, so the number of parallel emulations is limited
+    pub fn new_with_emulator_pool(
+        config: ContractClientConfig,
+        data_provider: impl ContractProvider,
+        emulator_pool: EmulatorPool,
+    ) -> Result<Self, TLError> {
+        Self::new_impl(config, data_provider, Some(emulator_pool))
     }
 
     pub async fn get_contract(
@@ -70,49 +82,150 @@ impl ContractClient {
 
         let data_boc = state.data_boc.as_deref().unwrap_or(&[]);
 
-        let c7 = TVMEmulatorC7 {
-            address: state.address.clone(),
-            unix_time: SystemTime::now().duration_since(UNIX_EPOCH).map_err(TLCoreError::from)?.as_secs() as u32,
-            balance: state.balance as u64,
-            rand_seed: TonHash::ZERO,
-            config: self.get_bc_config().await?.clone(),
+        // c7 is taken at the state mc block to get reproducible results for historical states
+        let c7 = TVMEmulatorC7::new(state.address.clone(), self.get_bc_config(state.mc_seqno).await?)?
+            .with_balance(state.balance as u64);
+        let c7 = match self.0.cache.get_or_load_mc_block(state.mc_seqno).await? {
+            Some((mc_block, prev_blocks)) => c7.with_mc_block(&mc_block).with_prev_blocks(prev_blocks),
+            None => match state.sync_utime {
+                Some(utime) => c7.with_unix_time(utime),
+                None => c7,
+            },
         };
 
-        let mut emulator = TVMEmulator::new(code_boc, data_boc, &c7)?;
-
-        let code_cell = TonCell::from_boc(code_boc)?;
-        let data_cell = TonCell::from_boc(data_boc)?;
-        let lib_ids = TonCellUtils::extract_lib_ids([&code_cell, &data_cell])?;
-        let libs_rsp = self
-            .0
-            .provider
-            .load_libs(lib_ids.into_iter().collect(), state.mc_seqno)
-            .await?
-            .into_iter()
-            .map(|(_, lib)| TonCellRef::from_boc(&lib))
-            .collect::<Result<Vec<_>, _>>()?;
-
-        if !libs_rsp.is_empty() {
-            emulator.set_libs(&LibsDict::new(libs_rsp)?.to_boc()?)?;
+        let args = TVMGetMethodArgs {
+            code_boc: code_boc.clone(),
+            data_boc: data_boc.to_vec(),
+            c7,
+            libs_boc: self.load_libs_boc(state).await?,
+            method: method_id.into(),
+            stack_boc: stack_boc.to_vec(),
+        };
+        match &self.0.emulator_pool {
+            Some(pool) => pool.run_get_method(args).await,
+            // emulation is a blocking FFI call, so it mustn't run on async worker
+            None => tokio::task::spawn_blocking(move || args.run()).await?,
         }
-        emulator.run_get_method(method_id, stack_boc)
+    }
+
+    /// Emulates external message against the latest state of destination account
+    ///
+    /// Nothing is sent to the network, so it's safe to check seqno, signature and balance before broadcasting
+    pub async fn dry_run_ext_msg(&self, msg: &Msg) -> Result<TXDryRunReport, TLError> {
+        let CommonMsgInfo::ExtIn(info) = &msg.info else {
+            return Err(TLError::UnexpectedValue {
+                expected: "ExtIn message".to_string(),
+                actual: format!("{:?}", msg.info),
+            });
+        };
+        let address = TonAddress::from_msg_address(info.dst.clone())?;
+        let state = self.0.provider.load_state(address, None).await?;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).map_err(TLCoreError::from)?.as_secs() as u32;
+        let (shard_account, response) = self.emulate_msg_response(&state, msg, now).await?;
+        TXDryRunReport::from_response(&shard_account, response)
+    }
+
+    /// Notifies about new txs of the addresses, tracked by the recent tx loop
+    pub fn subscribe<I: IntoIterator<Item = TonAddress>>(&self, addresses: I) -> ContractSubscription {
+        let (receiver, lagged) = self.0.cache.subscribe(addresses.into_iter().collect());
+        ContractSubscription::new(receiver, lagged)
+    }
+
+    /// The same as `subscribe`, but yields account state after the tx
+    pub fn subscribe_states<I: IntoIterator<Item = TonAddress>>(
+        &self,
+        addresses: I,
+    ) -> impl Stream<Item = Result<Arc<ContractState>, TLError>> {
+        let client = self.clone();
+        self.subscribe(addresses).then(move |(address, tx_id)| {
+            let client = client.clone();
+            async move { client.get_contract(&address, Some(&tx_id)).await }
+        })
+    }
+
+    /// Libraries are immutable, so preloaded ones are never requested from provider
+    pub async fn preload_libs(&self, libs: &LibsDict) { self.0.cache.preload_libs(libs).await }
+
+    /// Emulates message against the specified state (not necessary the latest one)
+    ///
+    /// Uses state sync_utime as tx time if it's known
+    pub async fn emulate_msg(&self, state: &ContractState, msg: &Msg) -> Result<ContractMsgResult, TLError> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).map_err(TLCoreError::from)?.as_secs() as u32;
+        let (_, response) = self.emulate_msg_response(state, msg, state.sync_utime.unwrap_or(now)).await?;
+        ContractMsgResult::from_response(state, response)
     }
 
     pub fn cache_stats(&self) -> HashMap<String, usize> { self.0.cache.cache_stats() }
 
-    async fn get_bc_config(&self) -> Result<&EmulBCConfig, TLError> {
-        self.0
-            .bc_config
-            .get_or_try_init(|| async {
-                let config = self.0.provider.load_bc_config(None).await?;
-                EmulBCConfig::from_boc(&config)
-            })
-            .await
+    fn new_impl(
+        config: ContractClientConfig,
+        data_provider: impl ContractProvider,
+        emulator_pool: Option<EmulatorPool>,
+    ) -> Result<Self, TLError> {
+        let provider = Arc::new(data_provider);
+        let inner = Inner {
+            provider: provider.clone(),
+            cache: ContractClientCache::new(config, provider.clone())?,
+            emulator_pool,
+        };
+        Ok(ContractClient(Arc::new(inner)))
+    }
+
+    async fn emulate_msg_response(
+        &self,
+        state: &ContractState,
+        msg: &Msg,
+        utime: u32,
+    ) -> Result<(ShardAccount, TXEmulationResponse), TLError> {
+        // storage fees are charged for the time since the last tx
+        let last_paid = match state.last_tx_id.lt {
+            0 => None,
+            _ => self.0.cache.get_or_load_tx_utime(&state.address, &state.last_tx_id).await?,
+        };
+        let last_paid = last_paid.or(state.sync_utime).unwrap_or(utime).min(utime);
+        let shard_account = ShardAccount::from_contract_state(state, last_paid)?;
+        let prev_blocks_boc = match self.0.cache.get_or_load_mc_block(state.mc_seqno).await? {
+            Some((_, prev_blocks)) => Some(prev_blocks.to_boc()?),
+            None => None,
+        };
+        let args = TXEmulOrdArgs {
+            in_msg_boc: msg.to_boc()?,
+            emul_args: TXEmulArgs {
+                shard_account_boc: shard_account.to_boc()?,
+                bc_config: self.get_bc_config(state.mc_seqno).await?,
+                // seed of the block which will include the tx is unknown
+                rand_seed: TonHash::from(rand::random::<[u8; 32]>()),
+                utime,
+                lt: state.last_tx_id.lt as u64 + 1,
+                ignore_chksig: false,
+                prev_blocks_boc,
+                libs_boc: self.load_libs_boc(state).await?,
+            },
+        };
+        let response = match &self.0.emulator_pool {
+            Some(pool) => pool.emulate_ord_response(args).await?,
+            None => {
+                tokio::task::spawn_blocking(move || TXEmulator::new(0, false)?.emulate_ord_response(&args)).await??
+            }
+        };
+        Ok((shard_account, response))
+    }
+
+    async fn load_libs_boc(&self, state: &ContractState) -> Result<Option<Arc<Vec<u8>>>, TLError> {
+        let mut cells = vec![];
+        for boc in [&state.code_boc, &state.data_boc].into_iter().flatten() {
+            cells.push(TonCell::from_boc(boc)?);
+        }
+        self.0.cache.get_or_load_libs_boc(&cells, state.mc_seqno).await
+    }
+
+    async fn get_bc_config(&self, mc_seqno: Option<u32>) -> Result<EmulBCConfig, TLError> {
+        self.0.cache.get_or_load_bc_config(mc_seqno).await
     }
 }
 
 struct Inner {
     provider: Arc<dyn ContractProvider>,
     cache: Arc<ContractClientCache>,
-    bc_config: OnceCell<EmulBCConfig>,
+    emulator_pool: Option<EmulatorPool>,
 }
@@ -1,15 +1,25 @@
 use crate::contracts::client::cache_stats::CacheStats;
 use crate::contracts::client::contract_client::ContractClientConfig;
+use crate::emulators::emul_bc_config::EmulBCConfig;
+use crate::emulators::emul_prev_blocks::{mc_block_id, EmulPrevBlocks};
 use crate::error::TLError;
+use crate::libs_dict::LibsDict;
 use futures_util::future::join_all;
 use moka::future::Cache;
-use std::collections::HashMap;
+use parking_lot::RwLock;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::hash::Hash;
+use std::ops::Deref;
 use std::sync::atomic::Ordering::Relaxed;
+use std::sync::atomic::{AtomicBool, AtomicU64};
 use std::sync::{Arc, Weak};
 use std::time::Duration;
+use tokio::sync::mpsc::error::TrySendError;
+use tokio::sync::mpsc::{channel, Receiver, Sender};
+use ton_lib_core::cell::{TonCell, TonCellRef, TonCellUtils, TonHash};
 use ton_lib_core::error::TLCoreError;
-use ton_lib_core::traits::contract_provider::{ContractProvider, ContractState};
+use ton_lib_core::traits::contract_provider::{ContractProvider, ContractState, McBlockInfo};
+use ton_lib_core::traits::tlb::TLB;
 use ton_lib_core::types::{TonAddress, TxIdLTHash};
 
 pub(super) struct ContractClientCache {
@@ -17,9 +27,40 @@ pub(super) struct ContractClientCache {
     latest_tx_cache: Cache<TonAddress, TxIdLTHash>,
     state_latest_cache: Cache<TonAddress, Arc<ContractState>>,
     state_by_tx_cache: Cache<TxIdLTHash, Arc<ContractState>>,
+    // libraries are immutable, so no ttl
+    libs_cache: Cache<TonHash, TonCellRef>,
+    missing_libs_cache: Cache<TonHash, ()>, // not found by provider, requested again after MISSING_LIBS_TTL
+    libs_boc_cache: Cache<Vec<TonHash>, Arc<Vec<u8>>>, // sorted lib_ids -> LibsDict boc
+    // config changes only in key blocks
+    key_block_seqno_cache: Cache<u32, u32>,    // mc_seqno -> key_block_seqno
+    bc_config_cache: Cache<u32, EmulBCConfig>, // key_block_seqno -> config
+    // used if provider can't resolve key_block_seqno, filled by recent_tx_loop
+    seen_key_blocks: RwLock<BTreeSet<u32>>,
+    key_block_seqno_unsupported: AtomicBool,
+    latest_mc_seqno: RwLock<Option<u32>>, // updated by recent_tx_loop
+    mc_blocks_cache: Cache<u32, McBlockInfo>,
+    prev_blocks_cache: Cache<u32, EmulPrevBlocks>, // mc_seqno -> prev blocks
+    tx_utime_cache: Cache<TxIdLTHash, u32>,
+    subscriptions: RwLock<Vec<AccountsSubscription>>,
     cache_stats: CacheStats,
 }
 
+struct AccountsSubscription {
+    addresses: HashSet<TonAddress>,
+    sender: Sender<(TonAddress, TxIdLTHash)>,
+    lagged: Arc<AtomicU64>, // events dropped because the receiver is full
+}
+
+// events are not awaited by recent_tx_loop, so slow subscribers lose them instead of blocking it
+const SUBSCRIPTION_CAPACITY: usize = 1024;
+const LIBS_CACHE_CAPACITY: u64 = 10_000;
+const MISSING_LIBS_TTL: Duration = Duration::from_secs(10);
+const KEY_BLOCK_SEQNO_CACHE_CAPACITY: u64 = 10_000;
+const BC_CONFIG_CACHE_CAPACITY: u64 = 100;
+const MC_BLOCKS_CACHE_CAPACITY: u64 = 10_000;
+const PREV_BLOCKS_CACHE_CAPACITY: u64 = 100;
+const TX_UTIME_CACHE_CAPACITY: u64 = 10_000;
+
 impl ContractClientCache {
     pub(super) fn new(config: ContractClientConfig, provider: Arc<dyn ContractProvider>) -> Result<Arc<Self>, TLError> {
         let (capacity, ttl) = (config.cache_capacity, config.cache_ttl);
@@ -28,6 +69,21 @@ impl ContractClientCache {
             latest_tx_cache: init_cache(capacity, ttl),
             state_latest_cache: init_cache(capacity, ttl),
             state_by_tx_cache: init_cache(capacity, ttl),
+            libs_cache: Cache::new(LIBS_CACHE_CAPACITY),
+            missing_libs_cache: Cache::builder()
+                .max_capacity(LIBS_CACHE_CAPACITY)
+                .time_to_live(MISSING_LIBS_TTL)
+                .build(),
+            libs_boc_cache: Cache::new(LIBS_CACHE_CAPACITY),
+            key_block_seqno_cache: Cache::new(KEY_BLOCK_SEQNO_CACHE_CAPACITY),
+            bc_config_cache: Cache::new(BC_CONFIG_CACHE_CAPACITY),
+            seen_key_blocks: RwLock::new(BTreeSet::new()),
+            key_block_seqno_unsupported: AtomicBool::new(false),
+            latest_mc_seqno: RwLock::new(None),
+            mc_blocks_cache: Cache::new(MC_BLOCKS_CACHE_CAPACITY),
+            prev_blocks_cache: Cache::new(PREV_BLOCKS_CACHE_CAPACITY),
+            tx_utime_cache: Cache::new(TX_UTIME_CACHE_CAPACITY),
+            subscriptions: RwLock::new(vec![]),
             cache_stats: CacheStats::default(),
         });
         let weak = Arc::downgrade(&client_cache);
@@ -57,10 +113,139 @@ impl ContractClientCache {
         Ok(state)
     }
 
+    /// Returns LibsDict boc with all libraries used by cells (including nested ones), None if no libraries found
+    pub(super) async fn get_or_load_libs_boc(
+        &self,
+        cells: &[TonCell],
+        mc_seqno: Option<u32>,
+    ) -> Result<Option<Arc<Vec<u8>>>, TLError> {
+        let mut libs = HashMap::new();
+        let mut requested = HashSet::new();
+        let mut to_resolve = TonCellUtils::extract_lib_ids(cells)?;
+        while !to_resolve.is_empty() {
+            requested.extend(to_resolve.iter().cloned());
+            let mut missing = vec![];
+            for lib_id in to_resolve {
+                self.cache_stats.libs_req.fetch_add(1, Relaxed);
+                match self.libs_cache.get(&lib_id).await {
+                    Some(lib) => {
+                        libs.insert(lib_id, lib);
+                    }
+                    None if self.missing_libs_cache.contains_key(&lib_id) => {}
+                    None => missing.push(lib_id),
+                }
+            }
+            if !missing.is_empty() {
+                self.cache_stats.libs_miss.fetch_add(missing.len(), Relaxed);
+                let mut not_found = missing.iter().cloned().collect::<HashSet<_>>();
+                for (lib_id, lib_boc) in self.provider.load_libs(missing, mc_seqno).await? {
+                    let lib = TonCellRef::from_boc(&lib_boc)?;
+                    if lib.hash()? != &lib_id {
+                        log::warn!("[ContractClientCache] got lib with hash {} for lib_id {lib_id}", lib.hash()?);
+                        continue;
+                    }
+                    not_found.remove(&lib_id);
+                    self.libs_cache.insert(lib_id.clone(), lib.clone()).await;
+                    libs.insert(lib_id, lib);
+                }
+                for lib_id in not_found {
+                    self.missing_libs_cache.insert(lib_id, ()).await;
+                }
+            }
+            // libraries may reference other libraries
+            let nested = TonCellUtils::extract_lib_ids(libs.values().map(|x| x.deref()))?;
+            to_resolve = nested.into_iter().filter(|x| !requested.contains(x)).collect();
+        }
+        if libs.is_empty() {
+            return Ok(None);
+        }
+        let mut lib_ids = libs.keys().cloned().collect::<Vec<_>>();
+        lib_ids.sort();
+        let libs_boc = self
+            .libs_boc_cache
+            .try_get_with(lib_ids, async move { Ok::<_, TLCoreError>(Arc::new(LibsDict::from(libs).to_boc()?)) })
+            .await?;
+        Ok(Some(libs_boc))
+    }
+
+    pub(super) async fn preload_libs(&self, libs: &LibsDict) {
+        for (lib_id, lib) in libs.iter() {
+            self.libs_cache.insert(lib_id.clone(), lib.clone()).await;
+        }
+    }
+
+    /// Returns config of the latest key block at mc_seqno, latest config if mc_seqno is None
+    pub(super) async fn get_or_load_bc_config(&self, mc_seqno: Option<u32>) -> Result<EmulBCConfig, TLError> {
+        let mc_seqno = self.resolve_mc_seqno(mc_seqno).await?;
+        let key_block_seqno = self.get_or_load_key_block_seqno(mc_seqno).await?;
+        let load_config = async {
+            log::info!("[ContractClientCache] loading config for key_block_seqno: {key_block_seqno}");
+            let config_boc = self.provider.load_bc_config(Some(key_block_seqno)).await?;
+            Ok::<_, TLCoreError>(EmulBCConfig::from_boc(&config_boc)?)
+        };
+        Ok(self.bc_config_cache.try_get_with(key_block_seqno, load_config).await?)
+    }
+
+    /// Returns mc block with its prev blocks (the latest block if mc_seqno is None)
+    ///
+    /// None if provider doesn't support mc blocks loading
+    pub(super) async fn get_or_load_mc_block(
+        &self,
+        mc_seqno: Option<u32>,
+    ) -> Result<Option<(McBlockInfo, EmulPrevBlocks)>, TLError> {
+        let mc_seqno = self.resolve_mc_seqno(mc_seqno).await?;
+        let mc_block = match self.get_or_load_mc_block_info(mc_seqno).await {
+            Ok(mc_block) => mc_block,
+            Err(err) if matches!(err.as_ref(), TLCoreError::ProviderUnsupported(_)) => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let load_prev_blocks = async {
+            let global_version =
+                self.get_or_load_bc_config(Some(mc_seqno)).await?.to_config_params()?.global_version()?;
+            let load_block_id = |seqno| async move { Ok(mc_block_id(&self.get_or_load_mc_block_info(seqno).await?)) };
+            let prev_key_block_seqno = mc_block.prev_key_block_seqno;
+            let prev_blocks =
+                EmulPrevBlocks::load_with(mc_seqno, prev_key_block_seqno, global_version.version, load_block_id)
+                    .await?;
+            Ok::<_, TLCoreError>(prev_blocks)
+        };
+        let prev_blocks = self.prev_blocks_cache.try_get_with(mc_seqno, load_prev_blocks).await?;
+        Ok(Some((mc_block, prev_blocks)))
+    }
+
+    /// None if provider doesn't support it
+    pub(super) async fn get_or_load_tx_utime(
+        &self,
+        address: &TonAddress,
+        tx_id: &TxIdLTHash,
+    ) -> Result<Option<u32>, TLError> {
+        let load_utime = self.provider.load_tx_utime(address.clone(), tx_id.clone());
+        match self.tx_utime_cache.try_get_with_by_ref(tx_id, load_utime).await {
+            Ok(utime) => Ok(Some(utime)),
+            Err(err) if matches!(err.as_ref(), TLCoreError::ProviderUnsupported(_)) => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    pub(super) fn subscribe(
+        &self,
+        addresses: HashSet<TonAddress>,
+    ) -> (Receiver<(TonAddress, TxIdLTHash)>, Arc<AtomicU64>) {
+        let (sender, receiver) = channel(SUBSCRIPTION_CAPACITY);
+        let lagged = Arc::new(AtomicU64::new(0));
+        self.subscriptions.write().push(AccountsSubscription {
+            addresses,
+            sender,
+            lagged: lagged.clone(),
+        });
+        (receiver, lagged)
+    }
+
     pub(super) fn cache_stats(&self) -> HashMap<String, usize> {
         let latest_entry_count = self.state_latest_cache.entry_count() as usize;
         let by_tx_entry_count = self.state_by_tx_cache.entry_count() as usize;
-        self.cache_stats.export(latest_entry_count, by_tx_entry_count)
+        let libs_entry_count = self.libs_cache.entry_count() as usize;
+        self.cache_stats.export(latest_entry_count, by_tx_entry_count, libs_entry_count)
     }
 
     async fn load_contract(
@@ -75,6 +260,82 @@ impl ContractClientCache {
         let state = self.provider.load_state(address.clone(), tx_id).await?;
         Ok(Arc::new(state))
     }
+
+    /// mc_seqno or the latest one seen by recent_tx_loop
+    async fn resolve_mc_seqno(&self, mc_seqno: Option<u32>) -> Result<u32, TLError> {
+        let latest_mc_seqno = *self.latest_mc_seqno.read();
+        match mc_seqno.or(latest_mc_seqno) {
+            Some(seqno) => Ok(seqno),
+            None => Ok(self.provider.last_mc_seqno().await?),
+        }
+    }
+
+    // mc blocks are immutable, so no ttl
+    async fn get_or_load_mc_block_info(&self, mc_seqno: u32) -> Result<McBlockInfo, Arc<TLCoreError>> {
+        let load_block = self.provider.load_mc_block_info(Some(mc_seqno));
+        self.mc_blocks_cache.try_get_with(mc_seqno, load_block).await
+    }
+
+    // config at mc_seqno is the same as at its key block
+    // if provider can't resolve it, the latest key block seen by recent_tx_loop is used (mc_seqno itself for older blocks)
+    async fn get_or_load_key_block_seqno(&self, mc_seqno: u32) -> Result<u32, TLError> {
+        let load_seqno = self.provider.load_key_block_seqno(Some(mc_seqno));
+        match self.key_block_seqno_cache.try_get_with(mc_seqno, load_seqno).await {
+            Ok(seqno) => Ok(seqno),
+            Err(err) if matches!(err.as_ref(), TLCoreError::ProviderUnsupported(_)) => {
+                self.key_block_seqno_unsupported.store(true, Relaxed);
+                let seen_seqno = self.seen_key_blocks.read().range(..=mc_seqno).next_back().copied();
+                Ok(seen_seqno.unwrap_or(mc_seqno))
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    // key block is detected by prev_key_block_seqno of the next mc block, so new config is used with 1 block delay
+    async fn track_key_blocks(&self, mc_seqno: u32) {
+        if !self.key_block_seqno_unsupported.load(Relaxed) {
+            return;
+        }
+        match self.get_or_load_mc_block_info(mc_seqno).await {
+            Ok(mc_block) => {
+                let key_block_seqno = mc_block.prev_key_block_seqno;
+                if self.seen_key_blocks.write().insert(key_block_seqno) {
+                    log::info!("[ContractClientCache] key block {key_block_seqno} is seen at mc_seqno {mc_seqno}");
+                }
+            }
+            Err(err) if matches!(err.as_ref(), TLCoreError::ProviderUnsupported(_)) => {}
+            Err(err) => log::warn!("[ContractClientCache] fail to load mc block {mc_seqno} to track key blocks: {err}"),
+        }
+    }
+
+    fn notify_subscribers(&self, latest_tx_per_addr: &HashMap<TonAddress, TxIdLTHash>) {
+        let mut subscriptions = self.subscriptions.write();
+        subscriptions.retain(|subscription| {
+            let mut dropped = 0;
+            for address in &subscription.addresses {
+                let Some(tx_id) = latest_tx_per_addr.get(address) else {
+                    continue;
+                };
+                match subscription.sender.try_send((address.clone(), tx_id.clone())) {
+                    Ok(()) => {}
+                    Err(TrySendError::Full(_)) => dropped += 1,
+                    Err(TrySendError::Closed(_)) => return false,
+                }
+            }
+            if dropped > 0 {
+                let total = subscription.lagged.fetch_add(dropped, Relaxed) + dropped;
+                log::warn!("[ContractClient] subscriber lagged: dropped {dropped} events ({total} total)");
+            }
+            !subscription.sender.is_closed()
+        });
+    }
+
+    fn update_latest_mc_seqno(&self, mc_seqno: u32) {
+        let mut latest = self.latest_mc_seqno.write();
+        if *latest < Some(mc_seqno) {
+            *latest = Some(mc_seqno);
+        }
+    }
 }
 
 async fn recent_tx_loop(weak_cache: Weak<ContractClientCache>, idle_on_error: Duration) {
@@ -115,11 +376,16 @@ async fn recent_tx_loop(weak_cache: Weak<ContractClientCache>, idle_on_error: Du
             }
         };
 
-        let update_cache_futs = latest_tx_per_addr.into_iter().map(|(address, tx_id)| async move {
-            client_cache_ref.latest_tx_cache.insert(address.clone(), tx_id).await;
-            client_cache_ref.state_latest_cache.invalidate(&address).await;
+        let update_cache_futs = latest_tx_per_addr.iter().map(|(address, tx_id)| async move {
+            client_cache_ref.latest_tx_cache.insert(address.clone(), tx_id.clone()).await;
+            client_cache_ref.state_latest_cache.invalidate(address).await;
         });
         join_all(update_cache_futs).await;
+
+        // config is resolved on the next use
+        client_cache_ref.track_key_blocks(cur_mc_seqno).await;
+        client_cache_ref.update_latest_mc_seqno(cur_mc_seqno);
+        client_cache_ref.notify_subscribers(&latest_tx_per_addr);
         cur_mc_seqno += 1;
     }
     log::info!("[recent_tx_loop] completed");
@@ -132,3 +398,206 @@ where
 {
     Cache::builder().max_capacity(capacity).time_to_live(ttl).build()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contracts::client::fixture_provider::FixtureProvider;
+
+    fn make_lib_ref(lib_id: &TonHash) -> anyhow::Result<TonCell> {
+        Ok(TonCell::from_boc_hex(&format!("b5ee9c7201010101002300084202{}", lib_id.to_hex()))?)
+    }
+
+    #[tokio::test]
+    async fn test_contract_client_cache_nested_libs() -> anyhow::Result<()> {
+        let mut builder = TonCell::builder();
+        builder.write_num(&0xBu8, 4)?;
+        let nested_lib = builder.build()?;
+        let mut builder = TonCell::builder();
+        builder.write_num(&0xAu8, 4)?;
+        builder.write_ref(make_lib_ref(nested_lib.hash()?)?.into_ref())?;
+        let lib = builder.build()?;
+        let missing_lib_id = TonHash::from([1u8; 32]);
+        let wrong_lib_id = TonHash::from([2u8; 32]);
+
+        let mut provider = FixtureProvider::new().with_lib(lib.clone())?.with_lib(nested_lib.clone())?;
+        provider.libs.insert(wrong_lib_id.clone(), lib.to_boc()?);
+        let provider = Arc::new(provider);
+        let config = ContractClientConfig::new_no_cache(Duration::from_secs(1));
+        let cache = ContractClientCache::new(config, provider.clone())?;
+
+        let code = make_lib_ref(lib.hash()?)?;
+        let data = make_lib_ref(&missing_lib_id)?;
+        let libs_boc = cache.get_or_load_libs_boc(&[code.clone(), data], None).await?.unwrap();
+        let libs_dict = LibsDict::from_boc(&libs_boc)?;
+        assert_eq!(libs_dict.len(), 2);
+        assert!(libs_dict.contains_key(nested_lib.hash()?));
+        assert_eq!(provider.calls("load_libs"), 2);
+
+        // resolved from cache, boc is reused
+        let libs_boc_cached = cache.get_or_load_libs_boc(&[code], None).await?.unwrap();
+        assert!(Arc::ptr_eq(&libs_boc, &libs_boc_cached));
+        assert_eq!(provider.calls("load_libs"), 2);
+
+        // missing lib is not requested again until ttl expires, lib with wrong hash is ignored
+        assert!(cache.get_or_load_libs_boc(&[make_lib_ref(&missing_lib_id)?], None).await?.is_none());
+        assert_eq!(provider.calls("load_libs"), 2);
+        assert!(cache.get_or_load_libs_boc(&[make_lib_ref(&wrong_lib_id)?], None).await?.is_none());
+        assert_eq!(provider.calls("load_libs"), 3);
+
+        // preloaded libs are not requested
+        let mut builder = TonCell::builder();
+        builder.write_num(&0xCu8, 4)?;
+        let preloaded = builder.build()?.into_ref();
+        cache.preload_libs(&LibsDict::new([preloaded.clone()])?).await;
+        assert!(cache.get_or_load_libs_boc(&[make_lib_ref(preloaded.hash()?)?], None).await?.is_some());
+        assert_eq!(provider.calls("load_libs"), 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_contract_client_cache_bc_config_per_key_block() -> anyhow::Result<()> {
+        // key block every 10 blocks, the latest mc block is 25
+        let provider = [0, 10, 20, 30].into_iter().fold(FixtureProvider::new(), |provider, seqno| {
+            provider.with_bc_config(seqno, u32::to_be_bytes(seqno).to_vec())
+        });
+        let provider = Arc::new(provider.with_last_mc_seqno(25));
+        let config = ContractClientConfig::new_no_cache(Duration::from_secs(1));
+        let cache = ContractClientCache::new(config, provider.clone())?;
+
+        let expected_config = |key_block_seqno: u32| EmulBCConfig::from_boc(&key_block_seqno.to_be_bytes());
+        assert_eq!(*cache.get_or_load_bc_config(Some(15)).await?, *expected_config(10)?);
+        assert_eq!(*cache.get_or_load_bc_config(Some(19)).await?, *expected_config(10)?);
+        assert_eq!(*cache.get_or_load_bc_config(Some(5)).await?, *expected_config(0)?);
+        assert_eq!(*cache.get_or_load_bc_config(None).await?, *expected_config(20)?);
+        assert_eq!(provider.calls("load_bc_config"), 3);
+
+        // new block seen by recent_tx_loop
+        cache.update_latest_mc_seqno(35);
+        cache.update_latest_mc_seqno(20);
+        assert_eq!(*cache.get_or_load_bc_config(None).await?, *expected_config(30)?);
+        assert_eq!(provider.calls("load_bc_config"), 4);
+        Ok(())
+    }
+
+    // FixtureProvider without load_key_block_seqno support
+    struct NoKeyBlocksProvider(FixtureProvider);
+
+    #[async_trait::async_trait]
+    impl ContractProvider for NoKeyBlocksProvider {
+        async fn last_mc_seqno(&self) -> Result<u32, TLCoreError> { self.0.last_mc_seqno().await }
+        async fn load_state(
+            &self,
+            address: TonAddress,
+            tx_id: Option<TxIdLTHash>,
+        ) -> Result<ContractState, TLCoreError> {
+            self.0.load_state(address, tx_id).await
+        }
+        async fn load_bc_config(&self, mc_seqno: Option<u32>) -> Result<Vec<u8>, TLCoreError> {
+            self.0.load_bc_config(mc_seqno).await
+        }
+        async fn load_libs(
+            &self,
+            lib_ids: Vec<TonHash>,
+            mc_seqno: Option<u32>,
+        ) -> Result<Vec<(TonHash, Vec<u8>)>, TLCoreError> {
+            self.0.load_libs(lib_ids, mc_seqno).await
+        }
+        async fn load_latest_tx_per_address(
+            &self,
+            mc_seqno: u32,
+        ) -> Result<HashMap<TonAddress, TxIdLTHash>, TLCoreError> {
+            self.0.load_latest_tx_per_address(mc_seqno).await
+        }
+        async fn load_mc_block_info(&self, mc_seqno: Option<u32>) -> Result<McBlockInfo, TLCoreError> {
+            self.0.load_mc_block_info(mc_seqno).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_contract_client_cache_bc_config_key_block_unsupported() -> anyhow::Result<()> {
+        // key block every 10 blocks, mc blocks 18..=22 are known
+        let fixture = [0, 10, 20].into_iter().fold(FixtureProvider::new(), |provider, seqno| {
+            provider.with_bc_config(seqno, u32::to_be_bytes(seqno).to_vec())
+        });
+        let fixture = (18..=22).fold(fixture, |provider, seqno| {
+            provider.with_mc_block(McBlockInfo {
+                seqno,
+                root_hash: TonHash::ZERO,
+                file_hash: TonHash::ZERO,
+                gen_utime: seqno,
+                prev_key_block_seqno: if seqno > 20 { 20 } else { 10 },
+                rand_seed: None,
+            })
+        });
+        let fixture = fixture.with_last_mc_seqno(18);
+        let config = ContractClientConfig::new_no_cache(Duration::from_secs(1));
+        let cache = ContractClientCache::new(config, Arc::new(NoKeyBlocksProvider(fixture.clone())))?;
+
+        // no key blocks are seen yet, so config is loaded per mc block
+        let expected_config = |key_block_seqno: u32| EmulBCConfig::from_boc(&key_block_seqno.to_be_bytes());
+        assert_eq!(*cache.get_or_load_bc_config(Some(18)).await?, *expected_config(10)?);
+        assert_eq!(*cache.get_or_load_bc_config(Some(19)).await?, *expected_config(10)?);
+        assert_eq!(fixture.calls("load_bc_config"), 2);
+
+        // blocks processed by recent_tx_loop
+        for seqno in 18..=22 {
+            cache.track_key_blocks(seqno).await;
+        }
+        assert_eq!(*cache.seen_key_blocks.read(), BTreeSet::from([10, 20]));
+        assert_eq!(*cache.get_or_load_bc_config(Some(18)).await?, *expected_config(10)?);
+        assert_eq!(*cache.get_or_load_bc_config(Some(19)).await?, *expected_config(10)?);
+        assert_eq!(*cache.get_or_load_bc_config(Some(21)).await?, *expected_config(20)?);
+        assert_eq!(*cache.get_or_load_bc_config(Some(22)).await?, *expected_config(20)?);
+        assert_eq!(fixture.calls("load_bc_config"), 4);
+
+        // blocks before the first seen key block
+        assert_eq!(*cache.get_or_load_bc_config(Some(5)).await?, *expected_config(0)?);
+        assert_eq!(fixture.calls("load_bc_config"), 5);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_contract_client_cache_subscribe() -> anyhow::Result<()> {
+        let address = TonAddress::new(0, TonHash::from([1u8; 32]));
+        let other_address = TonAddress::new(0, TonHash::from([2u8; 32]));
+        let tx_id = |lt: i64| TxIdLTHash::new(lt, TonHash::from([lt as u8; 32]));
+        let provider = FixtureProvider::new()
+            .with_latest_txs(10, HashMap::from([(address.clone(), tx_id(1)), (other_address.clone(), tx_id(2))]))
+            .with_latest_txs(11, HashMap::from([(other_address.clone(), tx_id(3))]))
+            .with_latest_txs(12, HashMap::from([(address.clone(), tx_id(4))]));
+        let config = ContractClientConfig::new_no_cache(Duration::from_millis(10));
+        let cache = ContractClientCache::new(config, Arc::new(provider))?;
+
+        let (mut receiver, _) = cache.subscribe(HashSet::from([address.clone()]));
+        let (dropped_receiver, _) = cache.subscribe(HashSet::from([other_address]));
+        drop(dropped_receiver);
+
+        let recv_timeout = Duration::from_secs(1);
+        assert_eq!(tokio::time::timeout(recv_timeout, receiver.recv()).await?, Some((address.clone(), tx_id(1))));
+        assert_eq!(tokio::time::timeout(recv_timeout, receiver.recv()).await?, Some((address, tx_id(4))));
+        assert_eq!(cache.subscriptions.read().len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_contract_client_cache_subscribe_lagged() -> anyhow::Result<()> {
+        let address = TonAddress::new(0, TonHash::from([1u8; 32]));
+        let tx_id = TxIdLTHash::new(1, TonHash::from([1u8; 32]));
+        let config = ContractClientConfig::new_no_cache(Duration::from_millis(10));
+        let cache = ContractClientCache::new(config, Arc::new(FixtureProvider::new()))?;
+
+        let (mut receiver, lagged) = cache.subscribe(HashSet::from([address.clone()]));
+        let latest_tx_per_addr = HashMap::from([(address.clone(), tx_id.clone())]);
+        for _ in 0..SUBSCRIPTION_CAPACITY + 2 {
+            cache.notify_subscribers(&latest_tx_per_addr);
+        }
+        assert_eq!(lagged.load(Relaxed), 2);
+        assert_eq!(receiver.recv().await, Some((address, tx_id)));
+
+        drop(receiver);
+        cache.notify_subscribers(&latest_tx_per_addr);
+        assert!(cache.subscriptions.read().is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+use crate::block_tlb::{OutList, Tx};
+use crate::emulators::tx::tx_emul_response::TXEmulationResponse;
+use crate::error::TLError;
+use std::sync::Arc;
+use ton_lib_core::cell::TonCellRef;
+use ton_lib_core::traits::contract_provider::ContractState;
+use ton_lib_core::traits::tlb::TLB;
+
+/// Result of message emulation against ContractState
+///
+/// `new_state` can be used to emulate the next message on top of this one (see `TonContract::with_state`)
+#[derive(Debug, Clone)]
+pub struct ContractMsgResult {
+    /// false if external message is rejected by the contract, the state is unchanged in this case
+    pub accepted: bool,
+    pub exit_code: Option<i32>,
+    pub gas_used: u64,
+    pub out_actions: OutList,
+    pub new_data: Option<TonCellRef>,
+    pub new_state: Arc<ContractState>,
+    pub tx: Option<Tx>,
+    pub vm_log: String,
+}
+
+impl ContractMsgResult {
+    pub fn from_response(state: &ContractState, response: TXEmulationResponse) -> Result<Self, TLError> {
+        if !response.success {
+            if response.external_not_accepted != Some(true) {
+                return Err(TLError::EmulatorEmulationError {
+                    vm_exit_code: response.vm_exit_code,
+                    response_raw: response.raw_response,
+                });
+            }
+            return Ok(Self {
+                accepted: false,
+                exit_code: response.vm_exit_code,
+                gas_used: 0,
+                out_actions: OutList::default(),
+                new_data: state.data_boc.as_deref().map(TonCellRef::from_boc).transpose()?,
+                new_state: Arc::new(state.clone()),
+                tx: None,
+                vm_log: response.vm_log.unwrap_or_default(),
+            });
+        }
+        let out_actions = match &response.actions {
+            Some(actions) => OutList::from_boc_b64(actions)?,
+            None => OutList::default(),
+        };
+        let success = response.into_success()?;
+        let tx = success.tx_parsed()?;
+        let shard_account = success.shard_account_parsed()?;
+        let new_state = ContractState {
+            mc_seqno: state.mc_seqno,
+            sync_utime: state.sync_utime,
+            ..shard_account.to_contract_state(state.address.clone())?
+        };
+        Ok(Self {
+            accepted: true,
+            exit_code: tx.descr.exit_code(),
+            gas_used: tx.descr.compute_phase().map(|x| *x.compute_phase_vm_info.gas_used).unwrap_or(0),
+            out_actions,
+            new_data: shard_account.account.get_data().cloned(),
+            new_state: Arc::new(new_state),
+            tx: Some(tx),
+            vm_log: success.vm_log,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block_tlb::_test_block_data::{
+        WALLET_EXT_IN_MSG_BOC_HEX, WALLET_EXT_IN_MSG_LT, WALLET_EXT_IN_MSG_UTIME, WALLET_SHARD_ACCOUNT_BOC_HEX,
+    };
+    use crate::block_tlb::{Msg, ShardAccount};
+    use crate::emulators::emul_bc_config::EmulBCConfig;
+    use crate::emulators::tx::tx_emul_args::{TXEmulArgs, TXEmulOrdArgs};
+    use crate::emulators::tx::tx_emulator::TXEmulator;
+    use crate::sys_utils::sys_tonlib_set_verbosity_level;
+    use ton_lib_core::cell::TonHash;
+    use ton_lib_core::types::TonAddress;
+
+    #[test]
+    fn test_contract_msg_result() -> anyhow::Result<()> {
+        sys_tonlib_set_verbosity_level(0);
+        let bc_config =
+            EmulBCConfig::from_boc_hex(include_str!("../../../../resources/tests/bc_config_key_block_42123611.hex"))?;
+        let shard_account = ShardAccount::from_boc_hex(WALLET_SHARD_ACCOUNT_BOC_HEX)?;
+        let ext_in_msg = Msg::from_boc_hex(WALLET_EXT_IN_MSG_BOC_HEX)?;
+        let address = TonAddress::from_msg_address(ext_in_msg.dst())?;
+        let state = ContractState {
+            sync_utime: Some(WALLET_EXT_IN_MSG_UTIME),
+            ..shard_account.to_contract_state(address)?
+        };
+        let mut args = TXEmulOrdArgs {
+            in_msg_boc: ext_in_msg.to_boc()?,
+            emul_args: TXEmulArgs {
+                shard_account_boc: shard_account.to_boc()?,
+                bc_config,
+                rand_seed: TonHash::ZERO,
+                utime: WALLET_EXT_IN_MSG_UTIME,
+                lt: WALLET_EXT_IN_MSG_LT,
+                ignore_chksig: false,
+                prev_blocks_boc: None,
+                libs_boc: None,
+            },
+        };
+        let mut emulator = TXEmulator::new(0, false)?;
+
+        let rejected = ContractMsgResult::from_response(&state, emulator.emulate_ord_response(&args)?)?;
+        assert!(!rejected.accepted);
+        assert_eq!(*rejected.new_state, state);
+        assert_eq!(rejected.new_data, shard_account.account.get_data().cloned());
+
+        args.emul_args.ignore_chksig = true;
+        let result = ContractMsgResult::from_response(&state, emulator.emulate_ord_response(&args)?)?;
+        assert!(result.accepted);
+        assert_eq!(result.exit_code, Some(0));
+        assert!(result.gas_used > 0);
+        assert_eq!(result.out_actions.actions.len(), 1);
+        assert_ne!(result.new_data, shard_account.account.get_data().cloned());
+        assert_eq!(result.new_state.last_tx_id.lt, WALLET_EXT_IN_MSG_LT as i64);
+        assert_eq!(result.new_state.sync_utime, state.sync_utime);
+        assert!(result.new_state.balance < state.balance);
+        Ok(())
+    }
+}
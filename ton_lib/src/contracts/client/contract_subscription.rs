@@ -0,0 +1,40 @@
+use futures_util::Stream;
+use std::pin::Pin;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering::Relaxed;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tokio::sync::mpsc::Receiver;
+use ton_lib_core::types::{TonAddress, TxIdLTHash};
+
+/// Stream of (address, latest_tx_id) for watched accounts, produced by ContractClient::subscribe
+///
+/// Events are sent once per masterchain block, so several txs of one account in a block produce a single event.
+/// The buffer is bounded: if the subscriber doesn't keep up, new events are dropped and counted in `lagged`.
+/// Subscription is cancelled on drop.
+pub struct ContractSubscription {
+    receiver: Receiver<(TonAddress, TxIdLTHash)>,
+    lagged: Arc<AtomicU64>,
+}
+
+impl ContractSubscription {
+    pub(super) fn new(receiver: Receiver<(TonAddress, TxIdLTHash)>, lagged: Arc<AtomicU64>) -> Self {
+        Self { receiver, lagged }
+    }
+
+    /// Returns None if ContractClient is dropped
+    pub async fn recv(&mut self) -> Option<(TonAddress, TxIdLTHash)> { self.receiver.recv().await }
+
+    /// Number of events dropped because the buffer was full
+    ///
+    /// Use ContractClient::get_contract to catch up with the latest state after lagging
+    pub fn lagged(&self) -> u64 { self.lagged.load(Relaxed) }
+}
+
+impl Stream for ContractSubscription {
+    type Item = (TonAddress, TxIdLTHash);
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
@@ -0,0 +1,103 @@
+use crate::contracts::client::provider_backends::ProviderBackends;
+use async_trait::async_trait;
+use futures_util::future::BoxFuture;
+use std::collections::HashMap;
+use ton_lib_core::cell::TonHash;
+use ton_lib_core::error::TLCoreError;
+use ton_lib_core::traits::contract_provider::{ContractProvider, ContractState, McBlockInfo};
+use ton_lib_core::types::{TonAddress, TxIdLTHash};
+
+const NAME: &str = "FailoverProvider";
+
+/// Tries providers in the order they were added, returns the first successful result
+#[derive(Default)]
+pub struct FailoverProvider {
+    backends: ProviderBackends,
+}
+
+impl FailoverProvider {
+    pub fn new() -> Self { Self::default() }
+
+    pub fn with_provider(mut self, name: &str, provider: impl ContractProvider) -> Self {
+        self.backends.push(name, provider);
+        self
+    }
+
+    /// provider name -> number of answers it returned
+    pub fn answers_stats(&self) -> HashMap<String, usize> { self.backends.answers_stats() }
+
+    /// Runs request like trait methods do, returns the result with name of the provider answered it
+    pub async fn request_with_source<T, F>(&self, request: F) -> Result<(T, String), TLCoreError>
+    where
+        F: for<'a> Fn(&'a dyn ContractProvider) -> BoxFuture<'a, Result<T, TLCoreError>>,
+    {
+        self.backends.first_ok_with_source(NAME, request).await
+    }
+}
+
+#[async_trait]
+impl ContractProvider for FailoverProvider {
+    async fn last_mc_seqno(&self) -> Result<u32, TLCoreError> {
+        self.backends.first_ok(NAME, |p| p.last_mc_seqno()).await
+    }
+
+    async fn load_state(&self, address: TonAddress, tx_id: Option<TxIdLTHash>) -> Result<ContractState, TLCoreError> {
+        self.backends.first_ok(NAME, |p| p.load_state(address.clone(), tx_id.clone())).await
+    }
+
+    async fn load_bc_config(&self, mc_seqno: Option<u32>) -> Result<Vec<u8>, TLCoreError> {
+        self.backends.first_ok(NAME, |p| p.load_bc_config(mc_seqno)).await
+    }
+
+    async fn load_key_block_seqno(&self, mc_seqno: Option<u32>) -> Result<u32, TLCoreError> {
+        self.backends.first_ok(NAME, |p| p.load_key_block_seqno(mc_seqno)).await
+    }
+
+    async fn load_libs(
+        &self,
+        lib_ids: Vec<TonHash>,
+        mc_seqno: Option<u32>,
+    ) -> Result<Vec<(TonHash, Vec<u8>)>, TLCoreError> {
+        self.backends.first_ok(NAME, |p| p.load_libs(lib_ids.clone(), mc_seqno)).await
+    }
+
+    async fn load_latest_tx_per_address(&self, mc_seqno: u32) -> Result<HashMap<TonAddress, TxIdLTHash>, TLCoreError> {
+        self.backends.first_ok(NAME, |p| p.load_latest_tx_per_address(mc_seqno)).await
+    }
+
+    async fn load_tx_utime(&self, address: TonAddress, tx_id: TxIdLTHash) -> Result<u32, TLCoreError> {
+        self.backends.first_ok(NAME, |p| p.load_tx_utime(address.clone(), tx_id.clone())).await
+    }
+
+    async fn load_mc_block_info(&self, mc_seqno: Option<u32>) -> Result<McBlockInfo, TLCoreError> {
+        self.backends.first_ok(NAME, |p| p.load_mc_block_info(mc_seqno)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contracts::client::fixture_provider::FixtureProvider;
+
+    #[tokio::test]
+    async fn test_failover_provider() -> anyhow::Result<()> {
+        let provider = FailoverProvider::new()
+            .with_provider("empty", FixtureProvider::new())
+            .with_provider("second", FixtureProvider::new().with_bc_config(1, vec![1]))
+            .with_provider("third", FixtureProvider::new().with_bc_config(2, vec![2]));
+
+        assert_eq!(provider.load_bc_config(None).await?, vec![1]);
+        assert_eq!(provider.load_key_block_seqno(Some(5)).await?, 1);
+        assert!(provider.load_state(TonAddress::ZERO, None).await.is_err());
+        let (config, source) = provider.request_with_source(|p| p.load_bc_config(None)).await?;
+        assert_eq!((config, source.as_str()), (vec![1], "second"));
+
+        let stats = provider.answers_stats();
+        assert_eq!(stats["empty"], 0);
+        assert_eq!(stats["second"], 3);
+        assert_eq!(stats["third"], 0);
+
+        assert!(FailoverProvider::new().last_mc_seqno().await.is_err());
+        Ok(())
+    }
+}
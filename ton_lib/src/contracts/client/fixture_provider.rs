@@ -0,0 +1,502 @@
+use crate::error::TLError;
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use ton_lib_core::cell::{TonCell, TonHash};
+use ton_lib_core::error::TLCoreError;
+use ton_lib_core::traits::contract_provider::{ContractProvider, ContractState, McBlockInfo};
+use ton_lib_core::traits::tlb::TLB;
+use ton_lib_core::types::{TonAddress, TxIdLTHash};
+
+const BC_CONFIG_PREFIX: &str = "bc_config_key_block_";
+
+/// Serves predefined data, so ContractClient & contracts can be tested offline
+///
+/// Directory layout for `from_dir` (all bocs are hex-encoded):
+/// - `bc_config_key_block_<seqno>.hex` - blockchain config valid since key block `seqno`, at least one is required
+/// - `libs/*.hex` - library cells
+/// - `states/*.json` - account states, see `FixtureState`
+/// - `latest_txs.json` - `{"<mc_seqno>": {"<address>": {"lt": <lt>, "hash": "<hex>"}}}`, optional
+/// - `mc_blocks.json` - list of `FixtureMcBlock`, optional. Without it emulation context isn't bound to blocks
+#[derive(Clone, Debug, Default)]
+pub struct FixtureProvider {
+    states: HashMap<TonAddress, BTreeMap<i64, ContractState>>, // address -> last_tx_lt -> state
+    bc_configs: BTreeMap<u32, Vec<u8>>,                        // key_block_seqno -> config boc
+    pub(super) libs: HashMap<TonHash, Vec<u8>>,                // lib_id -> lib boc
+    latest_txs: BTreeMap<u32, HashMap<TonAddress, TxIdLTHash>>, // mc_seqno -> latest tx per address
+    last_mc_seqno: Option<u32>,
+    mc_blocks: BTreeMap<u32, McBlockInfo>,
+    tx_utimes: HashMap<TxIdLTHash, u32>,
+    calls: Arc<Mutex<HashMap<&'static str, usize>>>, // method -> calls count, shared between clones
+    delay: Duration,
+}
+
+/// Account state file format
+#[derive(Serialize, Deserialize)]
+pub struct FixtureState {
+    pub address: String,
+    pub last_tx_lt: i64,
+    pub last_tx_hash: String,
+    pub balance: i64,
+    pub code: Option<String>,
+    pub data: Option<String>,
+    pub frozen_hash: Option<String>,
+    pub mc_seqno: Option<u32>,
+    pub sync_utime: Option<u32>,
+    /// unix time of the last tx, used as account last_paid in emulation
+    pub last_tx_utime: Option<u32>,
+}
+
+/// Masterchain block file format, hashes are hex-encoded
+#[derive(Serialize, Deserialize)]
+pub struct FixtureMcBlock {
+    pub seqno: u32,
+    pub root_hash: String,
+    pub file_hash: String,
+    pub gen_utime: u32,
+    pub prev_key_block_seqno: u32,
+    pub rand_seed: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct FixtureTxId {
+    pub(super) lt: i64,
+    pub(super) hash: String,
+}
+
+impl FixtureProvider {
+    pub fn new() -> Self { Self::default() }
+
+    pub fn from_dir<P: AsRef<Path>>(path: P) -> Result<Self, TLError> {
+        let path = path.as_ref();
+        let mut provider = Self::new();
+        for entry in fs::read_dir(path)? {
+            let file_path = entry?.path();
+            let Some(seqno) = file_path
+                .file_name()
+                .and_then(|x| x.to_str())
+                .and_then(|x| x.strip_prefix(BC_CONFIG_PREFIX)?.strip_suffix(".hex"))
+            else {
+                continue;
+            };
+            provider = provider.with_bc_config(seqno.parse()?, read_hex(&file_path)?);
+        }
+        if provider.bc_configs.is_empty() {
+            return Err(TLError::UnexpectedValue {
+                expected: format!("{BC_CONFIG_PREFIX}<seqno>.hex in {}", path.display()),
+                actual: "no config files".to_string(),
+            });
+        }
+
+        for lib_path in list_files(&path.join("libs"), "hex")? {
+            provider = provider.with_lib(TonCell::from_boc(&read_hex(&lib_path)?)?)?;
+        }
+        for state_path in list_files(&path.join("states"), "json")? {
+            let state: FixtureState = serde_json::from_slice(&fs::read(state_path)?)?;
+            let last_tx_utime = state.last_tx_utime;
+            let state: ContractState = state.try_into()?;
+            if let Some(utime) = last_tx_utime {
+                provider = provider.with_tx_utime(state.last_tx_id.clone(), utime);
+            }
+            provider = provider.with_state(state);
+        }
+        let latest_txs_path = path.join("latest_txs.json");
+        if latest_txs_path.exists() {
+            let latest_txs: HashMap<u32, HashMap<String, FixtureTxId>> =
+                serde_json::from_slice(&fs::read(latest_txs_path)?)?;
+            for (mc_seqno, txs) in latest_txs {
+                let mut parsed_txs = HashMap::with_capacity(txs.len());
+                for (address, tx_id) in txs {
+                    parsed_txs.insert(TonAddress::from_str(&address)?, tx_id.try_into()?);
+                }
+                provider = provider.with_latest_txs(mc_seqno, parsed_txs);
+            }
+        }
+        let mc_blocks_path = path.join("mc_blocks.json");
+        if mc_blocks_path.exists() {
+            let mc_blocks: Vec<FixtureMcBlock> = serde_json::from_slice(&fs::read(mc_blocks_path)?)?;
+            for mc_block in mc_blocks {
+                provider = provider.with_mc_block(mc_block.try_into()?);
+            }
+        }
+        Ok(provider)
+    }
+
+    /// Several states per address are allowed, the one with the highest lt is the latest
+    pub fn with_state(mut self, state: ContractState) -> Self {
+        self.states.entry(state.address.clone()).or_default().insert(state.last_tx_id.lt, state);
+        self
+    }
+
+    /// Config is used for all mc blocks starting from `key_block_seqno` until the next key block
+    pub fn with_bc_config(mut self, key_block_seqno: u32, config_boc: Vec<u8>) -> Self {
+        self.bc_configs.insert(key_block_seqno, config_boc);
+        self
+    }
+
+    pub fn with_lib(mut self, lib: TonCell) -> Result<Self, TLError> {
+        self.libs.insert(lib.hash()?.clone(), lib.to_boc()?);
+        Ok(self)
+    }
+
+    pub fn with_latest_txs(mut self, mc_seqno: u32, latest_txs: HashMap<TonAddress, TxIdLTHash>) -> Self {
+        self.latest_txs.insert(mc_seqno, latest_txs);
+        self
+    }
+
+    pub fn with_tx_utime(mut self, tx_id: TxIdLTHash, utime: u32) -> Self {
+        self.tx_utimes.insert(tx_id, utime);
+        self
+    }
+
+    /// The block with the highest seqno is the latest one
+    pub fn with_mc_block(mut self, mc_block: McBlockInfo) -> Self {
+        self.mc_blocks.insert(mc_block.seqno, mc_block);
+        self
+    }
+
+    /// If not set, the first mc_seqno from latest_txs is used, so recent tx loop goes through all of them
+    pub fn with_last_mc_seqno(mut self, mc_seqno: u32) -> Self {
+        self.last_mc_seqno = Some(mc_seqno);
+        self
+    }
+
+    /// Every response is returned after the delay, e.g. to emulate a slow backend
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Number of calls of the provider method, e.g. to check that ContractClient caches responses
+    pub fn calls(&self, method: &str) -> usize { self.calls.lock().get(method).copied().unwrap_or(0) }
+
+    async fn on_call(&self, method: &'static str) {
+        *self.calls.lock().entry(method).or_default() += 1;
+        if !self.delay.is_zero() {
+            tokio::time::sleep(self.delay).await;
+        }
+    }
+
+    fn key_block_seqno(&self, mc_seqno: Option<u32>) -> Result<u32, TLCoreError> {
+        let seqno = match mc_seqno {
+            Some(seqno) => self.bc_configs.range(..=seqno).next_back(),
+            None => self.bc_configs.iter().next_back(),
+        };
+        seqno.map(|(seqno, _)| *seqno).ok_or_else(|| not_found(format!("key block for mc_seqno {mc_seqno:?}")))
+    }
+}
+
+#[async_trait]
+impl ContractProvider for FixtureProvider {
+    async fn last_mc_seqno(&self) -> Result<u32, TLCoreError> {
+        self.on_call("last_mc_seqno").await;
+        let latest_txs_seqno = self.latest_txs.keys().next();
+        let last_mc_seqno = self.last_mc_seqno.or(latest_txs_seqno.copied());
+        Ok(last_mc_seqno.or(self.bc_configs.keys().next_back().copied()).unwrap_or(0))
+    }
+
+    async fn load_state(&self, address: TonAddress, tx_id: Option<TxIdLTHash>) -> Result<ContractState, TLCoreError> {
+        self.on_call("load_state").await;
+        let states = self.states.get(&address);
+        let state = match &tx_id {
+            Some(id) => states.and_then(|x| x.get(&id.lt)).filter(|x| &x.last_tx_id == id),
+            None => states.and_then(|x| x.values().next_back()),
+        };
+        state.cloned().ok_or_else(|| not_found(format!("state for address {address} at tx_id {tx_id:?}")))
+    }
+
+    async fn load_bc_config(&self, mc_seqno: Option<u32>) -> Result<Vec<u8>, TLCoreError> {
+        self.on_call("load_bc_config").await;
+        let key_block_seqno = self.key_block_seqno(mc_seqno)?;
+        Ok(self.bc_configs[&key_block_seqno].clone())
+    }
+
+    async fn load_key_block_seqno(&self, mc_seqno: Option<u32>) -> Result<u32, TLCoreError> {
+        self.on_call("load_key_block_seqno").await;
+        self.key_block_seqno(mc_seqno)
+    }
+
+    async fn load_libs(
+        &self,
+        lib_ids: Vec<TonHash>,
+        _mc_seqno: Option<u32>,
+    ) -> Result<Vec<(TonHash, Vec<u8>)>, TLCoreError> {
+        self.on_call("load_libs").await;
+        Ok(lib_ids.into_iter().filter_map(|id| Some((id.clone(), self.libs.get(&id)?.clone()))).collect())
+    }
+
+    async fn load_latest_tx_per_address(&self, mc_seqno: u32) -> Result<HashMap<TonAddress, TxIdLTHash>, TLCoreError> {
+        self.on_call("load_latest_tx_per_address").await;
+        let latest_txs = self.latest_txs.get(&mc_seqno).cloned();
+        latest_txs.ok_or_else(|| not_found(format!("latest txs for mc_seqno {mc_seqno}")))
+    }
+
+    async fn load_tx_utime(&self, address: TonAddress, tx_id: TxIdLTHash) -> Result<u32, TLCoreError> {
+        self.on_call("load_tx_utime").await;
+        if self.tx_utimes.is_empty() {
+            return Err(TLCoreError::ProviderUnsupported("FixtureProvider: no tx utimes are set".to_string()));
+        }
+        let utime = self.tx_utimes.get(&tx_id).copied();
+        utime.ok_or_else(|| not_found(format!("utime of tx {tx_id:?} of {address}")))
+    }
+
+    async fn load_mc_block_info(&self, mc_seqno: Option<u32>) -> Result<McBlockInfo, TLCoreError> {
+        self.on_call("load_mc_block_info").await;
+        if self.mc_blocks.is_empty() {
+            return Err(TLCoreError::ProviderUnsupported("FixtureProvider: no mc blocks are set".to_string()));
+        }
+        let mc_block = match mc_seqno {
+            Some(seqno) => self.mc_blocks.get(&seqno),
+            None => self.mc_blocks.values().next_back(),
+        };
+        mc_block.cloned().ok_or_else(|| not_found(format!("mc block {mc_seqno:?}")))
+    }
+}
+
+impl TryFrom<FixtureState> for ContractState {
+    type Error = TLError;
+
+    fn try_from(value: FixtureState) -> Result<Self, Self::Error> {
+        Ok(ContractState {
+            mc_seqno: value.mc_seqno,
+            address: TonAddress::from_str(&value.address)?,
+            last_tx_id: TxIdLTHash::new(value.last_tx_lt, value.last_tx_hash.parse()?),
+            code_boc: value.code.map(|x| hex::decode(x.trim())).transpose()?,
+            data_boc: value.data.map(|x| hex::decode(x.trim())).transpose()?,
+            frozen_hash: value.frozen_hash.map(|x| x.parse()).transpose()?,
+            balance: value.balance,
+            sync_utime: value.sync_utime,
+        })
+    }
+}
+
+impl From<&ContractState> for FixtureState {
+    fn from(value: &ContractState) -> Self {
+        FixtureState {
+            address: value.address.to_hex(),
+            last_tx_lt: value.last_tx_id.lt,
+            last_tx_hash: value.last_tx_id.hash.to_hex(),
+            balance: value.balance,
+            code: value.code_boc.as_ref().map(hex::encode),
+            data: value.data_boc.as_ref().map(hex::encode),
+            frozen_hash: value.frozen_hash.as_ref().map(|x| x.to_hex()),
+            mc_seqno: value.mc_seqno,
+            sync_utime: value.sync_utime,
+            last_tx_utime: None,
+        }
+    }
+}
+
+impl TryFrom<FixtureMcBlock> for McBlockInfo {
+    type Error = TLError;
+
+    fn try_from(value: FixtureMcBlock) -> Result<Self, Self::Error> {
+        Ok(McBlockInfo {
+            seqno: value.seqno,
+            root_hash: value.root_hash.parse()?,
+            file_hash: value.file_hash.parse()?,
+            gen_utime: value.gen_utime,
+            prev_key_block_seqno: value.prev_key_block_seqno,
+            rand_seed: value.rand_seed.map(|x| x.parse()).transpose()?,
+        })
+    }
+}
+
+impl From<&McBlockInfo> for FixtureMcBlock {
+    fn from(value: &McBlockInfo) -> Self {
+        FixtureMcBlock {
+            seqno: value.seqno,
+            root_hash: value.root_hash.to_hex(),
+            file_hash: value.file_hash.to_hex(),
+            gen_utime: value.gen_utime,
+            prev_key_block_seqno: value.prev_key_block_seqno,
+            rand_seed: value.rand_seed.as_ref().map(|x| x.to_hex()),
+        }
+    }
+}
+
+impl From<&TxIdLTHash> for FixtureTxId {
+    fn from(value: &TxIdLTHash) -> Self {
+        FixtureTxId {
+            lt: value.lt,
+            hash: value.hash.to_hex(),
+        }
+    }
+}
+
+impl TryFrom<FixtureTxId> for TxIdLTHash {
+    type Error = TLError;
+
+    fn try_from(value: FixtureTxId) -> Result<Self, Self::Error> { Ok(TxIdLTHash::new(value.lt, value.hash.parse()?)) }
+}
+
+fn not_found(what: String) -> TLCoreError { TLCoreError::Custom(format!("FixtureProvider: {what} is not found")) }
+
+fn read_hex(path: &Path) -> Result<Vec<u8>, TLError> { Ok(hex::decode(fs::read_to_string(path)?.trim())?) }
+
+// sorted to keep loading order deterministic
+fn list_files(dir: &Path, extension: &str) -> Result<Vec<std::path::PathBuf>, TLError> {
+    if !dir.exists() {
+        return Ok(vec![]);
+    }
+    let mut files = vec![];
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|x| x == extension) {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contracts::client::contract_client::{ContractClient, ContractClientConfig};
+    use crate::contracts::ton_contract::TonContract;
+    use crate::contracts::ton_wallet::TonWalletContract;
+    use crate::wallet::WalletVersion;
+    use std::time::Duration;
+
+    const BC_CONFIG_HEX: &str = include_str!("../../../resources/tests/bc_config_key_block_42123611.hex");
+
+    fn make_state(address: &TonAddress, lt: i64) -> ContractState {
+        ContractState {
+            mc_seqno: None,
+            address: address.clone(),
+            last_tx_id: TxIdLTHash::new(lt, TonHash::from([lt as u8; 32])),
+            code_boc: None,
+            data_boc: None,
+            frozen_hash: None,
+            balance: lt * 100,
+            sync_utime: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fixture_provider() -> anyhow::Result<()> {
+        let address = TonAddress::new(0, TonHash::from([1u8; 32]));
+        let provider = FixtureProvider::new()
+            .with_state(make_state(&address, 2))
+            .with_state(make_state(&address, 1))
+            .with_bc_config(10, vec![10])
+            .with_bc_config(20, vec![20])
+            .with_latest_txs(15, HashMap::from([(address.clone(), make_state(&address, 2).last_tx_id)]));
+
+        assert_eq!(provider.last_mc_seqno().await?, 15);
+        assert_eq!(provider.load_state(address.clone(), None).await?, make_state(&address, 2));
+        let tx_id = make_state(&address, 1).last_tx_id;
+        assert_eq!(provider.load_state(address.clone(), Some(tx_id.clone())).await?, make_state(&address, 1));
+        let wrong_tx_id = TxIdLTHash::new(1, TonHash::ZERO);
+        assert!(provider.load_state(address.clone(), Some(wrong_tx_id.clone())).await.is_err());
+        assert!(provider.load_state(TonAddress::ZERO, None).await.is_err());
+
+        assert_eq!(provider.load_key_block_seqno(Some(15)).await?, 10);
+        assert_eq!(provider.load_key_block_seqno(Some(20)).await?, 20);
+        assert_eq!(provider.load_key_block_seqno(None).await?, 20);
+        assert!(provider.load_key_block_seqno(Some(5)).await.is_err());
+        assert_eq!(provider.load_bc_config(Some(19)).await?, vec![10]);
+        assert_eq!(provider.load_bc_config(None).await?, vec![20]);
+        assert_eq!((provider.calls("load_key_block_seqno"), provider.calls("load_bc_config")), (4, 2));
+
+        assert_eq!(provider.load_latest_tx_per_address(15).await?.len(), 1);
+        assert!(provider.load_latest_tx_per_address(16).await.is_err());
+
+        let unsupported = provider.load_mc_block_info(None).await;
+        assert!(matches!(unsupported, Err(TLCoreError::ProviderUnsupported(_))));
+        let mc_block = |seqno: u32| McBlockInfo {
+            seqno,
+            root_hash: TonHash::from([seqno as u8; 32]),
+            file_hash: TonHash::ZERO,
+            gen_utime: seqno * 10,
+            prev_key_block_seqno: 10,
+            rand_seed: None,
+        };
+        let provider = provider.with_mc_block(mc_block(15)).with_mc_block(mc_block(14));
+
+        assert!(matches!(
+            provider.load_tx_utime(address.clone(), tx_id.clone()).await,
+            Err(TLCoreError::ProviderUnsupported(_))
+        ));
+        let provider = provider.with_tx_utime(tx_id.clone(), 100);
+        assert_eq!(provider.load_tx_utime(address.clone(), tx_id).await?, 100);
+        assert!(provider.load_tx_utime(address.clone(), wrong_tx_id).await.is_err());
+        assert_eq!(provider.load_mc_block_info(None).await?, mc_block(15));
+        assert_eq!(provider.load_mc_block_info(Some(14)).await?, mc_block(14));
+        assert!(provider.load_mc_block_info(Some(16)).await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_fixture_provider_from_dir() -> anyhow::Result<()> {
+        let dir = std::env::temp_dir().join(format!("ton_lib_fixture_{}", std::process::id()));
+        fs::create_dir_all(dir.join("states"))?;
+        fs::create_dir_all(dir.join("libs"))?;
+        let mut builder = TonCell::builder();
+        builder.write_num(&0xAu8, 4)?;
+        let lib = builder.build()?;
+        fs::write(dir.join("libs/lib.hex"), lib.to_boc_hex()?)?;
+        fs::write(dir.join("bc_config_key_block_10.hex"), "aabb\n")?;
+        let address = "0:0101010101010101010101010101010101010101010101010101010101010101";
+        let state_json = format!(
+            r#"{{"address": "{address}", "last_tx_lt": 5, "last_tx_hash": "{}", "balance": 7, "data": "b5ee", "last_tx_utime": 90}}"#,
+            TonHash::from([5u8; 32]).to_hex()
+        );
+        fs::write(dir.join("states/wallet.json"), state_json)?;
+        let latest_txs_json = format!(r#"{{"11": {{"{address}": {{"lt": 5, "hash": "{}"}}}}}}"#, "05".repeat(32));
+        fs::write(dir.join("latest_txs.json"), latest_txs_json)?;
+        let mc_blocks_json = format!(
+            r#"[{{"seqno": 11, "root_hash": "{0}", "file_hash": "{0}", "gen_utime": 100, "prev_key_block_seqno": 10}}]"#,
+            "0b".repeat(32)
+        );
+        fs::write(dir.join("mc_blocks.json"), mc_blocks_json)?;
+
+        let provider = FixtureProvider::from_dir(&dir);
+        fs::remove_dir_all(&dir)?;
+        let provider = provider?;
+
+        let address = TonAddress::from_str(address)?;
+        let state = provider.load_state(address.clone(), None).await?;
+        assert_eq!(state.last_tx_id, TxIdLTHash::new(5, TonHash::from([5u8; 32])));
+        assert_eq!(state.balance, 7);
+        assert_eq!(state.code_boc, None);
+        assert_eq!(state.data_boc, Some(vec![0xb5, 0xee]));
+        assert_eq!(provider.load_bc_config(Some(11)).await?, vec![0xaa, 0xbb]);
+        assert_eq!(provider.load_libs(vec![lib.hash()?.clone()], None).await?.len(), 1);
+        assert_eq!(provider.last_mc_seqno().await?, 11);
+        assert_eq!(provider.load_latest_tx_per_address(11).await?[&address], state.last_tx_id);
+        assert_eq!(provider.load_tx_utime(address.clone(), state.last_tx_id.clone()).await?, 90);
+        let mc_block = provider.load_mc_block_info(None).await?;
+        assert_eq!((mc_block.seqno, mc_block.gen_utime, mc_block.rand_seed), (11, 100, None));
+        assert_eq!(mc_block.root_hash, TonHash::from([11u8; 32]));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_fixture_provider_wallet_contract() -> anyhow::Result<()> {
+        let public_key = TonHash::from([3u8; 32]);
+        let address = TonAddress::new(0, TonHash::from([2u8; 32]));
+        let code = WalletVersion::get_code(WalletVersion::V4R2)?;
+        let data = WalletVersion::get_default_data_for_pubkey(WalletVersion::V4R2, public_key.clone(), 698983191)?;
+        let state = ContractState {
+            code_boc: Some(code.to_boc()?),
+            data_boc: Some(data.to_boc()?),
+            ..make_state(&address, 1)
+        };
+        let provider = FixtureProvider::new().with_state(state).with_bc_config(0, hex::decode(BC_CONFIG_HEX.trim())?);
+
+        let config = ContractClientConfig::new_no_cache(Duration::from_millis(100));
+        let ctr_cli = ContractClient::new(config, provider)?;
+        let contract = TonWalletContract::new(&ctr_cli, address, None).await?;
+        assert_eq!(contract.seqno().await?, 0);
+        assert_eq!(contract.get_public_key().await?, public_key);
+        Ok(())
+    }
+}
@@ -0,0 +1,165 @@
+use crate::contracts::client::provider_backends::{no_backends, ProviderBackends};
+use async_trait::async_trait;
+use futures_util::future::BoxFuture;
+use futures_util::stream::FuturesUnordered;
+use futures_util::StreamExt;
+use std::collections::HashMap;
+use std::time::Duration;
+use ton_lib_core::cell::TonHash;
+use ton_lib_core::error::TLCoreError;
+use ton_lib_core::traits::contract_provider::{ContractProvider, ContractState, McBlockInfo};
+use ton_lib_core::types::{TonAddress, TxIdLTHash};
+
+const NAME: &str = "HedgedProvider";
+
+/// Sends request to the next provider if the previous ones didn't answer within `delay` (or failed)
+///
+/// Returns the first successful result, slower requests are dropped
+pub struct HedgedProvider {
+    backends: ProviderBackends,
+    delay: Duration,
+}
+
+impl HedgedProvider {
+    pub fn new(delay: Duration) -> Self {
+        Self {
+            backends: ProviderBackends::default(),
+            delay,
+        }
+    }
+
+    pub fn with_provider(mut self, name: &str, provider: impl ContractProvider) -> Self {
+        self.backends.push(name, provider);
+        self
+    }
+
+    /// provider name -> number of answers it returned
+    pub fn answers_stats(&self) -> HashMap<String, usize> { self.backends.answers_stats() }
+
+    /// Runs request like trait methods do, returns the result with name of the provider answered it
+    pub async fn request_with_source<T, F>(&self, request: F) -> Result<(T, String), TLCoreError>
+    where
+        F: for<'a> Fn(&'a dyn ContractProvider) -> BoxFuture<'a, Result<T, TLCoreError>>,
+    {
+        let (res, pos) = self.hedged_with_pos(request).await?;
+        Ok((res, self.backends.get(pos).name.clone()))
+    }
+
+    async fn hedged<T, F>(&self, request: F) -> Result<T, TLCoreError>
+    where
+        F: for<'a> Fn(&'a dyn ContractProvider) -> BoxFuture<'a, Result<T, TLCoreError>>,
+    {
+        Ok(self.hedged_with_pos(request).await?.0)
+    }
+
+    async fn hedged_with_pos<T, F>(&self, request: F) -> Result<(T, usize), TLCoreError>
+    where
+        F: for<'a> Fn(&'a dyn ContractProvider) -> BoxFuture<'a, Result<T, TLCoreError>>,
+    {
+        let start = |pos: usize| {
+            let provider = self.backends.get(pos).provider.as_ref();
+            let request = &request;
+            async move { (pos, request(provider).await) }
+        };
+        let mut running = FuturesUnordered::new();
+        let mut next_pos = 0;
+        let mut last_err = None;
+        loop {
+            if running.is_empty() && next_pos < self.backends.len() {
+                running.push(start(next_pos));
+                next_pos += 1;
+            }
+            let response = match next_pos < self.backends.len() {
+                true => match tokio::time::timeout(self.delay, running.next()).await {
+                    Ok(response) => response,
+                    Err(_) => {
+                        log::debug!("[{NAME}] no answer in {:?}, sending request to the next provider", self.delay);
+                        running.push(start(next_pos));
+                        next_pos += 1;
+                        continue;
+                    }
+                },
+                false => running.next().await,
+            };
+            match response {
+                Some((pos, Ok(res))) => {
+                    self.backends.mark_answered(NAME, pos);
+                    return Ok((res, pos));
+                }
+                Some((pos, Err(err))) => {
+                    log::warn!("[{NAME}] backend {} failed: {err}", self.backends.get(pos).name);
+                    last_err = Some(err);
+                }
+                None => return Err(last_err.unwrap_or_else(|| no_backends(NAME))),
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl ContractProvider for HedgedProvider {
+    async fn last_mc_seqno(&self) -> Result<u32, TLCoreError> { self.hedged(|p| p.last_mc_seqno()).await }
+
+    async fn load_state(&self, address: TonAddress, tx_id: Option<TxIdLTHash>) -> Result<ContractState, TLCoreError> {
+        self.hedged(|p| p.load_state(address.clone(), tx_id.clone())).await
+    }
+
+    async fn load_bc_config(&self, mc_seqno: Option<u32>) -> Result<Vec<u8>, TLCoreError> {
+        self.hedged(|p| p.load_bc_config(mc_seqno)).await
+    }
+
+    async fn load_key_block_seqno(&self, mc_seqno: Option<u32>) -> Result<u32, TLCoreError> {
+        self.hedged(|p| p.load_key_block_seqno(mc_seqno)).await
+    }
+
+    async fn load_libs(
+        &self,
+        lib_ids: Vec<TonHash>,
+        mc_seqno: Option<u32>,
+    ) -> Result<Vec<(TonHash, Vec<u8>)>, TLCoreError> {
+        self.hedged(|p| p.load_libs(lib_ids.clone(), mc_seqno)).await
+    }
+
+    async fn load_latest_tx_per_address(&self, mc_seqno: u32) -> Result<HashMap<TonAddress, TxIdLTHash>, TLCoreError> {
+        self.hedged(|p| p.load_latest_tx_per_address(mc_seqno)).await
+    }
+
+    async fn load_tx_utime(&self, address: TonAddress, tx_id: TxIdLTHash) -> Result<u32, TLCoreError> {
+        self.hedged(|p| p.load_tx_utime(address.clone(), tx_id.clone())).await
+    }
+
+    async fn load_mc_block_info(&self, mc_seqno: Option<u32>) -> Result<McBlockInfo, TLCoreError> {
+        self.hedged(|p| p.load_mc_block_info(mc_seqno)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contracts::client::fixture_provider::FixtureProvider;
+
+    #[tokio::test]
+    async fn test_hedged_provider() -> anyhow::Result<()> {
+        let slow = FixtureProvider::new().with_last_mc_seqno(1).with_delay(Duration::from_secs(10));
+        let fast = FixtureProvider::new().with_last_mc_seqno(2);
+        let provider = HedgedProvider::new(Duration::from_millis(10))
+            .with_provider("slow", slow)
+            .with_provider("fast", fast)
+            .with_provider("unused", FixtureProvider::new().with_last_mc_seqno(3));
+        assert_eq!(tokio::time::timeout(Duration::from_secs(1), provider.last_mc_seqno()).await??, 2);
+
+        let stats = provider.answers_stats();
+        assert_eq!((stats["slow"], stats["fast"], stats["unused"]), (0, 1, 0));
+
+        // failed provider is replaced without waiting for delay
+        let provider = HedgedProvider::new(Duration::from_secs(10))
+            .with_provider("failed", FixtureProvider::new())
+            .with_provider("second", FixtureProvider::new().with_bc_config(5, vec![]));
+        assert_eq!(tokio::time::timeout(Duration::from_secs(1), provider.load_key_block_seqno(None)).await??, 5);
+        let (seqno, source) = provider.request_with_source(|p| p.load_key_block_seqno(None)).await?;
+        assert_eq!((seqno, source.as_str()), (5, "second"));
+        assert!(provider.load_key_block_seqno(Some(1)).await.is_err());
+        assert!(HedgedProvider::new(Duration::ZERO).last_mc_seqno().await.is_err());
+        Ok(())
+    }
+}
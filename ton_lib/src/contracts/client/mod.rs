@@ -1,5 +1,13 @@
 mod cache_stats;
 pub mod contract_client;
 pub mod contract_client_cache;
+pub mod contract_msg_result;
+pub mod contract_subscription;
+pub mod failover_provider;
+pub mod fixture_provider;
+pub mod hedged_provider;
+mod provider_backends;
+pub mod quorum_provider;
+pub mod recording_provider;
 #[cfg(feature = "tonlibjson")]
 pub mod tl_provider;
@@ -0,0 +1,78 @@
+use futures_util::future::BoxFuture;
+use std::collections::HashMap;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering::Relaxed;
+use std::sync::Arc;
+use ton_lib_core::error::TLCoreError;
+use ton_lib_core::traits::contract_provider::ContractProvider;
+
+/// Providers used by combinators, with the number of answers returned by each of them
+#[derive(Default)]
+pub(super) struct ProviderBackends(Vec<ProviderBackend>);
+
+pub(super) struct ProviderBackend {
+    pub(super) name: String,
+    pub(super) provider: Arc<dyn ContractProvider>,
+    answers: AtomicUsize,
+}
+
+impl ProviderBackends {
+    pub(super) fn push(&mut self, name: &str, provider: impl ContractProvider) {
+        self.0.push(ProviderBackend {
+            name: name.to_string(),
+            provider: Arc::new(provider),
+            answers: AtomicUsize::new(0),
+        });
+    }
+
+    pub(super) fn get(&self, pos: usize) -> &ProviderBackend { &self.0[pos] }
+
+    pub(super) fn len(&self) -> usize { self.0.len() }
+
+    pub(super) fn mark_answered(&self, combinator: &str, pos: usize) {
+        let backend = &self.0[pos];
+        log::debug!("[{combinator}] answer is returned by backend {}", backend.name);
+        backend.answers.fetch_add(1, Relaxed);
+    }
+
+    /// backend name -> number of returned answers
+    pub(super) fn answers_stats(&self) -> HashMap<String, usize> {
+        self.0.iter().map(|x| (x.name.clone(), x.answers.load(Relaxed))).collect()
+    }
+
+    pub(super) async fn first_ok<T, F>(&self, combinator: &str, request: F) -> Result<T, TLCoreError>
+    where
+        F: for<'a> Fn(&'a dyn ContractProvider) -> BoxFuture<'a, Result<T, TLCoreError>>,
+    {
+        Ok(self.first_ok_with_source(combinator, request).await?.0)
+    }
+
+    /// Tries backends in order, returns the first successful result with backend name or the last error
+    pub(super) async fn first_ok_with_source<T, F>(
+        &self,
+        combinator: &str,
+        request: F,
+    ) -> Result<(T, String), TLCoreError>
+    where
+        F: for<'a> Fn(&'a dyn ContractProvider) -> BoxFuture<'a, Result<T, TLCoreError>>,
+    {
+        let mut last_err = None;
+        for (pos, backend) in self.0.iter().enumerate() {
+            match request(backend.provider.as_ref()).await {
+                Ok(res) => {
+                    self.mark_answered(combinator, pos);
+                    return Ok((res, backend.name.clone()));
+                }
+                Err(err) => {
+                    log::warn!("[{combinator}] backend {} failed: {err}", backend.name);
+                    last_err = Some(err);
+                }
+            }
+        }
+        Err(last_err.unwrap_or_else(|| no_backends(combinator)))
+    }
+}
+
+pub(super) fn no_backends(combinator: &str) -> TLCoreError {
+    TLCoreError::Custom(format!("{combinator}: no providers configured"))
+}
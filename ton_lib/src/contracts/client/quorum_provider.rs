@@ -0,0 +1,231 @@
+use crate::contracts::client::provider_backends::{no_backends, ProviderBackends};
+use async_trait::async_trait;
+use futures_util::future::BoxFuture;
+use futures_util::stream::FuturesUnordered;
+use futures_util::StreamExt;
+use std::collections::HashMap;
+use ton_lib_core::cell::{TonCell, TonHash};
+use ton_lib_core::error::TLCoreError;
+use ton_lib_core::traits::contract_provider::{ContractProvider, ContractState, McBlockInfo};
+use ton_lib_core::traits::tlb::TLB;
+use ton_lib_core::types::{TonAddress, TxIdLTHash};
+
+const NAME: &str = "QuorumProvider";
+
+/// Requests state from providers and returns it only if the majority agree on last_tx_id, code & data hashes
+///
+/// Other requests are passed to providers one by one, like FailoverProvider does
+#[derive(Default)]
+pub struct QuorumProvider {
+    backends: ProviderBackends,
+}
+
+#[derive(PartialEq)]
+struct StateKey {
+    last_tx_id: TxIdLTHash,
+    code_hash: Option<TonHash>,
+    data_hash: Option<TonHash>,
+}
+
+struct StateVotes {
+    key: StateKey,
+    state: ContractState,
+    backends: Vec<usize>,
+}
+
+impl QuorumProvider {
+    pub fn new() -> Self { Self::default() }
+
+    pub fn with_provider(mut self, name: &str, provider: impl ContractProvider) -> Self {
+        self.backends.push(name, provider);
+        self
+    }
+
+    /// provider name -> number of answers it returned (for states - number of times it was in the majority)
+    pub fn answers_stats(&self) -> HashMap<String, usize> { self.backends.answers_stats() }
+
+    /// Runs non-state request like trait methods do, returns the result with name of the provider answered it
+    pub async fn request_with_source<T, F>(&self, request: F) -> Result<(T, String), TLCoreError>
+    where
+        F: for<'a> Fn(&'a dyn ContractProvider) -> BoxFuture<'a, Result<T, TLCoreError>>,
+    {
+        self.backends.first_ok_with_source(NAME, request).await
+    }
+
+    /// Returns the state with names of the providers in the majority
+    ///
+    /// Only as many providers as needed for the majority are queried,
+    /// the next ones are used if some of them fail or disagree
+    pub async fn load_state_with_source(
+        &self,
+        address: TonAddress,
+        tx_id: Option<TxIdLTHash>,
+    ) -> Result<(ContractState, Vec<String>), TLCoreError> {
+        let backends_count = self.backends.len();
+        if backends_count == 0 {
+            return Err(no_backends(NAME));
+        }
+        let majority = backends_count / 2 + 1;
+
+        let start = |pos: usize| {
+            let provider = self.backends.get(pos).provider.clone();
+            let (address, tx_id) = (address.clone(), tx_id.clone());
+            async move { (pos, provider.load_state(address, tx_id).await) }
+        };
+        let mut running = FuturesUnordered::new();
+        let mut next_pos = 0;
+        let mut votes: Vec<StateVotes> = vec![];
+        loop {
+            let max_votes = votes.iter().map(|x| x.backends.len()).max().unwrap_or(0);
+            while running.len() < majority - max_votes && next_pos < backends_count {
+                running.push(start(next_pos));
+                next_pos += 1;
+            }
+            let Some((pos, response)) = running.next().await else {
+                return Err(TLCoreError::Custom(format!(
+                    "{NAME}: no majority for state of {address} at tx_id {tx_id:?}: max {max_votes} of {backends_count} providers agree"
+                )));
+            };
+            let (key, state) = match response.and_then(|state| Ok((state_key(&state)?, state))) {
+                Ok(res) => res,
+                Err(err) => {
+                    log::warn!("[{NAME}] backend {} failed: {err}", self.backends.get(pos).name);
+                    continue;
+                }
+            };
+            let pos_in_votes = match votes.iter().position(|x| x.key == key) {
+                Some(vote_pos) => vote_pos,
+                None => {
+                    votes.push(StateVotes {
+                        key,
+                        state,
+                        backends: vec![],
+                    });
+                    votes.len() - 1
+                }
+            };
+            let vote = &mut votes[pos_in_votes];
+            vote.backends.push(pos);
+            if vote.backends.len() >= majority {
+                vote.backends.iter().for_each(|x| self.backends.mark_answered(NAME, *x));
+                let sources = vote.backends.iter().map(|x| self.backends.get(*x).name.clone()).collect();
+                return Ok((vote.state.clone(), sources));
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl ContractProvider for QuorumProvider {
+    async fn last_mc_seqno(&self) -> Result<u32, TLCoreError> {
+        self.backends.first_ok(NAME, |p| p.last_mc_seqno()).await
+    }
+
+    async fn load_state(&self, address: TonAddress, tx_id: Option<TxIdLTHash>) -> Result<ContractState, TLCoreError> {
+        Ok(self.load_state_with_source(address, tx_id).await?.0)
+    }
+
+    async fn load_bc_config(&self, mc_seqno: Option<u32>) -> Result<Vec<u8>, TLCoreError> {
+        self.backends.first_ok(NAME, |p| p.load_bc_config(mc_seqno)).await
+    }
+
+    async fn load_key_block_seqno(&self, mc_seqno: Option<u32>) -> Result<u32, TLCoreError> {
+        self.backends.first_ok(NAME, |p| p.load_key_block_seqno(mc_seqno)).await
+    }
+
+    async fn load_libs(
+        &self,
+        lib_ids: Vec<TonHash>,
+        mc_seqno: Option<u32>,
+    ) -> Result<Vec<(TonHash, Vec<u8>)>, TLCoreError> {
+        self.backends.first_ok(NAME, |p| p.load_libs(lib_ids.clone(), mc_seqno)).await
+    }
+
+    async fn load_latest_tx_per_address(&self, mc_seqno: u32) -> Result<HashMap<TonAddress, TxIdLTHash>, TLCoreError> {
+        self.backends.first_ok(NAME, |p| p.load_latest_tx_per_address(mc_seqno)).await
+    }
+
+    async fn load_tx_utime(&self, address: TonAddress, tx_id: TxIdLTHash) -> Result<u32, TLCoreError> {
+        self.backends.first_ok(NAME, |p| p.load_tx_utime(address.clone(), tx_id.clone())).await
+    }
+
+    async fn load_mc_block_info(&self, mc_seqno: Option<u32>) -> Result<McBlockInfo, TLCoreError> {
+        self.backends.first_ok(NAME, |p| p.load_mc_block_info(mc_seqno)).await
+    }
+}
+
+// boc serialization may differ between providers, so cell hashes are compared
+fn state_key(state: &ContractState) -> Result<StateKey, TLCoreError> {
+    let cell_hash = |boc: &Option<Vec<u8>>| match boc {
+        Some(boc) => Ok::<_, TLCoreError>(Some(TonCell::from_boc(boc)?.hash()?.clone())),
+        None => Ok(None),
+    };
+    Ok(StateKey {
+        last_tx_id: state.last_tx_id.clone(),
+        code_hash: cell_hash(&state.code_boc)?,
+        data_hash: cell_hash(&state.data_boc)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contracts::client::fixture_provider::FixtureProvider;
+    use std::time::Duration;
+
+    fn make_state(data: u8, lt: i64) -> anyhow::Result<ContractState> {
+        let mut builder = TonCell::builder();
+        builder.write_num(&data, 8)?;
+        Ok(ContractState {
+            mc_seqno: None,
+            address: TonAddress::ZERO,
+            last_tx_id: TxIdLTHash::new(lt, TonHash::ZERO),
+            code_boc: None,
+            data_boc: Some(builder.build()?.to_boc()?),
+            frozen_hash: None,
+            balance: 0,
+            sync_utime: None,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_quorum_provider() -> anyhow::Result<()> {
+        let provider = QuorumProvider::new()
+            .with_provider("first", FixtureProvider::new().with_state(make_state(1, 1)?))
+            .with_provider("second", FixtureProvider::new().with_state(make_state(2, 1)?))
+            .with_provider("third", FixtureProvider::new().with_state(make_state(2, 1)?));
+        let (state, sources) = provider.load_state_with_source(TonAddress::ZERO, None).await?;
+        assert_eq!(state, make_state(2, 1)?);
+        assert_eq!(sources, vec!["second".to_string(), "third".to_string()]);
+        let stats = provider.answers_stats();
+        assert_eq!((stats["first"], stats["second"], stats["third"]), (0, 1, 1));
+
+        // majority of agreeing answers is enough, the slow provider isn't waited for
+        let slow = FixtureProvider::new().with_state(make_state(1, 1)?).with_delay(Duration::from_secs(10));
+        let provider = QuorumProvider::new()
+            .with_provider("first", FixtureProvider::new().with_state(make_state(1, 1)?))
+            .with_provider("second", FixtureProvider::new().with_state(make_state(1, 1)?))
+            .with_provider("slow", slow);
+        let load_state = provider.load_state(TonAddress::ZERO, None);
+        assert_eq!(tokio::time::timeout(Duration::from_secs(1), load_state).await??, make_state(1, 1)?);
+
+        // next provider is queried if one of the first ones fails
+        let provider = QuorumProvider::new()
+            .with_provider("first", FixtureProvider::new())
+            .with_provider("second", FixtureProvider::new().with_state(make_state(2, 1)?))
+            .with_provider("third", FixtureProvider::new().with_state(make_state(2, 1)?));
+        let (_, sources) = provider.load_state_with_source(TonAddress::ZERO, None).await?;
+        assert_eq!(sources, vec!["second".to_string(), "third".to_string()]);
+        let (seqno, source) = provider.request_with_source(|p| p.last_mc_seqno()).await?;
+        assert_eq!((seqno, source.as_str()), (0, "first"));
+
+        // 1 of 3 providers fails, the others disagree on lt
+        let provider = QuorumProvider::new()
+            .with_provider("first", FixtureProvider::new())
+            .with_provider("second", FixtureProvider::new().with_state(make_state(2, 1)?))
+            .with_provider("third", FixtureProvider::new().with_state(make_state(2, 2)?));
+        assert!(provider.load_state(TonAddress::ZERO, None).await.is_err());
+        assert!(QuorumProvider::new().load_state(TonAddress::ZERO, None).await.is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,346 @@
+use crate::contracts::client::fixture_provider::{FixtureMcBlock, FixtureState, FixtureTxId};
+use crate::error::TLError;
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+use std::str::FromStr;
+use ton_lib_core::cell::TonHash;
+use ton_lib_core::error::TLCoreError;
+use ton_lib_core::traits::contract_provider::{ContractProvider, ContractState, McBlockInfo};
+use ton_lib_core::types::{TonAddress, TxIdLTHash};
+
+/// Single provider call with its result, stored as a json line. Bocs & hashes are hex-encoded
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+enum ProviderRecord {
+    LastMcSeqno {
+        seqno: u32,
+    },
+    LoadState {
+        address: String,
+        tx_id: Option<FixtureTxId>,
+        state: FixtureState,
+    },
+    LoadBcConfig {
+        mc_seqno: Option<u32>,
+        config: String,
+    },
+    LoadKeyBlockSeqno {
+        mc_seqno: Option<u32>,
+        seqno: u32,
+    },
+    LoadLibs {
+        mc_seqno: Option<u32>,
+        libs: Vec<(String, String)>,
+    },
+    LoadLatestTxPerAddress {
+        mc_seqno: u32,
+        txs: HashMap<String, FixtureTxId>,
+    },
+    LoadMcBlockInfo {
+        mc_seqno: Option<u32>,
+        block: FixtureMcBlock,
+    },
+    LoadTxUtime {
+        address: String,
+        tx_id: FixtureTxId,
+        utime: u32,
+    },
+}
+
+/// Passes all calls to the inner provider and appends successful results to the file
+///
+/// Use ReplayProvider to serve them back, e.g. to run integration tests offline
+pub struct RecordingProvider<P: ContractProvider> {
+    provider: P,
+    file: Mutex<File>,
+}
+
+impl<P: ContractProvider> RecordingProvider<P> {
+    /// Appends to the file if it exists
+    pub fn new<T: AsRef<Path>>(provider: P, path: T) -> Result<Self, TLError> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self {
+            provider,
+            file: Mutex::new(file),
+        })
+    }
+
+    fn write(&self, record: &ProviderRecord) -> Result<(), TLCoreError> {
+        let mut line = serde_json::to_vec(record).map_err(|err| TLCoreError::Custom(err.to_string()))?;
+        line.push(b'\n');
+        Ok(self.file.lock().write_all(&line)?)
+    }
+}
+
+#[async_trait]
+impl<P: ContractProvider> ContractProvider for RecordingProvider<P> {
+    async fn last_mc_seqno(&self) -> Result<u32, TLCoreError> {
+        let seqno = self.provider.last_mc_seqno().await?;
+        self.write(&ProviderRecord::LastMcSeqno { seqno })?;
+        Ok(seqno)
+    }
+
+    async fn load_state(&self, address: TonAddress, tx_id: Option<TxIdLTHash>) -> Result<ContractState, TLCoreError> {
+        let state = self.provider.load_state(address.clone(), tx_id.clone()).await?;
+        self.write(&ProviderRecord::LoadState {
+            address: address.to_hex(),
+            tx_id: tx_id.as_ref().map(FixtureTxId::from),
+            state: FixtureState::from(&state),
+        })?;
+        Ok(state)
+    }
+
+    async fn load_bc_config(&self, mc_seqno: Option<u32>) -> Result<Vec<u8>, TLCoreError> {
+        let config = self.provider.load_bc_config(mc_seqno).await?;
+        self.write(&ProviderRecord::LoadBcConfig {
+            mc_seqno,
+            config: hex::encode(&config),
+        })?;
+        Ok(config)
+    }
+
+    async fn load_key_block_seqno(&self, mc_seqno: Option<u32>) -> Result<u32, TLCoreError> {
+        let seqno = self.provider.load_key_block_seqno(mc_seqno).await?;
+        self.write(&ProviderRecord::LoadKeyBlockSeqno { mc_seqno, seqno })?;
+        Ok(seqno)
+    }
+
+    async fn load_libs(
+        &self,
+        lib_ids: Vec<TonHash>,
+        mc_seqno: Option<u32>,
+    ) -> Result<Vec<(TonHash, Vec<u8>)>, TLCoreError> {
+        let libs = self.provider.load_libs(lib_ids, mc_seqno).await?;
+        self.write(&ProviderRecord::LoadLibs {
+            mc_seqno,
+            libs: libs.iter().map(|(id, boc)| (id.to_hex(), hex::encode(boc))).collect(),
+        })?;
+        Ok(libs)
+    }
+
+    async fn load_latest_tx_per_address(&self, mc_seqno: u32) -> Result<HashMap<TonAddress, TxIdLTHash>, TLCoreError> {
+        let txs = self.provider.load_latest_tx_per_address(mc_seqno).await?;
+        self.write(&ProviderRecord::LoadLatestTxPerAddress {
+            mc_seqno,
+            txs: txs.iter().map(|(address, tx_id)| (address.to_hex(), tx_id.into())).collect(),
+        })?;
+        Ok(txs)
+    }
+
+    async fn load_tx_utime(&self, address: TonAddress, tx_id: TxIdLTHash) -> Result<u32, TLCoreError> {
+        let utime = self.provider.load_tx_utime(address.clone(), tx_id.clone()).await?;
+        self.write(&ProviderRecord::LoadTxUtime {
+            address: address.to_hex(),
+            tx_id: FixtureTxId::from(&tx_id),
+            utime,
+        })?;
+        Ok(utime)
+    }
+
+    async fn load_mc_block_info(&self, mc_seqno: Option<u32>) -> Result<McBlockInfo, TLCoreError> {
+        let block = self.provider.load_mc_block_info(mc_seqno).await?;
+        self.write(&ProviderRecord::LoadMcBlockInfo {
+            mc_seqno,
+            block: FixtureMcBlock::from(&block),
+        })?;
+        Ok(block)
+    }
+}
+
+/// Serves results recorded by RecordingProvider, fails on requests which were not recorded
+///
+/// If the same request was recorded several times, the first result is used.
+/// last_mc_seqno returns the first recorded value, so recent tx loop replays recorded blocks in order.
+#[derive(Default)]
+pub struct ReplayProvider {
+    last_mc_seqno: Option<u32>,
+    states: HashMap<(TonAddress, Option<TxIdLTHash>), ContractState>,
+    bc_configs: HashMap<Option<u32>, Vec<u8>>,
+    key_block_seqnos: HashMap<Option<u32>, u32>,
+    libs: HashMap<TonHash, Vec<u8>>, // libs are immutable, so mc_seqno is ignored
+    latest_txs: HashMap<u32, HashMap<TonAddress, TxIdLTHash>>,
+    mc_blocks: HashMap<Option<u32>, McBlockInfo>,
+    tx_utimes: HashMap<(TonAddress, TxIdLTHash), u32>,
+}
+
+impl ReplayProvider {
+    pub fn from_file<T: AsRef<Path>>(path: T) -> Result<Self, TLError> {
+        let mut provider = Self::default();
+        for line in BufReader::new(File::open(path)?).lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            provider.add_record(serde_json::from_str(&line)?)?;
+        }
+        Ok(provider)
+    }
+
+    fn add_record(&mut self, record: ProviderRecord) -> Result<(), TLError> {
+        match record {
+            ProviderRecord::LastMcSeqno { seqno } => {
+                self.last_mc_seqno.get_or_insert(seqno);
+            }
+            ProviderRecord::LoadState { address, tx_id, state } => {
+                let key = (TonAddress::from_str(&address)?, tx_id.map(TxIdLTHash::try_from).transpose()?);
+                self.states.entry(key).or_insert(state.try_into()?);
+            }
+            ProviderRecord::LoadBcConfig { mc_seqno, config } => {
+                self.bc_configs.entry(mc_seqno).or_insert(hex::decode(config)?);
+            }
+            ProviderRecord::LoadKeyBlockSeqno { mc_seqno, seqno } => {
+                self.key_block_seqnos.entry(mc_seqno).or_insert(seqno);
+            }
+            ProviderRecord::LoadLibs { libs, .. } => {
+                for (id, boc) in libs {
+                    self.libs.insert(TonHash::from_str(&id)?, hex::decode(boc)?);
+                }
+            }
+            ProviderRecord::LoadLatestTxPerAddress { mc_seqno, txs } => {
+                let mut parsed_txs = HashMap::with_capacity(txs.len());
+                for (address, tx_id) in txs {
+                    parsed_txs.insert(TonAddress::from_str(&address)?, tx_id.try_into()?);
+                }
+                self.latest_txs.entry(mc_seqno).or_insert(parsed_txs);
+            }
+            ProviderRecord::LoadMcBlockInfo { mc_seqno, block } => {
+                self.mc_blocks.entry(mc_seqno).or_insert(block.try_into()?);
+            }
+            ProviderRecord::LoadTxUtime { address, tx_id, utime } => {
+                self.tx_utimes.entry((TonAddress::from_str(&address)?, tx_id.try_into()?)).or_insert(utime);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ContractProvider for ReplayProvider {
+    async fn last_mc_seqno(&self) -> Result<u32, TLCoreError> {
+        self.last_mc_seqno.ok_or_else(|| not_recorded("last_mc_seqno".to_string()))
+    }
+
+    async fn load_state(&self, address: TonAddress, tx_id: Option<TxIdLTHash>) -> Result<ContractState, TLCoreError> {
+        let key = (address, tx_id);
+        let state = self.states.get(&key).cloned();
+        state.ok_or_else(|| not_recorded(format!("load_state for address {} at tx_id {:?}", key.0, key.1)))
+    }
+
+    async fn load_bc_config(&self, mc_seqno: Option<u32>) -> Result<Vec<u8>, TLCoreError> {
+        let config = self.bc_configs.get(&mc_seqno).cloned();
+        config.ok_or_else(|| not_recorded(format!("load_bc_config for mc_seqno {mc_seqno:?}")))
+    }
+
+    async fn load_key_block_seqno(&self, mc_seqno: Option<u32>) -> Result<u32, TLCoreError> {
+        let seqno = self.key_block_seqnos.get(&mc_seqno).copied();
+        seqno.ok_or_else(|| not_recorded(format!("load_key_block_seqno for mc_seqno {mc_seqno:?}")))
+    }
+
+    async fn load_libs(
+        &self,
+        lib_ids: Vec<TonHash>,
+        _mc_seqno: Option<u32>,
+    ) -> Result<Vec<(TonHash, Vec<u8>)>, TLCoreError> {
+        Ok(lib_ids.into_iter().filter_map(|id| Some((id.clone(), self.libs.get(&id)?.clone()))).collect())
+    }
+
+    async fn load_latest_tx_per_address(&self, mc_seqno: u32) -> Result<HashMap<TonAddress, TxIdLTHash>, TLCoreError> {
+        let txs = self.latest_txs.get(&mc_seqno).cloned();
+        txs.ok_or_else(|| not_recorded(format!("load_latest_tx_per_address for mc_seqno {mc_seqno}")))
+    }
+
+    // optional methods: nothing is recorded if the original provider doesn't support them
+    async fn load_tx_utime(&self, address: TonAddress, tx_id: TxIdLTHash) -> Result<u32, TLCoreError> {
+        if self.tx_utimes.is_empty() {
+            return Err(TLCoreError::ProviderUnsupported("ReplayProvider: no tx utimes are recorded".to_string()));
+        }
+        let key = (address, tx_id);
+        let utime = self.tx_utimes.get(&key).copied();
+        utime.ok_or_else(|| not_recorded(format!("load_tx_utime for address {} at tx_id {:?}", key.0, key.1)))
+    }
+
+    async fn load_mc_block_info(&self, mc_seqno: Option<u32>) -> Result<McBlockInfo, TLCoreError> {
+        if self.mc_blocks.is_empty() {
+            return Err(TLCoreError::ProviderUnsupported("ReplayProvider: no mc blocks are recorded".to_string()));
+        }
+        let block = self.mc_blocks.get(&mc_seqno).cloned();
+        block.ok_or_else(|| not_recorded(format!("load_mc_block_info for mc_seqno {mc_seqno:?}")))
+    }
+}
+
+fn not_recorded(what: String) -> TLCoreError { TLCoreError::Custom(format!("ReplayProvider: {what} is not recorded")) }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contracts::client::fixture_provider::FixtureProvider;
+
+    #[tokio::test]
+    async fn test_recording_provider_replay() -> anyhow::Result<()> {
+        let address = TonAddress::new(0, TonHash::from([1u8; 32]));
+        let tx_id = TxIdLTHash::new(5, TonHash::from([5u8; 32]));
+        let state = ContractState {
+            mc_seqno: Some(3),
+            address: address.clone(),
+            last_tx_id: tx_id.clone(),
+            code_boc: Some(vec![1, 2, 3]),
+            data_boc: None,
+            frozen_hash: None,
+            balance: 100,
+            sync_utime: Some(1000),
+        };
+        let lib_id = TonHash::from([7u8; 32]);
+        let mc_block = McBlockInfo {
+            seqno: 3,
+            root_hash: TonHash::from([3u8; 32]),
+            file_hash: TonHash::from([4u8; 32]),
+            gen_utime: 1000,
+            prev_key_block_seqno: 2,
+            rand_seed: None,
+        };
+        let fixture = FixtureProvider::new()
+            .with_state(state.clone())
+            .with_bc_config(2, vec![0xaa])
+            .with_latest_txs(3, HashMap::from([(address.clone(), tx_id.clone())]))
+            .with_mc_block(mc_block.clone())
+            .with_tx_utime(tx_id.clone(), 999);
+
+        let path = std::env::temp_dir().join(format!("ton_lib_records_{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let recorder = RecordingProvider::new(fixture, &path)?;
+        assert_eq!(recorder.last_mc_seqno().await?, 3);
+        assert_eq!(recorder.load_state(address.clone(), None).await?, state);
+        assert_eq!(recorder.load_state(address.clone(), Some(tx_id.clone())).await?, state);
+        assert!(recorder.load_state(TonAddress::ZERO, None).await.is_err());
+        assert_eq!(recorder.load_key_block_seqno(Some(3)).await?, 2);
+        assert_eq!(recorder.load_bc_config(Some(2)).await?, vec![0xaa]);
+        assert!(recorder.load_libs(vec![lib_id.clone()], None).await?.is_empty());
+        assert_eq!(recorder.load_latest_tx_per_address(3).await?.len(), 1);
+        assert_eq!(recorder.load_mc_block_info(Some(3)).await?, mc_block);
+        assert_eq!(recorder.load_tx_utime(address.clone(), tx_id.clone()).await?, 999);
+        drop(recorder);
+
+        let replay = ReplayProvider::from_file(&path);
+        std::fs::remove_file(&path)?;
+        let replay = replay?;
+        assert_eq!(replay.last_mc_seqno().await?, 3);
+        assert_eq!(replay.load_state(address.clone(), None).await?, state);
+        assert_eq!(replay.load_state(address.clone(), Some(tx_id.clone())).await?, state);
+        assert!(replay.load_state(TonAddress::ZERO, None).await.is_err());
+        assert_eq!(replay.load_key_block_seqno(Some(3)).await?, 2);
+        assert!(replay.load_key_block_seqno(None).await.is_err());
+        assert_eq!(replay.load_bc_config(Some(2)).await?, vec![0xaa]);
+        assert!(replay.load_libs(vec![lib_id], None).await?.is_empty());
+        assert_eq!(replay.load_latest_tx_per_address(3).await?, HashMap::from([(address.clone(), tx_id.clone())]));
+        assert!(replay.load_latest_tx_per_address(4).await.is_err());
+        assert_eq!(replay.load_mc_block_info(Some(3)).await?, mc_block);
+        assert!(replay.load_mc_block_info(None).await.is_err());
+        assert_eq!(replay.load_tx_utime(address.clone(), tx_id.clone()).await?, 999);
+        Ok(())
+    }
+}
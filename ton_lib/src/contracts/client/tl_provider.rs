@@ -1,6 +1,6 @@
 use crate::block_tlb::BlockIdExt;
 use crate::clients::tl_client::tl::client::TLClientTrait;
-use crate::clients::tl_client::{TLClient, TLConnection};
+use crate::clients::tl_client::TLClient;
 use crate::error::TLError;
 use async_recursion::async_recursion;
 use async_trait::async_trait;
@@ -11,39 +11,47 @@ use std::ops::Deref;
 use std::sync::Arc;
 use std::time::Duration;
 use ton_lib_core::cell::TonHash;
+use ton_lib_core::constants::TON_MASTERCHAIN;
 use ton_lib_core::error::TLCoreError;
-use ton_lib_core::traits::contract_provider::{ContractProvider, ContractState};
+use ton_lib_core::traits::contract_provider::{ContractProvider, ContractState, McBlockInfo};
 use ton_lib_core::types::{TonAddress, TxIdLTHash};
 
 static BLOCK_IDS_CACHE_SIZE: u64 = 100;
 
-pub struct TLProvider {
-    client: TLClient,
+/// ContractProvider on top of TLClient, TLRecorder or TLReplayer
+pub struct TLProvider<C: TLClientTrait = TLClient> {
+    client: C,
     mc_block_cache: Cache<u32, BlockIdExt>, // mc_seqno -> block_id
     block_shards_cache: Cache<u32, Arc<HashSet<BlockIdExt>>>, // mc_seqno -> shards, must keep it separately from unseen_cache for proper checks
     unseen_cache: Cache<u32, Arc<HashSet<BlockIdExt>>>,
+    commit_mc_seqno_cache: Cache<BlockIdExt, u32>, // shard block_id -> seqno of mc block committing it
 }
 
-impl TLProvider {
-    pub fn new(client: TLClient) -> Self {
+impl<C: TLClientTrait> TLProvider<C> {
+    pub fn new(client: C) -> Self {
         Self {
             client,
             mc_block_cache: Cache::new(BLOCK_IDS_CACHE_SIZE),
             block_shards_cache: Cache::new(BLOCK_IDS_CACHE_SIZE),
             unseen_cache: Cache::new(BLOCK_IDS_CACHE_SIZE),
+            commit_mc_seqno_cache: Cache::new(BLOCK_IDS_CACHE_SIZE),
         }
     }
 }
 
 #[async_trait]
-impl ContractProvider for TLProvider {
+impl<C: TLClientTrait + 'static> ContractProvider for TLProvider<C> {
     async fn last_mc_seqno(&self) -> Result<u32, TLCoreError> { Ok(self.client.get_mc_info().await?.last.seqno) }
 
     async fn load_state(&self, address: TonAddress, tx_id: Option<TxIdLTHash>) -> Result<ContractState, TLCoreError> {
-        let raw_state = match tx_id {
-            Some(id) => self.client.get_account_state_raw_by_tx(address.clone(), id).await,
-            None => self.client.get_account_state_raw(address.clone()).await,
-        }?;
+        let (raw_state, mc_seqno) = match tx_id {
+            Some(id) => {
+                let raw_state = self.client.get_account_state_raw_by_tx(address.clone(), id).await?;
+                let mc_seqno = self.get_mc_seqno(&raw_state.block_id).await?;
+                (raw_state, Some(mc_seqno))
+            }
+            None => (self.client.get_account_state_raw(address.clone()).await?, None),
+        };
 
         let code_boc = Some(raw_state.code).filter(|x| !x.is_empty());
         let data_boc = Some(raw_state.data).filter(|x| !x.is_empty());
@@ -52,18 +60,37 @@ impl ContractProvider for TLProvider {
             false => Some(TonHash::from_vec(raw_state.frozen_hash)?),
         };
         Ok(ContractState {
-            mc_seqno: None,
+            mc_seqno,
             address,
             last_tx_id: raw_state.last_tx_id,
             code_boc,
             data_boc,
             frozen_hash,
             balance: raw_state.balance,
+            sync_utime: Some(raw_state.sync_utime as u32),
         })
     }
 
-    async fn load_bc_config(&self, _mc_seqno: Option<u32>) -> Result<Vec<u8>, TLCoreError> {
-        Ok(self.client.get_config_boc_all(0).await?)
+    async fn load_bc_config(&self, mc_seqno: Option<u32>) -> Result<Vec<u8>, TLCoreError> {
+        match mc_seqno {
+            Some(seqno) => {
+                let block_id = self.client.lookup_mc_block(seqno).await?;
+                Ok(self.client.get_config_boc_all_by_block(0, block_id).await?)
+            }
+            None => Ok(self.client.get_config_boc_all(0).await?),
+        }
+    }
+
+    async fn load_key_block_seqno(&self, mc_seqno: Option<u32>) -> Result<u32, TLCoreError> {
+        let block_id = match mc_seqno {
+            Some(seqno) => self.client.lookup_mc_block(seqno).await?,
+            None => self.client.get_mc_info().await?.last,
+        };
+        let header = self.client.get_block_header(block_id).await?;
+        match header.is_key_block {
+            true => Ok(header.id.seqno),
+            false => Ok(header.prev_key_block_seqno as u32),
+        }
     }
 
     async fn load_libs(
@@ -119,10 +146,66 @@ impl ContractProvider for TLProvider {
         }
         Ok(latest_by_address)
     }
+
+    async fn load_tx_utime(&self, address: TonAddress, tx_id: TxIdLTHash) -> Result<u32, TLCoreError> {
+        let txs = self.client.get_account_txs_v2(address.clone(), tx_id.clone(), 1, false).await?.txs;
+        match txs.into_iter().find(|x| x.tx_id == tx_id) {
+            Some(tx) => Ok(tx.utime as u32),
+            None => Err(TLCoreError::Custom(format!("tx {tx_id:?} of {address} is not found"))),
+        }
+    }
+
+    async fn load_mc_block_info(&self, mc_seqno: Option<u32>) -> Result<McBlockInfo, TLCoreError> {
+        let block_id = match mc_seqno {
+            Some(seqno) => self.client.lookup_mc_block(seqno).await?,
+            None => self.client.get_mc_info().await?.last,
+        };
+        let header = self.client.get_block_header(block_id.clone()).await?;
+        Ok(McBlockInfo {
+            seqno: block_id.seqno,
+            root_hash: block_id.root_hash,
+            file_hash: block_id.file_hash,
+            gen_utime: header.gen_utime as u32,
+            prev_key_block_seqno: header.prev_key_block_seqno as u32,
+            rand_seed: None,
+        })
+    }
 }
 
-impl TLProvider {
-    async fn find_connection(&self, mc_seqno: u32) -> Result<&TLConnection, TLError> {
+impl<C: TLClientTrait> TLProvider<C> {
+    /// Seqno of the masterchain block the state is taken from (for shard blocks - the one committing it)
+    async fn get_mc_seqno(&self, block_id: &BlockIdExt) -> Result<u32, TLError> {
+        if block_id.shard_ident.workchain == TON_MASTERCHAIN {
+            return Ok(block_id.seqno);
+        }
+        Ok(self
+            .commit_mc_seqno_cache
+            .try_get_with_by_ref(
+                block_id,
+                async move { Ok::<_, TLCoreError>(self.find_commit_mc_seqno(block_id).await?) },
+            )
+            .await?)
+    }
+
+    /// Shard block is committed by the first mc block with the same or newer block of intersecting shard
+    /// It can't be committed before its master_ref, which is >= min_ref_mc_seqno
+    async fn find_commit_mc_seqno(&self, block_id: &BlockIdExt) -> Result<u32, TLError> {
+        let header = self.client.get_block_header(block_id.clone()).await?;
+        let mut mc_seqno = header.min_ref_mc_seqno as u32 + 1;
+        loop {
+            let conn = self.find_connection(mc_seqno).await?;
+            let mc_block = self.get_or_load_master(conn, mc_seqno).await?;
+            let shards = self.get_or_load_shards(conn, &mc_block).await?;
+            let committed =
+                shards.iter().any(|x| x.shard_ident.intersects(&block_id.shard_ident) && x.seqno >= block_id.seqno);
+            if committed {
+                return Ok(mc_seqno);
+            }
+            mc_seqno += 1;
+        }
+    }
+
+    async fn find_connection(&self, mc_seqno: u32) -> Result<&dyn TLClientTrait, TLError> {
         loop {
             let conn = self.client.get_connection();
             let mc_info = conn.get_mc_info().await?;
@@ -133,13 +216,13 @@ impl TLProvider {
         }
     }
 
-    async fn get_or_load_master(&self, conn: &TLConnection, mc_seqno: u32) -> Result<BlockIdExt, TLError> {
+    async fn get_or_load_master(&self, conn: &dyn TLClientTrait, mc_seqno: u32) -> Result<BlockIdExt, TLError> {
         Ok(self.mc_block_cache.try_get_with(mc_seqno, async move { Ok(conn.lookup_mc_block(mc_seqno).await?) }).await?)
     }
 
     async fn get_or_load_shards(
         &self,
-        conn: &TLConnection,
+        conn: &dyn TLClientTrait,
         mc_block: &BlockIdExt,
     ) -> Result<Arc<HashSet<BlockIdExt>>, TLError> {
         Ok(self
@@ -153,7 +236,7 @@ impl TLProvider {
 
     async fn get_or_load_unseen(
         &self,
-        conn: &TLConnection,
+        conn: &dyn TLClientTrait,
         mc_seqno: u32,
         prev_shards: &HashSet<BlockIdExt>,
         cur_shards: HashSet<BlockIdExt>,
@@ -170,7 +253,7 @@ impl TLProvider {
     #[async_recursion]
     async fn get_unseen_shards(
         &self,
-        conn: &TLConnection,
+        conn: &dyn TLClientTrait,
         mc_seqno: u32,
         prev_shards: &HashSet<BlockIdExt>,
         cur_shards: HashSet<BlockIdExt>,
@@ -192,7 +275,7 @@ impl TLProvider {
 
     async fn get_prev_blocks_with_retry(
         &self,
-        conn: &TLConnection,
+        conn: &dyn TLClientTrait,
         mc_seqno: u32,
         block_id: &BlockIdExt,
     ) -> Result<HashSet<BlockIdExt>, TLError> {
@@ -211,3 +294,115 @@ impl TLProvider {
         Err(last_error.unwrap())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block_tlb::ShardIdent;
+    use crate::clients::tl_client::tl::request::TLRequest;
+    use crate::clients::tl_client::tl::response::TLResponse;
+    use crate::clients::tl_client::tl::types::{TLBlockId, TLBlocksHeader, TLBlocksMCInfo, TLBlocksShards};
+    use crate::clients::tl_client::TLReplayer;
+    use ton_lib_core::constants::TON_SHARD_FULL;
+
+    fn block_id(workchain: i32, shard: u64, seqno: u32) -> BlockIdExt {
+        BlockIdExt {
+            shard_ident: ShardIdent::new(workchain, shard),
+            seqno,
+            ..BlockIdExt::ZERO_BLOCK_ID
+        }
+    }
+
+    fn make_replayer(records: Vec<(TLRequest, TLResponse)>) -> anyhow::Result<TLReplayer> {
+        let path = std::env::temp_dir().join(format!("ton_lib_tl_provider_{}.jsonl", std::process::id()));
+        let lines = records
+            .into_iter()
+            .map(|(request, response)| serde_json::json!({"request": request, "response": response}).to_string())
+            .collect::<Vec<_>>();
+        std::fs::write(&path, lines.join("\n"))?;
+        let replayer = TLReplayer::from_file(&path);
+        std::fs::remove_file(&path)?;
+        Ok(replayer?)
+    }
+
+    fn mc_block_records(seqno: u32, shards: Vec<BlockIdExt>) -> Vec<(TLRequest, TLResponse)> {
+        let mc_block_id = block_id(TON_MASTERCHAIN, TON_SHARD_FULL, seqno);
+        let lookup_req = TLRequest::BlocksLookupBlock {
+            mode: 1,
+            id: TLBlockId {
+                workchain: TON_MASTERCHAIN,
+                shard: TON_SHARD_FULL as i64,
+                seqno: seqno as i32,
+            },
+            lt: 0,
+            utime: 0,
+        };
+        vec![
+            (lookup_req, TLResponse::TLBlockIdExt(mc_block_id.clone())),
+            (TLRequest::BlocksGetShards { id: mc_block_id }, TLResponse::TLBlocksShards(TLBlocksShards { shards })),
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_tl_provider_get_mc_seqno() -> anyhow::Result<()> {
+        // header values are taken from shard block 0:6000000000000000:52111590 (master_ref: 47004578)
+        // shards are crafted: 47004579 doesn't commit it yet, 47004580 commits it together with the next block
+        let shard_block_id = block_id(0, 0x6000000000000000, 52111590);
+        let header = TLBlocksHeader {
+            id: shard_block_id.clone(),
+            global_id: -239,
+            version: 0,
+            flags: 1,
+            after_merge: false,
+            after_split: false,
+            before_split: false,
+            want_merge: true,
+            want_split: false,
+            validator_list_hash_short: 0,
+            catchain_seqno: 684420,
+            min_ref_mc_seqno: 47004578,
+            is_key_block: false,
+            prev_key_block_seqno: 46991999,
+            start_lt: 56269616000000,
+            end_lt: 56269616000011,
+            gen_utime: 1745147839,
+            vert_seqno: Some(1),
+            prev_blocks: None,
+        };
+        let mc_info = TLBlocksMCInfo {
+            last: block_id(TON_MASTERCHAIN, TON_SHARD_FULL, 47004580),
+            state_root_hash: vec![],
+            init: BlockIdExt::ZERO_BLOCK_ID,
+        };
+        let mut records = vec![
+            (
+                TLRequest::GetBlockHeader {
+                    id: shard_block_id.clone(),
+                },
+                TLResponse::TLBlocksHeader(header),
+            ),
+            (TLRequest::BlocksGetMCInfo {}, TLResponse::TLBlocksMCInfo(mc_info)),
+        ];
+        records.extend(mc_block_records(
+            47004579,
+            vec![
+                block_id(0, 0x2000000000000000, 52100000),
+                block_id(0, 0x6000000000000000, 52111589),
+            ],
+        ));
+        records.extend(mc_block_records(
+            47004580,
+            vec![
+                block_id(0, 0x2000000000000000, 52100001),
+                block_id(0, 0x6000000000000000, 52111591),
+            ],
+        ));
+        let provider = TLProvider::new(make_replayer(records)?);
+
+        assert_eq!(provider.get_mc_seqno(&shard_block_id).await?, 47004580);
+        assert_eq!(provider.commit_mc_seqno_cache.get(&shard_block_id).await, Some(47004580));
+        let mc_block_id = block_id(TON_MASTERCHAIN, TON_SHARD_FULL, 47004578);
+        assert_eq!(provider.get_mc_seqno(&mc_block_id).await?, 47004578);
+        Ok(())
+    }
+}
@@ -1,12 +1,16 @@
-use crate::block_tlb::TVMStack;
+use crate::block_tlb::{CommonMsgInfo, CommonMsgInfoInt, CurrencyCollection, Msg, TVMStack};
 use crate::contracts::client::contract_client::ContractClient;
+use crate::contracts::client::contract_msg_result::ContractMsgResult;
 use crate::emulators::tvm::tvm_method_id::TVMGetMethodID;
 use crate::error::TLError;
 use std::sync::Arc;
+use ton_lib_core::cell::TonCell;
 use ton_lib_core::traits::contract_provider::ContractState;
 use ton_lib_core::traits::tlb::TLB;
 use ton_lib_core::types::{TonAddress, TxIdLTHash};
 
+pub use async_trait::async_trait; // used by #[ton_contract] traits
+
 pub struct ContractCtx {
     pub client: ContractClient,
     pub address: TonAddress,
@@ -29,6 +33,16 @@ pub trait TonContract: Send + Sync + Sized {
 
     async fn get_state(&self) -> Result<&Arc<ContractState>, TLError> { Ok(&self.ctx().state) }
 
+    /// The same contract on top of another state, e.g. `ContractMsgResult::new_state`
+    fn with_state(&self, state: Arc<ContractState>) -> Self {
+        let ctx = self.ctx();
+        Self::from_ctx(ContractCtx {
+            client: ctx.client.clone(),
+            address: ctx.address.clone(),
+            state,
+        })
+    }
+
     async fn emulate_get_method<M>(&self, method: M, stack: &TVMStack) -> Result<Vec<u8>, TLError>
     where
         M: Into<TVMGetMethodID> + Send,
@@ -40,6 +54,35 @@ pub trait TonContract: Send + Sync + Sized {
         response.stack_boc()
     }
 
+    async fn emulate_internal(
+        &self,
+        msg_body: TonCell,
+        value: u128,
+        sender: &TonAddress,
+    ) -> Result<ContractMsgResult, TLError> {
+        let ctx = self.ctx();
+        let info = CommonMsgInfoInt {
+            ihr_disabled: true,
+            bounce: true,
+            src: sender.to_msg_address_int().into(),
+            dst: ctx.address.to_msg_address_int().into(),
+            value: CurrencyCollection::new(value),
+            ..Default::default()
+        };
+        ctx.client.emulate_msg(&ctx.state, &Msg::new(info, msg_body)).await
+    }
+
+    async fn emulate_external(&self, msg: &Msg) -> Result<ContractMsgResult, TLError> {
+        if !matches!(msg.info, CommonMsgInfo::ExtIn(_)) {
+            return Err(TLError::UnexpectedValue {
+                expected: "ExtIn message".to_string(),
+                actual: format!("{:?}", msg.info),
+            });
+        }
+        let ctx = self.ctx();
+        ctx.client.emulate_msg(&ctx.state, msg).await
+    }
+
     async fn get_parsed_data<D: TLB>(&self) -> Result<D, TLError> {
         let state = self.get_state().await?;
         match &state.data_boc {
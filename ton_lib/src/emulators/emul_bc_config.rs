@@ -1,9 +1,12 @@
+use crate::block_tlb::ConfigParams;
 use crate::error::TLError;
 use base64::engine::general_purpose::STANDARD;
 use base64::Engine;
 use std::ffi::CString;
 use std::ops::Deref;
 use std::sync::Arc;
+use ton_lib_core::cell::TonCell;
+use ton_lib_core::traits::tlb::TLB;
 
 #[derive(Clone, Debug)]
 pub struct EmulBCConfig(Arc<CString>);
@@ -23,7 +26,15 @@ impl EmulBCConfig {
     pub fn from_boc_hex(config_boc_hex: &str) -> Result<Self, TLError> {
         Self::from_boc_b64(&STANDARD.encode(hex::decode(config_boc_hex)?))
     }
+    pub fn from_config_params(config_params: &ConfigParams) -> Result<Self, TLError> {
+        Self::from_boc(&config_params.to_dict_cell()?.to_boc()?)
+    }
     pub fn from_boc_b64(config_boc_base64: &str) -> Result<Self, TLError> {
         Ok(Self(Arc::new(CString::new(config_boc_base64)?)))
     }
+
+    pub fn to_config_params(&self) -> Result<ConfigParams, TLError> {
+        let config_boc = STANDARD.decode(self.0.to_bytes())?;
+        Ok(ConfigParams::from_dict_cell(&TonCell::from_boc(&config_boc)?)?)
+    }
 }
@@ -0,0 +1,205 @@
+use crate::emulators::tvm::tvm_emul_args::TVMGetMethodArgs;
+use crate::emulators::tvm::tvm_response::TVMGetMethodSuccess;
+use crate::emulators::tx::tx_emul_args::{TXEmulOrdArgs, TXEmulTickTockArgs};
+use crate::emulators::tx::tx_emul_response::{TXEmulationResponse, TXEmulationSuccess};
+use crate::emulators::tx::tx_emulator::TXEmulator;
+use crate::error::TLError;
+use parking_lot::Mutex;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::Arc;
+use std::thread;
+use tokio::sync::{oneshot, Semaphore};
+
+type EmulJob = Box<dyn FnOnce(&mut EmulPoolWorker) + Send>;
+
+#[derive(Clone, Copy, Debug)]
+pub struct EmulPoolConfig {
+    pub threads: usize,
+    /// max jobs waiting for a free worker, submit waits when the queue is full
+    pub queue_capacity: usize,
+    pub tx_log_level: u32,
+    pub tx_debug_enabled: bool,
+}
+
+impl Default for EmulPoolConfig {
+    fn default() -> Self {
+        Self {
+            threads: thread::available_parallelism().map(|x| x.get()).unwrap_or(4),
+            queue_capacity: 1024,
+            tx_log_level: 0,
+            tx_debug_enabled: false,
+        }
+    }
+}
+
+/// Runs emulations on dedicated threads, so blocking FFI calls don't stall async runtime
+///
+/// Each worker keeps its own TXEmulator, so config, libs & prev_blocks are not reloaded if unchanged.
+/// TVMEmulator is bound to contract code & data, so it's created per get-method call (on worker thread).
+#[derive(Clone)]
+pub struct EmulatorPool(Arc<Inner>);
+
+struct Inner {
+    sender: Sender<EmulJob>,
+    permits: Arc<Semaphore>,
+    permits_total: usize,
+}
+
+/// Worker state available to jobs
+pub struct EmulPoolWorker {
+    tx_emulator: Option<TXEmulator>,
+    config: EmulPoolConfig,
+}
+
+/// Result of submitted job
+pub struct EmulJobHandle<R>(oneshot::Receiver<Result<R, TLError>>);
+
+impl<R> EmulJobHandle<R> {
+    pub async fn result(self) -> Result<R, TLError> { self.0.await? }
+}
+
+impl EmulatorPool {
+    pub fn new(config: EmulPoolConfig) -> Result<Self, TLError> {
+        if config.threads == 0 {
+            return Err(TLError::UnexpectedValue {
+                expected: "threads > 0".to_string(),
+                actual: "0".to_string(),
+            });
+        }
+        let (sender, receiver) = channel();
+        let receiver = Arc::new(Mutex::new(receiver));
+        for pos in 0..config.threads {
+            let receiver = receiver.clone();
+            thread::Builder::new().name(format!("emul_pool_{pos}")).spawn(move || run_worker(receiver, config))?;
+        }
+        let permits_total = config.threads + config.queue_capacity;
+        let inner = Inner {
+            sender,
+            permits: Arc::new(Semaphore::new(permits_total)),
+            permits_total,
+        };
+        Ok(Self(Arc::new(inner)))
+    }
+
+    /// Waits for a free slot in the queue and submits the job
+    pub async fn submit<F, R>(&self, job: F) -> Result<EmulJobHandle<R>, TLError>
+    where
+        F: FnOnce(&mut EmulPoolWorker) -> Result<R, TLError> + Send + 'static,
+        R: Send + 'static,
+    {
+        let permit = self.0.permits.clone().acquire_owned().await?;
+        let (res_sender, res_receiver) = oneshot::channel();
+        let job: EmulJob = Box::new(move |worker| {
+            let _ = res_sender.send(job(worker));
+            drop(permit);
+        });
+        if self.0.sender.send(job).is_err() {
+            return Err(TLError::EmulatorPoolClosed);
+        }
+        Ok(EmulJobHandle(res_receiver))
+    }
+
+    pub async fn exec<F, R>(&self, job: F) -> Result<R, TLError>
+    where
+        F: FnOnce(&mut EmulPoolWorker) -> Result<R, TLError> + Send + 'static,
+        R: Send + 'static,
+    {
+        self.submit(job).await?.result().await
+    }
+
+    pub async fn run_get_method(&self, args: TVMGetMethodArgs) -> Result<TVMGetMethodSuccess, TLError> {
+        self.exec(move |_| args.run()).await
+    }
+
+    pub async fn emulate_ord(&self, args: TXEmulOrdArgs) -> Result<TXEmulationSuccess, TLError> {
+        self.exec(move |worker| worker.tx_emulator()?.emulate_ord(&args)).await
+    }
+
+    pub async fn emulate_ord_response(&self, args: TXEmulOrdArgs) -> Result<TXEmulationResponse, TLError> {
+        self.exec(move |worker| worker.tx_emulator()?.emulate_ord_response(&args)).await
+    }
+
+    pub async fn emulate_ticktock(&self, args: TXEmulTickTockArgs) -> Result<TXEmulationSuccess, TLError> {
+        self.exec(move |worker| worker.tx_emulator()?.emulate_ticktock(&args)).await
+    }
+
+    /// Jobs in progress + jobs in queue
+    pub fn jobs_count(&self) -> usize { self.0.permits_total - self.0.permits.available_permits() }
+}
+
+impl EmulPoolWorker {
+    /// Reusable TXEmulator, created on first use
+    pub fn tx_emulator(&mut self) -> Result<&mut TXEmulator, TLError> {
+        if self.tx_emulator.is_none() {
+            self.tx_emulator = Some(TXEmulator::new(self.config.tx_log_level, self.config.tx_debug_enabled)?);
+        }
+        Ok(self.tx_emulator.as_mut().unwrap())
+    }
+}
+
+fn run_worker(receiver: Arc<Mutex<Receiver<EmulJob>>>, config: EmulPoolConfig) {
+    let mut worker = EmulPoolWorker {
+        tx_emulator: None,
+        config,
+    };
+    loop {
+        // lock is released before job execution
+        let Ok(job) = receiver.lock().recv() else {
+            return; // pool is dropped
+        };
+        if catch_unwind(AssertUnwindSafe(|| job(&mut worker))).is_err() {
+            log::error!("[EmulatorPool] job panicked, recreating TXEmulator");
+            worker.tx_emulator = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_emulator_pool_exec() -> anyhow::Result<()> {
+        let config = EmulPoolConfig {
+            threads: 2,
+            queue_capacity: 1,
+            ..Default::default()
+        };
+        let pool = EmulatorPool::new(config)?;
+        let thread_name = pool.exec(|_| Ok(thread::current().name().unwrap_or_default().to_string())).await?;
+        assert!(thread_name.starts_with("emul_pool_"));
+
+        let handles = futures_util::future::try_join_all((0..8).map(|i| pool.exec(move |_| Ok(i * 2)))).await?;
+        assert_eq!(handles, (0..8).map(|i| i * 2).collect::<Vec<_>>());
+
+        // worker survives panic
+        assert!(pool.exec::<_, ()>(|_| panic!("job panic")).await.is_err());
+        assert_eq!(pool.exec(|_| Ok(1)).await?, 1);
+        assert_eq!(pool.jobs_count(), 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_emulator_pool_backpressure() -> anyhow::Result<()> {
+        let config = EmulPoolConfig {
+            threads: 1,
+            queue_capacity: 1,
+            ..Default::default()
+        };
+        let pool = EmulatorPool::new(config)?;
+        let (unlock_sender, unlock_receiver) = std::sync::mpsc::channel::<()>();
+        let running = pool.submit(move |_| Ok(unlock_receiver.recv().is_ok())).await?;
+        let queued = pool.submit(|_| Ok(true)).await?;
+        assert_eq!(pool.jobs_count(), 2);
+
+        let blocked = tokio::time::timeout(std::time::Duration::from_millis(50), pool.submit(|_| Ok(true))).await;
+        assert!(blocked.is_err());
+
+        unlock_sender.send(())?;
+        assert!(running.result().await?);
+        assert!(queued.result().await?);
+        assert!(pool.exec(|_| Ok(true)).await?);
+        Ok(())
+    }
+}
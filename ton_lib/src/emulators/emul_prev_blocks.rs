@@ -0,0 +1,142 @@
+use crate::block_tlb::{BlockIdExt, ConfigParams, ShardIdent, TVMTuple};
+use crate::clients::lite_client::client::LiteClient;
+use crate::error::TLError;
+use futures_util::future::try_join_all;
+use num_bigint::{BigInt, Sign};
+use std::future::Future;
+use ton_lib_core::traits::contract_provider::{ContractProvider, McBlockInfo};
+use ton_lib_core::traits::tlb::TLB;
+
+const PREV_MC_BLOCKS_CNT: u32 = 16;
+const PREV_BLOCKS_100_GLOBAL_VERSION: u32 = 9;
+
+/// PREVBLOCKSINFO tuple (c7 param 13), accessible in TVM via PREVMCBLOCKS, PREVKEYBLOCK & PREVMCBLOCKS_100
+///
+/// https://github.com/ton-blockchain/ton/blob/6f745c04daf8861bb1791cffce6edb1beec62204/doc/GlobalVersions.md#version-4
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmulPrevBlocks {
+    /// up to 16 latest masterchain blocks, the newest goes first
+    pub last_mc_blocks: Vec<BlockIdExt>,
+    pub prev_key_block: BlockIdExt,
+    /// up to 16 latest masterchain blocks with seqno divisible by 100 (since global version 9)
+    pub last_mc_blocks_100: Option<Vec<BlockIdExt>>,
+}
+
+impl EmulPrevBlocks {
+    /// Loads blocks preceding masterchain block `mc_seqno` (inclusive)
+    ///
+    /// `last_mc_blocks_100` is loaded only if it's supported by config global version
+    pub async fn load(
+        client: &LiteClient,
+        mc_seqno: u32,
+        prev_key_block_seqno: u32,
+        config: &ConfigParams,
+    ) -> Result<Self, TLError> {
+        let global_version = config.global_version()?.version;
+        Self::load_with(mc_seqno, prev_key_block_seqno, global_version, |seqno| client.lookup_mc_block(seqno)).await
+    }
+
+    /// The same as `load`, but block ids are taken from the provider
+    pub async fn load_from_provider(
+        provider: &dyn ContractProvider,
+        mc_block: &McBlockInfo,
+        global_version: u32,
+    ) -> Result<Self, TLError> {
+        let load_block_id = |seqno| async move { Ok(mc_block_id(&provider.load_mc_block_info(Some(seqno)).await?)) };
+        Self::load_with(mc_block.seqno, mc_block.prev_key_block_seqno, global_version, load_block_id).await
+    }
+
+    /// `load_block_id` returns masterchain block id by seqno
+    pub async fn load_with<F, Fut>(
+        mc_seqno: u32,
+        prev_key_block_seqno: u32,
+        global_version: u32,
+        load_block_id: F,
+    ) -> Result<Self, TLError>
+    where
+        F: Fn(u32) -> Fut,
+        Fut: Future<Output = Result<BlockIdExt, TLError>>,
+    {
+        let last_seqnos = (0..PREV_MC_BLOCKS_CNT).filter_map(|i| mc_seqno.checked_sub(i));
+        let last_mc_blocks = try_join_all(last_seqnos.map(&load_block_id)).await?;
+        let prev_key_block = load_block_id(prev_key_block_seqno).await?;
+        let last_mc_blocks_100 = match global_version >= PREV_BLOCKS_100_GLOBAL_VERSION {
+            true => {
+                let last_100 = mc_seqno / 100 * 100;
+                let seqnos = (0..PREV_MC_BLOCKS_CNT).filter_map(|i| last_100.checked_sub(i * 100));
+                Some(try_join_all(seqnos.map(&load_block_id)).await?)
+            }
+            false => None,
+        };
+        Ok(Self {
+            last_mc_blocks,
+            prev_key_block,
+            last_mc_blocks_100,
+        })
+    }
+
+    pub fn to_tuple(&self) -> TVMTuple {
+        let make_list = |ids: &[BlockIdExt]| TVMTuple::new(ids.iter().map(|x| block_id_tuple(x).into()).collect());
+        let mut tuple = TVMTuple::default();
+        tuple.push(make_list(&self.last_mc_blocks).into());
+        tuple.push(block_id_tuple(&self.prev_key_block).into());
+        if let Some(blocks_100) = &self.last_mc_blocks_100 {
+            tuple.push(make_list(blocks_100).into());
+        }
+        tuple
+    }
+
+    pub fn to_boc(&self) -> Result<Vec<u8>, TLError> { Ok(self.to_tuple().to_boc()?) }
+}
+
+pub fn mc_block_id(mc_block: &McBlockInfo) -> BlockIdExt {
+    BlockIdExt {
+        shard_ident: ShardIdent::new_mc(),
+        seqno: mc_block.seqno,
+        root_hash: mc_block.root_hash.clone(),
+        file_hash: mc_block.file_hash.clone(),
+    }
+}
+
+// [ wc:Integer shard:Integer seqno:Integer root_hash:Integer file_hash:Integer ] = BlockId
+fn block_id_tuple(block_id: &BlockIdExt) -> TVMTuple {
+    let mut tuple = TVMTuple::default();
+    tuple.push_tiny_int(block_id.shard_ident.workchain as i64);
+    tuple.push_tiny_int(block_id.shard_ident.shard as i64);
+    tuple.push_tiny_int(block_id.seqno as i64);
+    tuple.push_int(BigInt::from_bytes_be(Sign::Plus, block_id.root_hash.as_slice()));
+    tuple.push_int(BigInt::from_bytes_be(Sign::Plus, block_id.file_hash.as_slice()));
+    tuple
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block_tlb::TVMStackValue;
+
+    #[test]
+    fn test_emul_prev_blocks_to_tuple() -> anyhow::Result<()> {
+        let block_id = BlockIdExt::ZERO_BLOCK_ID;
+        let prev_blocks = EmulPrevBlocks {
+            last_mc_blocks: vec![block_id.clone(); 16],
+            prev_key_block: block_id.clone(),
+            last_mc_blocks_100: None,
+        };
+        let mut tuple = prev_blocks.to_tuple();
+        assert_eq!(tuple.len(), 2);
+        let TVMStackValue::Tuple(last_blocks) = &tuple[0] else {
+            panic!("expected tuple");
+        };
+        assert_eq!(last_blocks.len(), 16);
+        let TVMStackValue::Tuple(key_block) = &mut tuple[1] else {
+            panic!("expected tuple");
+        };
+        assert_eq!(*key_block.get_tiny_int(0)?, -1);
+        assert_eq!(*key_block.get_tiny_int(1)?, i64::MIN);
+        assert_eq!(key_block.get_int(3)?.to_bytes_be().1, block_id.root_hash.as_slice());
+
+        let parsed = TVMTuple::from_boc(&prev_blocks.to_boc()?)?;
+        assert_eq!(parsed.len(), 2);
+        Ok(())
+    }
+}
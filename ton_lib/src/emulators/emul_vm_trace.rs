@@ -0,0 +1,187 @@
+use std::str::FromStr;
+use ton_lib_core::cell::TonHash;
+
+/// Execution trace, parsed from VM debug log
+///
+/// Log is available only if debug is enabled (`TVMEmulator::set_debug_enabled`, `TXEmulator::new(_, true)`)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct VMTrace {
+    pub steps: Vec<VMTraceStep>,
+    /// set if VM was terminated by exception
+    pub exit_code: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct VMTraceStep {
+    /// instruction with arguments, e.g. "PUSHINT 5" or "implicit RET"
+    pub instruction: String,
+    /// stack before instruction execution, as printed by VM
+    pub stack: Option<String>,
+    /// code cell hash & offset in bits
+    pub code_pos: Option<(TonHash, u32)>,
+    /// gas remaining after instruction execution
+    pub gas_remaining: Option<i64>,
+    pub exception: Option<VMException>,
+    /// other lines related to the step (debug prints, gas limit changes, etc.)
+    pub logs: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VMException {
+    pub code: i32,
+    pub message: String,
+}
+
+// instructions loading cells to slices (charged as cell load)
+const CELL_LOAD_OPCODES: [&str; 5] = ["CTOS", "XCTOS", "XLOAD", "XLOADQ", "implicit JMPREF"];
+
+impl VMTrace {
+    pub fn parse(vm_log: &str) -> Self {
+        let mut trace = VMTrace::default();
+        let mut cur_step = VMTraceStep::default();
+        let mut executed = false;
+        for line in vm_log.lines().map(str::trim).filter(|x| !x.is_empty()) {
+            // long stack dumps are split into several lines
+            if let Some(stack) = cur_step.stack.as_mut().filter(|x| !is_stack_closed(x)) {
+                stack.push(' ');
+                stack.push_str(line);
+                continue;
+            }
+            // next step begins with stack dump or code position
+            let is_step_start = line.starts_with("stack:") || line.starts_with("code cell hash:");
+            if executed && (is_step_start || line.starts_with("execute ")) {
+                trace.steps.push(std::mem::take(&mut cur_step));
+                executed = false;
+            }
+            if let Some(stack) = line.strip_prefix("stack:") {
+                cur_step.stack = Some(stack.trim().to_string());
+            } else if let Some(pos) = line.strip_prefix("code cell hash:") {
+                cur_step.code_pos = parse_code_pos(pos);
+            } else if let Some(instruction) = line.strip_prefix("execute ") {
+                cur_step.instruction = instruction.to_string();
+                executed = true;
+            } else if let Some(gas) = line.strip_prefix("gas remaining:") {
+                cur_step.gas_remaining = gas.trim().parse().ok();
+            } else if let Some(exception) = line.strip_prefix("handling exception code ") {
+                cur_step.exception = parse_exception(exception);
+            } else if let Some(exit_code) =
+                line.strip_prefix("default exception handler, terminating vm with exit code ")
+            {
+                trace.exit_code = exit_code.trim().parse().ok();
+            } else {
+                cur_step.logs.push(line.to_string());
+            }
+        }
+        if cur_step != VMTraceStep::default() {
+            trace.steps.push(cur_step);
+        }
+        trace
+    }
+
+    /// The step which threw the first exception
+    pub fn failed_step(&self) -> Option<&VMTraceStep> { self.steps.iter().find(|x| x.exception.is_some()) }
+    pub fn cell_loads(&self) -> impl Iterator<Item = &VMTraceStep> { self.steps.iter().filter(|x| x.is_cell_load()) }
+}
+
+impl VMTraceStep {
+    pub fn opcode(&self) -> &str { self.instruction.split_whitespace().next().unwrap_or_default() }
+    pub fn is_cell_load(&self) -> bool {
+        CELL_LOAD_OPCODES.iter().any(|x| self.instruction == *x || self.opcode() == *x)
+    }
+}
+
+fn is_stack_closed(stack: &str) -> bool { stack.matches('[').count() <= stack.matches(']').count() }
+
+// "<hash> offset: <offset>"
+fn parse_code_pos(pos: &str) -> Option<(TonHash, u32)> {
+    let (hash, offset) = pos.trim().split_once(" offset:")?;
+    Some((TonHash::from_str(hash.trim()).ok()?, offset.trim().parse().ok()?))
+}
+
+// "<code>: <message>"
+fn parse_exception(exception: &str) -> Option<VMException> {
+    let (code, message) = exception.split_once(':').unwrap_or((exception, ""));
+    Some(VMException {
+        code: code.trim().parse().ok()?,
+        message: message.trim().to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VM_LOG: &str = "\
+stack: [ 0 CS{Cell{0000} bits: 0..16; refs: 0..0} ]
+code cell hash: 8E6D3F7C34E5B2C1A3C0AF2C2D8E1F3A6B4D5E7F8091A2B3C4D5E6F708192A3B offset: 0
+execute SETCP 0
+gas remaining: 999974
+stack: [ 0 CS{Cell{0000} bits: 0..16; refs: 0..0} ]
+code cell hash: 8E6D3F7C34E5B2C1A3C0AF2C2D8E1F3A6B4D5E7F8091A2B3C4D5E6F708192A3B offset: 16
+execute CTOS
+changing gas limit to 1000
+gas remaining: 999874
+stack: [ 0 ]
+code cell hash: 8E6D3F7C34E5B2C1A3C0AF2C2D8E1F3A6B4D5E7F8091A2B3C4D5E6F708192A3B offset: 24
+execute LDU 32
+handling exception code 9: cell underflow
+default exception handler, terminating vm with exit code 9
+";
+
+    #[test]
+    fn test_vm_trace_parse() -> anyhow::Result<()> {
+        let trace = VMTrace::parse(VM_LOG);
+        assert_eq!(trace.steps.len(), 3);
+        assert_eq!(trace.exit_code, Some(9));
+
+        let first = &trace.steps[0];
+        assert_eq!(first.instruction, "SETCP 0");
+        assert_eq!(first.opcode(), "SETCP");
+        assert_eq!(first.gas_remaining, Some(999974));
+        assert_eq!(first.stack.as_deref(), Some("[ 0 CS{Cell{0000} bits: 0..16; refs: 0..0} ]"));
+        let (hash, offset) = first.code_pos.clone().unwrap();
+        assert_eq!(hash, TonHash::from_str("8E6D3F7C34E5B2C1A3C0AF2C2D8E1F3A6B4D5E7F8091A2B3C4D5E6F708192A3B")?);
+        assert_eq!(offset, 0);
+
+        assert_eq!(trace.steps[1].logs, vec!["changing gas limit to 1000".to_string()]);
+        assert_eq!(trace.cell_loads().count(), 1);
+
+        let failed = trace.failed_step().unwrap();
+        assert_eq!(failed.instruction, "LDU 32");
+        assert_eq!(
+            failed.exception,
+            Some(VMException {
+                code: 9,
+                message: "cell underflow".to_string()
+            })
+        );
+        assert_eq!(failed.gas_remaining, None);
+        Ok(())
+    }
+
+    const VM_LOG_MULTILINE: &str = "\
+stack: [ 1 [ 2 3 ]
+ CS{Cell{0000} bits: 0..16; refs: 0..0} ]
+execute UNPAIR
+gas remaining: 999950
+stack: [ 1 2 3 ]
+execute implicit RET
+gas remaining: 999945
+";
+
+    #[test]
+    fn test_vm_trace_parse_multiline_stack() {
+        let trace = VMTrace::parse(VM_LOG_MULTILINE);
+        assert_eq!(trace.steps.len(), 2);
+        assert_eq!(trace.steps[0].stack.as_deref(), Some("[ 1 [ 2 3 ] CS{Cell{0000} bits: 0..16; refs: 0..0} ]"));
+        assert!(trace.steps[0].logs.is_empty());
+        assert_eq!(trace.steps[1].instruction, "implicit RET");
+        assert_eq!(trace.steps[1].gas_remaining, Some(999945));
+        assert_eq!(trace.exit_code, None);
+    }
+
+    #[test]
+    fn test_vm_trace_parse_empty() {
+        assert_eq!(VMTrace::parse(""), VMTrace::default());
+    }
+}
@@ -1,4 +1,8 @@
 pub mod emul_bc_config;
+pub mod emul_pool;
+pub mod emul_prev_blocks;
 pub(super) mod emul_utils;
+pub mod emul_vm_trace;
+pub mod sandbox;
 pub mod tvm;
 pub mod tx;
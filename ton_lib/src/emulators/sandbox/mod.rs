@@ -0,0 +1,2 @@
+pub mod sandbox_chain;
+pub mod sandbox_tx;
@@ -0,0 +1,283 @@
+use crate::block_tlb::{CommonMsgInfo, Msg, ShardAccount};
+use crate::emulators::emul_bc_config::EmulBCConfig;
+use crate::emulators::emul_prev_blocks::EmulPrevBlocks;
+use crate::emulators::sandbox::sandbox_tx::{build_tx_tree, SandboxTx, SandboxTxNode};
+use crate::emulators::tx::tx_emul_args::{TXEmulArgs, TXEmulOrdArgs};
+use crate::emulators::tx::tx_emulator::TXEmulator;
+use crate::error::TLError;
+use crate::libs_dict::LibsDict;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use ton_lib_core::cell::{TonCell, TonCellRef, TonCellUtils, TonHash};
+use ton_lib_core::error::TLCoreError;
+use ton_lib_core::traits::contract_provider::ContractProvider;
+use ton_lib_core::traits::tlb::TLB;
+use ton_lib_core::types::TonAddress;
+
+/// Local blockchain on top of TXEmulator
+///
+/// Keeps accounts states, assigns lt & utime and routes outbound internal messages
+/// to destination accounts until the queue drains.
+/// Unknown accounts are loaded from `provider` (if set) or considered as non-existing.
+pub struct Sandbox {
+    emulator: TXEmulator,
+    bc_config: EmulBCConfig,
+    provider: Option<Arc<dyn ContractProvider>>,
+    accounts: HashMap<TonAddress, ShardAccount>,
+    libs: LibsDict,
+    libs_boc: Option<Arc<Vec<u8>>>,
+    now: u32,
+    lt: u64,
+    rand_seed: TonHash,
+    prev_blocks_boc: Option<Vec<u8>>,
+    ignore_chksig: bool,
+    max_txs: usize,
+}
+
+// changes made by a single send_msg call, applied only if all txs are emulated
+struct SandboxChanges {
+    accounts: HashMap<TonAddress, ShardAccount>,
+    lt: u64,
+}
+
+impl Sandbox {
+    pub const DEFAULT_MAX_TXS: usize = 1000;
+    pub const DEFAULT_START_LT: u64 = 1_000_000;
+
+    pub fn new(bc_config: EmulBCConfig) -> Result<Self, TLError> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).map_err(TLCoreError::from)?.as_secs() as u32;
+        Ok(Self {
+            emulator: TXEmulator::new(0, false)?,
+            bc_config,
+            provider: None,
+            accounts: HashMap::new(),
+            libs: LibsDict::default(),
+            libs_boc: None,
+            now,
+            lt: Self::DEFAULT_START_LT,
+            rand_seed: TonHash::ZERO,
+            prev_blocks_boc: None,
+            ignore_chksig: false,
+            max_txs: Self::DEFAULT_MAX_TXS,
+        })
+    }
+
+    pub fn with_provider(mut self, provider: Arc<dyn ContractProvider>) -> Self {
+        self.provider = Some(provider);
+        self
+    }
+
+    /// Enables VM debug output (visible in SandboxTx::vm_log)
+    pub fn with_debug_enabled(mut self, debug_enabled: bool) -> Result<Self, TLError> {
+        self.emulator = TXEmulator::new(0, debug_enabled)?;
+        Ok(self)
+    }
+
+    pub fn with_now(mut self, now: u32) -> Self {
+        self.now = now;
+        self
+    }
+
+    pub fn with_lt(mut self, lt: u64) -> Self {
+        self.lt = lt;
+        self
+    }
+
+    pub fn with_rand_seed(mut self, rand_seed: TonHash) -> Self {
+        self.rand_seed = rand_seed;
+        self
+    }
+
+    /// Required by contracts using PREVMCBLOCKS / PREVKEYBLOCK / PREVMCBLOCKS_100
+    pub fn with_prev_blocks(mut self, prev_blocks: &EmulPrevBlocks) -> Result<Self, TLError> {
+        self.prev_blocks_boc = Some(prev_blocks.to_boc()?);
+        Ok(self)
+    }
+
+    pub fn with_ignore_chksig(mut self, ignore_chksig: bool) -> Self {
+        self.ignore_chksig = ignore_chksig;
+        self
+    }
+
+    /// Limits txs count produced by a single `send_msg` call (protection from infinite loops)
+    pub fn with_max_txs(mut self, max_txs: usize) -> Self {
+        self.max_txs = max_txs;
+        self
+    }
+
+    pub fn now(&self) -> u32 { self.now }
+    pub fn set_now(&mut self, now: u32) { self.now = now; }
+    pub fn lt(&self) -> u64 { self.lt }
+    pub fn accounts(&self) -> &HashMap<TonAddress, ShardAccount> { &self.accounts }
+    pub fn account(&self, address: &TonAddress) -> Option<&ShardAccount> { self.accounts.get(address) }
+    pub fn set_account(&mut self, address: TonAddress, account: ShardAccount) {
+        self.accounts.insert(address, account);
+    }
+
+    pub fn add_libs<I: IntoIterator<Item = TonCellRef>>(&mut self, libs: I) -> Result<(), TLError> {
+        for lib in libs {
+            self.libs.insert(lib.hash()?.clone(), lib);
+        }
+        self.libs_boc = match self.libs.is_empty() {
+            true => None,
+            false => Some(Arc::new(self.libs.to_boc()?)),
+        };
+        Ok(())
+    }
+
+    /// Returns account from local state, loads it from provider if it's unknown
+    pub async fn get_or_load_account(&mut self, address: &TonAddress) -> Result<&ShardAccount, TLError> {
+        if !self.accounts.contains_key(address) {
+            let account = self.load_account(address).await?;
+            self.accounts.insert(address.clone(), account);
+        }
+        Ok(&self.accounts[address])
+    }
+
+    /// Emulates `msg` (external-in or internal) and all txs caused by it
+    ///
+    /// Messages are processed in created_lt order until the queue drains.
+    /// On error (including SandboxTxLimitExceeded) accounts & lt are left unchanged
+    pub async fn send_msg(&mut self, msg: &Msg) -> Result<SandboxTx, TLError> {
+        let mut changes = SandboxChanges {
+            accounts: HashMap::new(),
+            lt: self.lt,
+        };
+        let mut queue = BTreeMap::new();
+        let mut msg_seqno = 0usize;
+        queue.insert((0, msg_seqno), (msg.clone(), None));
+
+        let mut nodes = vec![];
+        while let Some((_, (msg, parent))) = queue.pop_first() {
+            if nodes.len() >= self.max_txs {
+                return Err(TLError::SandboxTxLimitExceeded(nodes.len()));
+            }
+            let address = match &msg.info {
+                CommonMsgInfo::Int(info) => TonAddress::from_msg_address(info.dst.clone())?,
+                CommonMsgInfo::ExtIn(info) => TonAddress::from_msg_address(info.dst.clone())?,
+                CommonMsgInfo::ExtOut(_) => return Err(TLError::SandboxWrongMsg("can't send ExtOut message".into())),
+            };
+            let tx = self.emulate(&mut changes, &address, &msg).await?;
+            for out_msg in &tx.tx.msgs.out_msgs {
+                if let CommonMsgInfo::Int(info) = &out_msg.info {
+                    msg_seqno += 1;
+                    queue.insert((info.created_lt, msg_seqno), (out_msg.clone(), Some(nodes.len())));
+                }
+            }
+            nodes.push(SandboxTxNode { tx, parent });
+        }
+        let root_tx = build_tx_tree(nodes).ok_or_else(|| TLError::SandboxWrongMsg("no txs emulated".into()))?;
+        self.accounts.extend(changes.accounts);
+        self.lt = changes.lt;
+        Ok(root_tx)
+    }
+
+    async fn emulate(
+        &mut self,
+        changes: &mut SandboxChanges,
+        address: &TonAddress,
+        msg: &Msg,
+    ) -> Result<SandboxTx, TLError> {
+        let shard_account_boc = match changes.accounts.get(address) {
+            Some(account) => account.to_boc()?,
+            None => self.get_or_load_account(address).await?.to_boc()?,
+        };
+        let args = TXEmulOrdArgs {
+            in_msg_boc: msg.to_boc()?,
+            emul_args: TXEmulArgs {
+                shard_account_boc,
+                bc_config: self.bc_config.clone(),
+                rand_seed: self.rand_seed.clone(),
+                utime: self.now,
+                lt: changes.lt,
+                ignore_chksig: self.ignore_chksig,
+                prev_blocks_boc: self.prev_blocks_boc.clone(),
+                libs_boc: self.libs_boc.clone(),
+            },
+        };
+        let response = self.emulator.emulate_ord(&args)?;
+        let tx = response.tx_parsed()?;
+        changes.accounts.insert(address.clone(), response.shard_account_parsed()?);
+        // out messages got lt in range (tx.lt, tx.lt + out_msgs_cnt]
+        changes.lt = changes.lt.max(tx.lt + tx.out_msgs_cnt as u64 + 1);
+        Ok(SandboxTx {
+            address: address.clone(),
+            tx,
+            vm_log: response.vm_log,
+            children: vec![],
+        })
+    }
+
+    async fn load_account(&mut self, address: &TonAddress) -> Result<ShardAccount, TLError> {
+        let Some(provider) = &self.provider else {
+            return Ok(ShardAccount::NON_EXIST);
+        };
+        let provider = provider.clone();
+        let state = provider.load_state(address.clone(), None).await?;
+        let mut cells = vec![];
+        for boc in [&state.code_boc, &state.data_boc].into_iter().flatten() {
+            cells.push(TonCell::from_boc(boc)?);
+        }
+        let lib_ids = TonCellUtils::extract_lib_ids(&cells)?;
+        let missing_libs: Vec<_> = lib_ids.into_iter().filter(|id| !self.libs.contains_key(id)).collect();
+        if !missing_libs.is_empty() {
+            let libs = provider.load_libs(missing_libs, state.mc_seqno).await?;
+            let libs = libs.into_iter().map(|(_, boc)| TonCellRef::from_boc(&boc)).collect::<Result<Vec<_>, _>>()?;
+            self.add_libs(libs)?;
+        }
+        Ok(ShardAccount::from_contract_state(&state, self.now)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block_tlb::_test_block_data::{
+        WALLET_EXT_IN_MSG_BOC_HEX, WALLET_EXT_IN_MSG_LT, WALLET_EXT_IN_MSG_UTIME, WALLET_SHARD_ACCOUNT_BOC_HEX,
+    };
+    use crate::block_tlb::MaybeAccount;
+    use crate::sys_utils::sys_tonlib_set_verbosity_level;
+    use std::sync::LazyLock;
+
+    static BC_CONFIG: LazyLock<EmulBCConfig> = LazyLock::new(|| {
+        EmulBCConfig::from_boc_hex(include_str!("../../../../resources/tests/bc_config_key_block_42123611.hex"))
+            .unwrap()
+    });
+
+    #[tokio::test]
+    async fn test_sandbox_routes_out_msgs() -> anyhow::Result<()> {
+        sys_tonlib_set_verbosity_level(0);
+        let wallet = ShardAccount::from_boc_hex(WALLET_SHARD_ACCOUNT_BOC_HEX)?;
+        let MaybeAccount::Account(account) = &wallet.account else {
+            panic!("Expected account")
+        };
+        let wallet_address = TonAddress::from_msg_address(account.addr.clone())?;
+        // wallet sends jetton transfer to jetton_wallet, which doesn't exist in sandbox, so message bounces back
+        let ext_in_msg = Msg::from_boc_hex(WALLET_EXT_IN_MSG_BOC_HEX)?;
+
+        let mut sandbox =
+            Sandbox::new(BC_CONFIG.clone())?.with_now(WALLET_EXT_IN_MSG_UTIME).with_lt(WALLET_EXT_IN_MSG_LT);
+        sandbox.set_account(wallet_address.clone(), wallet.clone());
+        assert!(sandbox.send_msg(&ext_in_msg).await.is_err()); // wrong signature
+
+        // limit is exceeded after the wallet tx, nothing is applied
+        let mut sandbox = sandbox.with_ignore_chksig(true).with_max_txs(1);
+        let err = sandbox.send_msg(&ext_in_msg).await.unwrap_err();
+        assert!(matches!(err, TLError::SandboxTxLimitExceeded(1)));
+        assert_eq!(sandbox.account(&wallet_address).unwrap().to_boc()?, wallet.to_boc()?);
+        assert_eq!(sandbox.lt(), WALLET_EXT_IN_MSG_LT);
+
+        let mut sandbox = sandbox.with_max_txs(Sandbox::DEFAULT_MAX_TXS);
+        let root_tx = sandbox.send_msg(&ext_in_msg).await?;
+        assert_eq!(root_tx.address, wallet_address);
+        assert_eq!(root_tx.txs_count(), 3);
+        let jetton_wallet_tx = &root_tx.children[0];
+        assert_ne!(jetton_wallet_tx.address, wallet_address);
+        assert_eq!(jetton_wallet_tx.children[0].address, wallet_address);
+        assert!(jetton_wallet_tx.tx.lt > root_tx.tx.lt);
+        assert!(sandbox.lt() > jetton_wallet_tx.children[0].tx.lt);
+        assert_eq!(sandbox.accounts().len(), 2);
+        Ok(())
+    }
+}
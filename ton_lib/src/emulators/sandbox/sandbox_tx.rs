@@ -0,0 +1,102 @@
+use crate::block_tlb::{Tx, TxDescr};
+use ton_lib_core::types::TonAddress;
+
+/// Tx emulated by Sandbox. `children` are txs caused by outbound internal messages of this tx
+#[derive(Debug, Clone)]
+pub struct SandboxTx {
+    pub address: TonAddress,
+    pub tx: Tx,
+    pub vm_log: String,
+    pub children: Vec<SandboxTx>,
+}
+
+impl SandboxTx {
+    /// Depth-first, parent goes before its children
+    pub fn flatten(&self) -> Vec<&SandboxTx> {
+        let mut result = vec![];
+        let mut stack = vec![self];
+        while let Some(node) = stack.pop() {
+            result.push(node);
+            stack.extend(node.children.iter().rev());
+        }
+        result
+    }
+
+    pub fn txs_count(&self) -> usize { 1 + self.children.iter().map(SandboxTx::txs_count).sum::<usize>() }
+
+    pub fn aborted(&self) -> bool {
+        match &self.tx.descr {
+            TxDescr::Ord(descr) => descr.aborted,
+            TxDescr::TickTock(descr) => descr.aborted,
+            _ => false,
+        }
+    }
+
+    /// true if no tx in the tree is aborted
+    pub fn all_success(&self) -> bool { self.flatten().iter().all(|node| !node.aborted()) }
+}
+
+pub(super) struct SandboxTxNode {
+    pub(super) tx: SandboxTx,
+    pub(super) parent: Option<usize>,
+}
+
+/// nodes must be in processing order: parent always goes before children
+pub(super) fn build_tx_tree(nodes: Vec<SandboxTxNode>) -> Option<SandboxTx> {
+    let mut children_ids = vec![vec![]; nodes.len()];
+    for (id, node) in nodes.iter().enumerate() {
+        if let Some(parent) = node.parent {
+            children_ids[parent].push(id);
+        }
+    }
+    let mut txs: Vec<_> = nodes.into_iter().map(|node| Some(node.tx)).collect();
+    // children have bigger ids, so build from the end
+    for id in (0..txs.len()).rev() {
+        let children = children_ids[id].iter().filter_map(|child_id| txs[*child_id].take()).collect();
+        if let Some(tx) = txs[id].as_mut() {
+            tx.children = children;
+        }
+    }
+    txs.into_iter().next().flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_node(lt: u64, parent: Option<usize>) -> SandboxTxNode {
+        let tx = Tx {
+            lt,
+            ..Default::default()
+        };
+        SandboxTxNode {
+            tx: SandboxTx {
+                address: TonAddress::ZERO,
+                tx,
+                vm_log: String::new(),
+                children: vec![],
+            },
+            parent,
+        }
+    }
+
+    #[test]
+    fn test_build_tx_tree() {
+        // 1 -> (2 -> (4, 5), 3)
+        let nodes = vec![
+            make_node(1, None),
+            make_node(2, Some(0)),
+            make_node(3, Some(0)),
+            make_node(4, Some(1)),
+            make_node(5, Some(1)),
+        ];
+        let root = build_tx_tree(nodes).unwrap();
+        assert_eq!(root.txs_count(), 5);
+        assert_eq!(root.children.len(), 2);
+        assert_eq!(root.children[0].children.len(), 2);
+        assert!(root.children[1].children.is_empty());
+        let lts: Vec<_> = root.flatten().iter().map(|node| node.tx.lt).collect();
+        assert_eq!(lts, vec![1, 2, 4, 5, 3]);
+        assert!(build_tx_tree(vec![]).is_none());
+    }
+}
@@ -1,4 +1,5 @@
 pub mod tvm_c7;
+pub mod tvm_emul_args;
 pub mod tvm_emulator;
 pub mod tvm_method_id;
 pub mod tvm_response;
@@ -1,9 +1,20 @@
+use crate::block_tlb::{Block, ConfigParams};
+use crate::clients::lite_client::client::LiteClient;
 use crate::emulators::emul_bc_config::EmulBCConfig;
+use crate::emulators::emul_prev_blocks::EmulPrevBlocks;
 use crate::error::TLError;
 use std::time::{SystemTime, UNIX_EPOCH};
-use ton_lib_core::cell::TonHash;
+use ton_lib_core::cell::{TonCell, TonHash};
+use ton_lib_core::traits::contract_provider::{ContractProvider, ContractState, McBlockInfo};
+use ton_lib_core::traits::tlb::TLB;
 use ton_lib_core::types::TonAddress;
 
+/// Smart contract context (c7 register) for TVMEmulator
+///
+/// Use `load` or `load_from_provider` to reproduce context of a masterchain block:
+/// unix_time, rand_seed, config & prev_blocks are taken from the same block.
+/// Unpacked config & storage prices are derived by the emulator from `config`,
+/// in-msg params are empty for get methods. Due payment is not supported by emulator API, so it's 0.
 #[derive(Clone, Debug)]
 pub struct TVMEmulatorC7 {
     pub address: TonAddress,
@@ -11,6 +22,8 @@ pub struct TVMEmulatorC7 {
     pub balance: u64,
     pub rand_seed: TonHash,
     pub config: EmulBCConfig,
+    /// required by contracts using PREVMCBLOCKS / PREVKEYBLOCK / PREVMCBLOCKS_100
+    pub prev_blocks: Option<EmulPrevBlocks>,
 }
 
 impl TVMEmulatorC7 {
@@ -21,6 +34,138 @@ impl TVMEmulatorC7 {
             balance: 0,
             rand_seed: TonHash::ZERO,
             config,
+            prev_blocks: None,
         })
     }
+
+    pub fn from_config_params(address: TonAddress, config: &ConfigParams) -> Result<Self, TLError> {
+        Self::new(address, EmulBCConfig::from_config_params(config)?)
+    }
+
+    /// Builds c7 as it was at the end of masterchain block `mc_seqno`
+    pub async fn load(client: &LiteClient, address: TonAddress, mc_seqno: u32) -> Result<Self, TLError> {
+        let block_id = client.lookup_mc_block(mc_seqno).await?;
+        let block = Block::from_boc(&client.get_block(block_id, None).await?.data)?;
+        let config = client.get_config(mc_seqno, None).await?;
+        let prev_blocks = EmulPrevBlocks::load(client, mc_seqno, block.info.prev_key_block_seqno, &config).await?;
+        Ok(Self::from_config_params(address, &config)?.with_block(&block).with_prev_blocks(prev_blocks))
+    }
+
+    /// Builds c7 for the state at its mc block (the latest one if state.mc_seqno is None)
+    ///
+    /// rand_seed is derived from the block root hash if provider doesn't expose it
+    pub async fn load_from_provider(provider: &dyn ContractProvider, state: &ContractState) -> Result<Self, TLError> {
+        let mc_block = provider.load_mc_block_info(state.mc_seqno).await?;
+        let config_boc = provider.load_bc_config(Some(mc_block.seqno)).await?;
+        let config = ConfigParams::from_dict_cell(&TonCell::from_boc(&config_boc)?)?;
+        let global_version = config.global_version()?.version;
+        let prev_blocks = EmulPrevBlocks::load_from_provider(provider, &mc_block, global_version).await?;
+        Ok(Self::new(state.address.clone(), EmulBCConfig::from_boc(&config_boc)?)?
+            .with_balance(state.balance as u64)
+            .with_mc_block(&mc_block)
+            .with_prev_blocks(prev_blocks))
+    }
+
+    /// Takes unix_time & rand_seed from the block
+    pub fn with_block(self, block: &Block) -> Self {
+        self.with_unix_time(block.info.gen_utime).with_rand_seed(block.extra.rand_seed.clone())
+    }
+
+    /// Takes unix_time & rand_seed from the block
+    ///
+    /// If the real seed is unknown, the block root hash is used, so results are still reproducible for the block
+    pub fn with_mc_block(self, mc_block: &McBlockInfo) -> Self {
+        let rand_seed = mc_block.rand_seed.as_ref().unwrap_or(&mc_block.root_hash).clone();
+        self.with_unix_time(mc_block.gen_utime).with_rand_seed(rand_seed)
+    }
+
+    pub fn with_unix_time(mut self, unix_time: u32) -> Self {
+        self.unix_time = unix_time;
+        self
+    }
+
+    pub fn with_balance(mut self, balance: u64) -> Self {
+        self.balance = balance;
+        self
+    }
+
+    pub fn with_rand_seed(mut self, rand_seed: TonHash) -> Self {
+        self.rand_seed = rand_seed;
+        self
+    }
+
+    pub fn with_prev_blocks(mut self, prev_blocks: EmulPrevBlocks) -> Self {
+        self.prev_blocks = Some(prev_blocks);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contracts::client::fixture_provider::FixtureProvider;
+    use ton_lib_core::types::TxIdLTHash;
+
+    const BC_CONFIG_HEX: &str = include_str!("../../../resources/tests/bc_config_key_block_42123611.hex");
+
+    fn make_mc_block(seqno: u32) -> McBlockInfo {
+        McBlockInfo {
+            seqno,
+            root_hash: TonHash::from([(seqno % 256) as u8; 32]),
+            file_hash: TonHash::from([1u8; 32]),
+            gen_utime: 1_700_000_000 + seqno,
+            prev_key_block_seqno: 42123611,
+            rand_seed: Some(TonHash::from([2u8; 32])),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tvm_emulator_c7_load_from_provider() -> anyhow::Result<()> {
+        let mc_seqno = 42123620;
+        let seqnos = (0..16).map(|i| mc_seqno - i).chain((0..16).map(|i| 42123600 - i * 100)).chain([42123611]);
+        let mut provider = FixtureProvider::new().with_bc_config(42123611, hex::decode(BC_CONFIG_HEX.trim())?);
+        for seqno in seqnos {
+            provider = provider.with_mc_block(make_mc_block(seqno));
+        }
+        let state = ContractState {
+            mc_seqno: Some(mc_seqno),
+            address: TonAddress::new(0, TonHash::from([3u8; 32])),
+            last_tx_id: TxIdLTHash::new(1, TonHash::ZERO),
+            code_boc: None,
+            data_boc: None,
+            frozen_hash: None,
+            balance: 100,
+            sync_utime: Some(1),
+        };
+
+        let c7 = TVMEmulatorC7::load_from_provider(&provider, &state).await?;
+        assert_eq!(c7.address, state.address);
+        assert_eq!(c7.balance, 100);
+        assert_eq!(c7.unix_time, 1_700_000_000 + mc_seqno);
+        assert_eq!(c7.rand_seed, TonHash::from([2u8; 32]));
+        assert_eq!(*c7.config, *EmulBCConfig::from_boc_hex(BC_CONFIG_HEX.trim())?);
+
+        let prev_blocks = c7.prev_blocks.as_ref().unwrap();
+        let last_seqnos = prev_blocks.last_mc_blocks.iter().map(|x| x.seqno).collect::<Vec<_>>();
+        assert_eq!(last_seqnos, (0..16).map(|i| mc_seqno - i).collect::<Vec<_>>());
+        assert_eq!(prev_blocks.last_mc_blocks[0].root_hash, make_mc_block(mc_seqno).root_hash);
+        assert_eq!(prev_blocks.prev_key_block.seqno, 42123611);
+        let last_100 = prev_blocks.last_mc_blocks_100.as_ref().unwrap();
+        assert_eq!((last_100.len(), last_100[0].seqno, last_100[15].seqno), (16, 42123600, 42122100));
+
+        // seed is derived from the block if it's unknown
+        let c7 = c7.with_mc_block(&McBlockInfo {
+            rand_seed: None,
+            ..make_mc_block(mc_seqno)
+        });
+        assert_eq!(c7.rand_seed, make_mc_block(mc_seqno).root_hash);
+
+        // block is required
+        let state = ContractState {
+            mc_seqno: Some(mc_seqno + 1),
+            ..state
+        };
+        assert!(TVMEmulatorC7::load_from_provider(&provider, &state).await.is_err());
+        Ok(())
+    }
 }
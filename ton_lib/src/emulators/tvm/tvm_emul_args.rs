@@ -0,0 +1,27 @@
+use crate::emulators::tvm::tvm_c7::TVMEmulatorC7;
+use crate::emulators::tvm::tvm_emulator::TVMEmulator;
+use crate::emulators::tvm::tvm_method_id::TVMGetMethodID;
+use crate::emulators::tvm::tvm_response::TVMGetMethodSuccess;
+use crate::error::TLError;
+use std::sync::Arc;
+
+/// Everything required to run get-method on a fresh TVMEmulator
+#[derive(Debug, Clone)]
+pub struct TVMGetMethodArgs {
+    pub code_boc: Vec<u8>,
+    pub data_boc: Vec<u8>,
+    pub c7: TVMEmulatorC7,
+    pub libs_boc: Option<Arc<Vec<u8>>>, // shared, so cached libs aren't copied for each call
+    pub method: TVMGetMethodID,
+    pub stack_boc: Vec<u8>,
+}
+
+impl TVMGetMethodArgs {
+    pub fn run(&self) -> Result<TVMGetMethodSuccess, TLError> {
+        let mut emulator = TVMEmulator::new(&self.code_boc, &self.data_boc, &self.c7)?;
+        if let Some(libs_boc) = &self.libs_boc {
+            emulator.set_libs(libs_boc)?;
+        }
+        emulator.run_get_method(self.method.clone(), &self.stack_boc)
+    }
+}
@@ -11,7 +11,7 @@ use std::ffi::CString;
 use tonlib_sys::{
     tvm_emulator_create, tvm_emulator_destroy, tvm_emulator_run_get_method, tvm_emulator_send_external_message,
     tvm_emulator_send_internal_message, tvm_emulator_set_c7, tvm_emulator_set_debug_enabled,
-    tvm_emulator_set_gas_limit, tvm_emulator_set_libraries,
+    tvm_emulator_set_gas_limit, tvm_emulator_set_libraries, tvm_emulator_set_prev_blocks_info,
 };
 
 #[derive(Debug)]
@@ -40,10 +40,16 @@ impl TVMEmulator {
         let success = unsafe {
             tvm_emulator_set_c7(self.ptr, address.as_ptr(), c7.unix_time, c7.balance, seed.as_ptr(), c7.config.as_ptr())
         };
-        match success {
-            true => Ok(()),
-            false => set_param_failed("c7"),
+        if !success {
+            return set_param_failed("c7");
         }
+        if let Some(prev_blocks) = &c7.prev_blocks {
+            let info = make_b64_c_str(&prev_blocks.to_boc()?)?;
+            if !unsafe { tvm_emulator_set_prev_blocks_info(self.ptr, info.as_ptr()) } {
+                return set_param_failed("prev_blocks_info");
+            }
+        }
+        Ok(())
     }
 
     pub fn set_debug_enabled(&mut self, enabled: bool) -> Result<(), TLError> {
@@ -318,4 +324,39 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_tvm_emulator_vm_trace() -> anyhow::Result<()> {
+        sys_tonlib_set_verbosity_level(0);
+        let code = hex::decode(include_str!("../../../resources/tests/test_multiplier_code.hex"))?;
+        let c7 = TVMEmulatorC7::new(
+            TonAddress::from_str("Ef8CmPZLxWB-9ypeGdGhEqA6ZNLBFUwnqXPH2eUQd_MzbGh_")?,
+            BC_CONFIG.clone(),
+        )?;
+        let mut emulator = TVMEmulator::new(&code, &TonCell::EMPTY.to_boc()?, &c7)?;
+        emulator.set_debug_enabled(true)?;
+
+        let mut stack = TVMStack::default();
+        stack.push_int(BigInt::from(2));
+        stack.push_int(BigInt::from(3));
+        let emulated = emulator.run_get_method("get_val", &stack.to_boc()?)?;
+        assert_eq!(emulated.vm_exit_code, 0);
+        let trace = emulated.vm_trace();
+        assert_eq!(trace.exit_code, None);
+        assert!(trace.failed_step().is_none());
+        let first_stack = trace.steps[0].stack.as_deref().unwrap();
+        assert!(first_stack.starts_with("[ 2 3 ") && first_stack.ends_with(']'), "{first_stack}");
+        assert!(trace.steps.iter().all(|x| !x.instruction.is_empty() && x.gas_remaining.is_some()));
+        assert_eq!(trace.steps.last().unwrap().instruction, "implicit RET");
+
+        // no arguments => stack underflow
+        let emulated = emulator.run_get_method("get_val", TVMStack::EMPTY_BOC)?;
+        assert_eq!(emulated.vm_exit_code, 2);
+        let trace = emulated.vm_trace();
+        assert_eq!(trace.exit_code, Some(2));
+        let failed = trace.failed_step().unwrap();
+        assert_eq!(failed.exception.as_ref().unwrap().code, 2);
+        assert_eq!(failed.gas_remaining, None);
+        Ok(())
+    }
 }
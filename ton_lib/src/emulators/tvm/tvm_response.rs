@@ -1,5 +1,6 @@
 use crate::block_tlb::TVMStack;
 use crate::emulators::emul_utils::require_field;
+use crate::emulators::emul_vm_trace::VMTrace;
 use crate::error::TLError;
 use base64::prelude::BASE64_STANDARD;
 use base64::Engine;
@@ -73,6 +74,8 @@ impl TVMGetMethodSuccess {
     }
 
     pub fn exit_success(&self) -> bool { self.vm_exit_code == 0 || self.vm_exit_code == 1 }
+
+    pub fn vm_trace(&self) -> VMTrace { VMTrace::parse(self.vm_log.as_deref().unwrap_or_default()) }
 }
 
 #[derive(Debug)]
@@ -90,6 +93,7 @@ pub struct TVMSendMsgSuccess {
 
 impl TVMSendMsgSuccess {
     pub fn exit_success(&self) -> bool { self.vm_exit_code == 0 || self.vm_exit_code == 1 }
+    pub fn vm_trace(&self) -> VMTrace { VMTrace::parse(&self.vm_log) }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -1,3 +1,5 @@
+pub mod tx_dry_run;
 pub mod tx_emul_args;
 pub mod tx_emul_response;
 pub mod tx_emulator;
+pub mod tx_replay;
@@ -0,0 +1,129 @@
+use crate::block_tlb::{Msg, OutList, ShardAccount, TrActionPhase, TrComputePhaseVM, TrStoragePhase, Tx, TxDescr};
+use crate::emulators::tx::tx_emul_response::TXEmulationResponse;
+use crate::error::TLError;
+use ton_lib_core::traits::tlb::TLB;
+
+/// Result of external message emulation against current account state
+///
+/// `accepted = false` means the message would be rejected by the contract (wrong seqno, signature, etc.)
+/// and no tx would appear in blockchain. In this case `tx` is None and `exit_code` is taken from the VM.
+#[derive(Debug, Clone)]
+pub struct TXDryRunReport {
+    pub accepted: bool,
+    pub exit_code: Option<i32>,
+    pub error: Option<String>,
+    pub tx: Option<Tx>,
+    pub out_actions: OutList,
+    pub balance_before: u128,
+    pub balance_after: u128,
+    pub vm_log: String,
+}
+
+impl TXDryRunReport {
+    pub fn from_response(shard_account: &ShardAccount, response: TXEmulationResponse) -> Result<Self, TLError> {
+        let balance_before = shard_account.account.get_balance().map(|x| x.to_u128()).unwrap_or(0);
+        if !response.success {
+            if response.external_not_accepted != Some(true) {
+                return Err(TLError::EmulatorEmulationError {
+                    vm_exit_code: response.vm_exit_code,
+                    response_raw: response.raw_response,
+                });
+            }
+            return Ok(Self {
+                accepted: false,
+                exit_code: response.vm_exit_code,
+                error: response.error,
+                tx: None,
+                out_actions: OutList::default(),
+                balance_before,
+                balance_after: balance_before,
+                vm_log: response.vm_log.unwrap_or_default(),
+            });
+        }
+        let out_actions = match &response.actions {
+            Some(actions) => OutList::from_boc_b64(actions)?,
+            None => OutList::default(),
+        };
+        let success = response.into_success()?;
+        let tx = success.tx_parsed()?;
+        let shard_account_after = success.shard_account_parsed()?;
+        Ok(Self {
+            accepted: true,
+            exit_code: tx.descr.exit_code(),
+            error: None,
+            balance_before,
+            balance_after: shard_account_after.account.get_balance().map(|x| x.to_u128()).unwrap_or(0),
+            tx: Some(tx),
+            out_actions,
+            vm_log: success.vm_log,
+        })
+    }
+
+    /// true if message is accepted and tx is not aborted
+    pub fn success(&self) -> bool {
+        match self.tx.as_ref().map(|x| &x.descr) {
+            Some(TxDescr::Ord(descr)) => !descr.aborted,
+            _ => false,
+        }
+    }
+
+    pub fn total_fees(&self) -> u128 { self.tx.as_ref().map(|x| x.total_fees.grams.to_u128()).unwrap_or(0) }
+    pub fn balance_change(&self) -> i128 { self.balance_after as i128 - self.balance_before as i128 }
+    pub fn out_msgs(&self) -> &[Msg] { self.tx.as_ref().map(|x| x.msgs.out_msgs.as_slice()).unwrap_or(&[]) }
+    pub fn storage_phase(&self) -> Option<&TrStoragePhase> { self.tx.as_ref()?.descr.storage_phase() }
+    pub fn compute_phase(&self) -> Option<&TrComputePhaseVM> { self.tx.as_ref()?.descr.compute_phase() }
+    pub fn action_phase(&self) -> Option<&TrActionPhase> { self.tx.as_ref()?.descr.action_phase() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block_tlb::_test_block_data::{
+        WALLET_EXT_IN_MSG_BOC_HEX, WALLET_EXT_IN_MSG_LT, WALLET_EXT_IN_MSG_UTIME, WALLET_SHARD_ACCOUNT_BOC_HEX,
+    };
+    use crate::emulators::emul_bc_config::EmulBCConfig;
+    use crate::emulators::tx::tx_emul_args::{TXEmulArgs, TXEmulOrdArgs};
+    use crate::emulators::tx::tx_emulator::TXEmulator;
+    use crate::sys_utils::sys_tonlib_set_verbosity_level;
+    use ton_lib_core::cell::TonHash;
+
+    #[test]
+    fn test_tx_dry_run_report() -> anyhow::Result<()> {
+        sys_tonlib_set_verbosity_level(0);
+        let bc_config =
+            EmulBCConfig::from_boc_hex(include_str!("../../../../resources/tests/bc_config_key_block_42123611.hex"))?;
+        let shard_account = ShardAccount::from_boc_hex(WALLET_SHARD_ACCOUNT_BOC_HEX)?;
+        let ext_in_msg = Msg::from_boc_hex(WALLET_EXT_IN_MSG_BOC_HEX)?;
+        let mut args = TXEmulOrdArgs {
+            in_msg_boc: ext_in_msg.to_boc()?,
+            emul_args: TXEmulArgs {
+                shard_account_boc: shard_account.to_boc()?,
+                bc_config,
+                rand_seed: TonHash::ZERO,
+                utime: WALLET_EXT_IN_MSG_UTIME,
+                lt: WALLET_EXT_IN_MSG_LT,
+                ignore_chksig: false,
+                prev_blocks_boc: None,
+                libs_boc: None,
+            },
+        };
+        let mut emulator = TXEmulator::new(0, false)?;
+
+        let rejected = TXDryRunReport::from_response(&shard_account, emulator.emulate_ord_response(&args)?)?;
+        assert!(!rejected.accepted);
+        assert!(!rejected.success());
+        assert!(rejected.tx.is_none());
+        assert_eq!(rejected.balance_change(), 0);
+
+        args.emul_args.ignore_chksig = true;
+        let report = TXDryRunReport::from_response(&shard_account, emulator.emulate_ord_response(&args)?)?;
+        assert!(report.accepted);
+        assert!(report.success());
+        assert_eq!(report.exit_code, Some(0));
+        assert_eq!(report.out_msgs().len(), 1);
+        assert_eq!(report.out_actions.actions.len(), 1);
+        assert!(report.total_fees() > 0);
+        assert!(report.balance_change() < 0);
+        Ok(())
+    }
+}
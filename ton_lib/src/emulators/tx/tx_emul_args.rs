@@ -1,5 +1,6 @@
 use crate::emulators::emul_bc_config::EmulBCConfig;
 use std::fmt::{Display, Formatter};
+use std::sync::Arc;
 use ton_lib_core::cell::TonHash;
 
 #[derive(Debug, Clone)]
@@ -22,7 +23,7 @@ pub struct TXEmulArgs {
     pub lt: u64,
     pub ignore_chksig: bool,
     pub prev_blocks_boc: Option<Vec<u8>>,
-    pub libs_boc: Option<Vec<u8>>,
+    pub libs_boc: Option<Arc<Vec<u8>>>, // shared, so cached libs aren't copied for each call
 }
 
 impl Display for TXEmulArgs {
@@ -36,7 +37,7 @@ impl Display for TXEmulArgs {
 
         let libs_str = match &self.libs_boc {
             None => "None",
-            Some(boc) => &hex::encode(boc),
+            Some(boc) => &hex::encode(boc.as_slice()),
         };
 
         f.write_fmt(format_args!(
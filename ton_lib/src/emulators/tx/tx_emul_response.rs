@@ -1,5 +1,6 @@
 use crate::block_tlb::{ShardAccount, Tx};
 use crate::emulators::emul_utils::require_field;
+use crate::emulators::emul_vm_trace::VMTrace;
 use crate::error::TLError;
 use base64::prelude::BASE64_STANDARD;
 use base64_serde::base64_serde_type;
@@ -69,4 +70,5 @@ impl TXEmulationSuccess {
         Ok(ShardAccount::from_boc_b64(&self.shard_account_boc_b64)?)
     }
     pub fn tx_parsed(&self) -> Result<Tx, TLCoreError> { Tx::from_boc_b64(&self.tx_boc_b64) }
+    pub fn vm_trace(&self) -> VMTrace { VMTrace::parse(&self.vm_log) }
 }
@@ -0,0 +1,182 @@
+use crate::block_tlb::{Block, BlockIdExt, ShardAccount, Tx, TxDescr};
+use crate::clients::lite_client::client::LiteClient;
+use crate::emulators::emul_bc_config::EmulBCConfig;
+use crate::emulators::emul_prev_blocks::EmulPrevBlocks;
+use crate::emulators::tx::tx_emul_args::{TXEmulArgs, TXEmulOrdArgs, TXEmulTickTockArgs};
+use crate::emulators::tx::tx_emul_response::TXEmulationSuccess;
+use crate::emulators::tx::tx_emulator::TXEmulator;
+use crate::error::TLError;
+use std::ops::Deref;
+use std::sync::Arc;
+use ton_lib_core::cell::{TonCell, TonCellRef, TonCellUtils, TonHash};
+use ton_lib_core::constants::TON_MASTERCHAIN;
+use ton_lib_core::traits::tlb::TLB;
+use ton_lib_core::types::{TonAddress, TxIdLTHash};
+
+const LOAD_TXS_BATCH: u32 = 16;
+
+/// Result of historical tx re-execution
+#[derive(Debug, Clone)]
+pub struct TXReplayResult {
+    pub expected_tx: Tx,
+    pub expected_hash: TonHash,
+    pub emulated_tx: Tx,
+    pub emulated_hash: TonHash,
+    /// available if replayer is created with debug enabled
+    pub vm_log: String,
+}
+
+impl TXReplayResult {
+    pub fn hash_matched(&self) -> bool { self.expected_hash == self.emulated_hash }
+    pub fn state_update_matched(&self) -> bool { self.expected_tx.state_update == self.emulated_tx.state_update }
+}
+
+/// Re-executes historical txs with the same environment they were executed on-chain
+///
+/// Account state is taken from the end of the block with previous account tx,
+/// all preceding account txs in the same block are replayed first.
+pub struct TXReplayer {
+    client: LiteClient,
+    debug_enabled: bool,
+}
+
+struct BlockTxs {
+    block_id: BlockIdExt,
+    /// account txs in the block in execution order, the target tx goes last
+    txs: Vec<(Tx, TonCellRef)>,
+    /// (block_id, tx_id) of the previous account tx, None if it's the first account tx
+    prev_tx: Option<(BlockIdExt, TxIdLTHash)>,
+}
+
+impl BlockTxs {
+    // txs are loaded in reverse order
+    fn new(block_id: BlockIdExt, mut txs: Vec<(Tx, TonCellRef)>, prev_tx: Option<(BlockIdExt, TxIdLTHash)>) -> Self {
+        txs.reverse();
+        Self { block_id, txs, prev_tx }
+    }
+}
+
+impl TXReplayer {
+    pub fn new(client: LiteClient) -> Self {
+        Self {
+            client,
+            debug_enabled: false,
+        }
+    }
+
+    pub fn with_debug_enabled(mut self, debug_enabled: bool) -> Self {
+        self.debug_enabled = debug_enabled;
+        self
+    }
+
+    pub async fn replay_tx(&self, address: &TonAddress, tx_id: &TxIdLTHash) -> Result<TXReplayResult, TLError> {
+        let block_txs = self.load_block_txs(address, tx_id).await?;
+        let block = Block::from_boc(&self.client.get_block(block_txs.block_id.clone(), None).await?.data)?;
+        // masterchain block is collated on top of previous masterchain state
+        let mc_seqno = match &block.info.master_ref {
+            Some(master_ref) => master_ref.seqno,
+            None if block_txs.block_id.shard_ident.workchain == TON_MASTERCHAIN => block.info.seqno - 1,
+            None => return Err(TLError::Custom(format!("no master_ref in block {:?}", block_txs.block_id))),
+        };
+        let config = self.client.get_config(mc_seqno, None).await?;
+        let prev_blocks =
+            EmulPrevBlocks::load(&self.client, mc_seqno, block.info.prev_key_block_seqno, &config).await?;
+
+        let mut shard_account = match &block_txs.prev_tx {
+            None => ShardAccount::NON_EXIST,
+            Some((prev_block_id, prev_tx_id)) => ShardAccount {
+                account: self.client.get_account_state_by_block(address, prev_block_id, None).await?,
+                last_tx_hash: prev_tx_id.hash.clone(),
+                last_tx_lt: prev_tx_id.lt as u64,
+            },
+        };
+
+        let mut emulator = TXEmulator::new(0, self.debug_enabled)?;
+        let mut emul_args = TXEmulArgs {
+            shard_account_boc: vec![],
+            bc_config: EmulBCConfig::from_config_params(&config)?,
+            rand_seed: block.extra.rand_seed.clone(),
+            utime: 0,
+            lt: 0,
+            ignore_chksig: false,
+            prev_blocks_boc: Some(prev_blocks.to_boc()?),
+            libs_boc: self.load_libs_boc(&shard_account).await?,
+        };
+
+        let txs_cnt = block_txs.txs.len();
+        for (pos, (expected_tx, expected_cell)) in block_txs.txs.into_iter().enumerate() {
+            emul_args.shard_account_boc = shard_account.to_boc()?;
+            emul_args.utime = expected_tx.now;
+            emul_args.lt = expected_tx.lt;
+            let response = emulate_tx(&mut emulator, &expected_tx, &emul_args)?;
+            if pos + 1 < txs_cnt {
+                shard_account = response.shard_account_parsed()?;
+                continue;
+            }
+            return Ok(TXReplayResult {
+                emulated_tx: response.tx_parsed()?,
+                emulated_hash: TonCell::from_boc_b64(&response.tx_boc_b64)?.hash()?.clone(),
+                expected_hash: expected_cell.hash()?.clone(),
+                expected_tx,
+                vm_log: response.vm_log,
+            });
+        }
+        Err(TLError::Custom(format!("tx {tx_id:?} not found for address {address}")))
+    }
+
+    async fn load_block_txs(&self, address: &TonAddress, tx_id: &TxIdLTHash) -> Result<BlockTxs, TLError> {
+        let mut block_id = None;
+        let mut txs = vec![];
+        let mut from_tx = tx_id.clone();
+        loop {
+            let loaded = self.client.get_txs(address, &from_tx, LOAD_TXS_BATCH, None).await?;
+            if loaded.is_empty() {
+                return Err(TLError::Custom(format!("tx {from_tx:?} not found for address {address}")));
+            }
+            for (tx_block_id, tx_cell) in loaded {
+                let tx = Tx::from_cell(&tx_cell)?;
+                match &block_id {
+                    None => block_id = Some(tx_block_id.clone()),
+                    Some(cur_block_id) if cur_block_id != &tx_block_id => {
+                        let prev_tx_id = TxIdLTHash::new(tx.lt as i64, tx_cell.hash()?.clone());
+                        return Ok(BlockTxs::new(cur_block_id.clone(), txs, Some((tx_block_id, prev_tx_id))));
+                    }
+                    Some(_) => {}
+                }
+                from_tx = TxIdLTHash::new(tx.prev_tx_lt as i64, tx.prev_tx_hash.clone());
+                txs.push((tx, tx_cell));
+                if from_tx.lt == 0 {
+                    return Ok(BlockTxs::new(tx_block_id, txs, None));
+                }
+            }
+        }
+    }
+
+    async fn load_libs_boc(&self, shard_account: &ShardAccount) -> Result<Option<Arc<Vec<u8>>>, TLError> {
+        let cells = [shard_account.account.get_code(), shard_account.account.get_data()];
+        let lib_ids = TonCellUtils::extract_lib_ids(cells.into_iter().flatten().map(|x| x.deref()))?;
+        if lib_ids.is_empty() {
+            return Ok(None);
+        }
+        let lib_ids = lib_ids.into_iter().collect::<Vec<_>>();
+        Ok(Some(Arc::new(self.client.get_libs(&lib_ids, None).await?.to_boc()?)))
+    }
+}
+
+fn emulate_tx(emulator: &mut TXEmulator, tx: &Tx, emul_args: &TXEmulArgs) -> Result<TXEmulationSuccess, TLError> {
+    if let TxDescr::TickTock(descr) = &tx.descr {
+        let args = TXEmulTickTockArgs {
+            is_tock: descr.is_tock,
+            emul_args: emul_args.clone(),
+        };
+        return emulator.emulate_ticktock(&args);
+    }
+    let Some(in_msg) = &tx.msgs.in_msg else {
+        return Err(TLError::Custom(format!("tx {} has no in_msg", tx.lt)));
+    };
+    let args = TXEmulOrdArgs {
+        in_msg_boc: in_msg.to_boc()?,
+        emul_args: emul_args.clone(),
+    };
+    emulator.emulate_ord(&args)
+}
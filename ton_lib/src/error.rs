@@ -3,6 +3,7 @@ use std::sync::Arc;
 use std::time::Duration;
 use thiserror::Error;
 use tokio::time::error::Elapsed;
+use ton_lib_core::cell::TonHash;
 use ton_lib_core::error::TLCoreError;
 use ton_lib_core::types::{TonAddress, TxIdLTHash};
 use ton_liteapi::tl::request::Request;
@@ -52,6 +53,12 @@ pub enum TLError {
     #[error("TLWrongUsage: {0}")]
     TLWrongUsage(String),
 
+    // TonCenter
+    #[error("TonCenterHttpError: status: {status}, body: {body}")]
+    TonCenterHttpError { status: u16, body: String },
+    #[error("TonCenterApiError: code: {code:?}, message: {message}")]
+    TonCenterApiError { code: Option<i32>, message: String },
+
     // Emulators
     #[error("TVMEmulatorCreationFailed: emulator_create returns null")]
     EmulatorCreationFailed,
@@ -66,6 +73,14 @@ pub enum TLError {
         vm_exit_code: Option<i32>,
         response_raw: String,
     },
+    #[error("EmulatorPoolClosed: emulator pool workers are stopped")]
+    EmulatorPoolClosed,
+
+    // Sandbox
+    #[error("SandboxTxLimitExceeded: processed {0} txs, but message queue is not empty")]
+    SandboxTxLimitExceeded(usize),
+    #[error("SandboxWrongMsg: {0}")]
+    SandboxWrongMsg(String),
 
     // TVMStack
     #[error("TVMStackError: fail to pop specified type. expected: {0}, got: {1}")]
@@ -82,6 +97,36 @@ pub enum TLError {
     MnemonicFirstByte(u8),
     #[error("MnemonicFirstBytePassless: first byte can't be {0}")]
     MnemonicFirstBytePassless(u8),
+    #[error("MnemonicBip39WordsCount: expected 12 or 24 words, got {0}")]
+    MnemonicBip39WordsCount(usize),
+    #[error("MnemonicBip39Checksum: checksum mismatch")]
+    MnemonicBip39Checksum,
+
+    // Keystore
+    #[error("KeystoreUnsupportedVersion: version {0} is not supported")]
+    KeystoreUnsupportedVersion(u32),
+    #[error("KeystoreEntryNotFound: no entry with name {0}")]
+    KeystoreEntryNotFound(String),
+    #[error("KeystoreEntryExists: entry with name {0} already exists")]
+    KeystoreEntryExists(String),
+    #[error("KeystoreDecryptFailed: wrong password or corrupted entry {0}")]
+    KeystoreDecryptFailed(String),
+
+    // Signer
+    #[error("SignerError: {0}")]
+    SignerError(String),
+    #[error("SignatureInvalid: signature doesn't match hash {0}")]
+    SignatureInvalid(TonHash),
+
+    // EncryptedComment
+    #[error("DecryptionFailed: {0}")]
+    DecryptionFailed(String),
+
+    // TonConnect
+    #[error("TonConnectWrongDomain: domain {0} is not allowed")]
+    TonConnectWrongDomain(String),
+    #[error("TonConnectExpired: timestamp {timestamp} is expired or in future, now: {now}")]
+    TonConnectExpired { timestamp: u64, now: u64 },
 
     // General errors
     #[error("UnexpectedValue: expected: {expected}, actual: {actual}")]
@@ -121,9 +166,13 @@ pub enum TLError {
     #[error("{0}")]
     SerdeJson(#[from] serde_json::Error),
     #[error("{0}")]
+    Reqwest(#[from] reqwest::Error),
+    #[error("{0}")]
     RecvError(#[from] tokio::sync::oneshot::error::RecvError),
     #[error("{0}")]
     AcquireError(#[from] tokio::sync::AcquireError),
+    #[error("{0}")]
+    JoinError(#[from] tokio::task::JoinError),
 }
 
 impl From<TLError> for TLCoreError {
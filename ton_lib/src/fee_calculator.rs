@@ -0,0 +1,163 @@
+use crate::block_tlb::{ConfigParams, GasLimitsPrices, MsgForwardPrices, StorageInfo, StoragePrices};
+use crate::error::TLError;
+use std::ops::Deref;
+use std::sync::Arc;
+use ton_lib_core::cell::{TonCell, TonCellUtils};
+
+/// Offline fees estimation based on ConfigParams (18, 20/21, 24/25)
+///
+/// All values are in nanotons
+/// https://docs.ton.org/v3/documentation/smart-contracts/transaction-fees/fees-low-level
+#[derive(Debug, Clone)]
+pub struct FeeCalculator {
+    is_masterchain: bool,
+    storage_prices: Vec<StoragePrices>, // sorted by utime_since
+    gas_prices: Arc<GasLimitsPrices>,
+    fwd_prices: Arc<MsgForwardPrices>,
+}
+
+impl FeeCalculator {
+    pub fn new(config: &ConfigParams, workchain: i32) -> Result<Self, TLError> {
+        let is_masterchain = workchain == -1;
+        let mut storage_prices: Vec<_> = config.storage_prices()?.storage_prices.values().cloned().collect();
+        storage_prices.sort_by_key(|prices| prices.utime_since);
+        let (gas_prices, fwd_prices) = match is_masterchain {
+            true => (config.gas_prices_mc()?, config.fwd_prices_mc()?),
+            false => (config.gas_prices()?, config.fwd_prices()?),
+        };
+        Ok(Self {
+            is_masterchain,
+            storage_prices,
+            gas_prices,
+            fwd_prices,
+        })
+    }
+
+    pub fn is_masterchain(&self) -> bool { self.is_masterchain }
+    pub fn gas_prices(&self) -> &GasLimitsPrices { &self.gas_prices }
+    pub fn fwd_prices(&self) -> &MsgForwardPrices { &self.fwd_prices }
+
+    pub fn gas_fee(&self, gas_used: u64) -> u128 {
+        let (flat_gas_limit, flat_gas_price) = self.gas_prices.flat_gas();
+        if gas_used <= flat_gas_limit {
+            return flat_gas_price as u128;
+        }
+        let gas_price = self.gas_prices.gas_price() as u128;
+        flat_gas_price as u128 + shr16_ceil(gas_price * (gas_used - flat_gas_limit) as u128)
+    }
+
+    /// cells & bits must not include the root cell of message
+    pub fn fwd_fee(&self, cells: u64, bits: u64) -> u128 {
+        let prices = &self.fwd_prices;
+        let bits_price = prices.bit_price as u128 * bits as u128;
+        let cells_price = prices.cell_price as u128 * cells as u128;
+        prices.lump_price as u128 + shr16_ceil(bits_price + cells_price)
+    }
+
+    /// Full forward fee for message. Root cell is not counted, as it's done by validators
+    pub fn msg_fwd_fee(&self, msg: &TonCell) -> Result<u128, TLError> {
+        let (cells, bits) = TonCellUtils::storage_stat(msg.refs.iter().map(Deref::deref))?;
+        Ok(self.fwd_fee(cells, bits))
+    }
+
+    /// Part of fwd_fee which is collected by validators on action phase (the rest is deducted from msg value)
+    pub fn fwd_fee_action_part(&self, fwd_fee: u128) -> u128 { (fwd_fee * self.fwd_prices.first_frac as u128) >> 16 }
+
+    /// Storage fee for `cells` & `bits` stored from `since` till `until` (unix time)
+    pub fn storage_fee(&self, cells: u64, bits: u64, since: u32, until: u32) -> u128 {
+        let Some(first) = self.storage_prices.first() else {
+            return 0;
+        };
+        let mut total = 0u128;
+        let mut from = since.max(first.utime_since);
+        for (pos, prices) in self.storage_prices.iter().enumerate() {
+            let valid_until = match self.storage_prices.get(pos + 1) {
+                Some(next) => next.utime_since.min(until),
+                None => until,
+            };
+            if from >= valid_until {
+                continue;
+            }
+            let (bit_price, cell_price) = match self.is_masterchain {
+                true => (prices.mc_bit_price_ps, prices.mc_cell_price_ps),
+                false => (prices.bit_price_ps, prices.cell_price_ps),
+            };
+            let payment = bits as u128 * bit_price as u128 + cells as u128 * cell_price as u128;
+            total += payment * (valid_until - from) as u128;
+            from = valid_until;
+        }
+        shr16_ceil(total)
+    }
+
+    /// Storage fee accumulated by account since `last_paid` till `now`
+    pub fn account_storage_fee(&self, storage_info: &StorageInfo, now: u32) -> u128 {
+        let used = &storage_info.used;
+        self.storage_fee(*used.cells.deref(), *used.bits.deref(), storage_info.last_paid, now)
+    }
+}
+
+fn shr16_ceil(value: u128) -> u128 { (value + 0xffff) >> 16 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block_tlb::_test_block_data::CONFIG_BOC_HEX;
+    use crate::block_tlb::{MaybeStorageExtraInfo, StorageExtraInfoNone, StorageUsed};
+    use ton_lib_core::traits::tlb::TLB;
+    use ton_lib_core::types::tlb_core::VarLenBytes;
+
+    #[test]
+    fn test_fee_calculator_basechain() -> anyhow::Result<()> {
+        let config = ConfigParams::from_boc_hex(CONFIG_BOC_HEX)?;
+        let calc = FeeCalculator::new(&config, 0)?;
+        assert!(!calc.is_masterchain());
+
+        assert_eq!(calc.gas_fee(0), 40000);
+        assert_eq!(calc.gas_fee(100), 40000);
+        assert_eq!(calc.gas_fee(2994), 40000 + 400 * 2894);
+
+        assert_eq!(calc.fwd_fee(0, 0), 400000);
+        assert_eq!(calc.fwd_fee(1, 100), 480000);
+
+        let mut builder = TonCell::builder();
+        builder.write_num(&0u128, 100)?;
+        let body = builder.build()?.into_ref();
+        let mut builder = TonCell::builder();
+        builder.write_bits([0u8; 75], 600)?;
+        builder.write_ref(body.clone())?;
+        builder.write_ref(body)?;
+        let msg_fwd_fee = calc.msg_fwd_fee(&builder.build()?)?;
+        assert_eq!(msg_fwd_fee, 480000);
+        assert_eq!(calc.fwd_fee_action_part(msg_fwd_fee), 159997);
+
+        // (1000 * 1 + 1 * 500) * 86400 / 2^16, rounded up
+        assert_eq!(calc.storage_fee(1, 1000, 0, 86400), 1978);
+        assert_eq!(calc.storage_fee(1, 1000, 86400, 86400), 0);
+
+        let storage_info = StorageInfo {
+            used: StorageUsed {
+                cells: VarLenBytes::new(1u32, 8),
+                bits: VarLenBytes::new(1000u32, 16),
+            },
+            storage_extra: MaybeStorageExtraInfo::None(StorageExtraInfoNone),
+            last_paid: 1000,
+            due_payment: None,
+        };
+        assert_eq!(calc.account_storage_fee(&storage_info, 87400), 1978);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fee_calculator_masterchain() -> anyhow::Result<()> {
+        let config = ConfigParams::from_boc_hex(CONFIG_BOC_HEX)?;
+        let calc = FeeCalculator::new(&config, -1)?;
+        assert!(calc.is_masterchain());
+
+        assert_eq!(calc.gas_fee(100), 1000000);
+        assert_eq!(calc.gas_fee(1100), 1000000 + 10000 * 1000);
+        assert_eq!(calc.fwd_fee(1, 100), 10000000 + 1000000 + 1000000);
+        // (1000 * 1000 + 1 * 500000) * 65536 / 2^16
+        assert_eq!(calc.storage_fee(1, 1000, 0, 65536), 1500000);
+        Ok(())
+    }
+}
@@ -2,10 +2,12 @@ pub use ton_lib_core; // re-export
 pub mod block_tlb;
 pub mod clients;
 pub mod error;
+pub mod fee_calculator;
 pub mod libs_dict;
 pub mod meta_loader;
 pub mod tep;
 pub mod tlb_adapters;
+pub mod ton_connect;
 pub mod tvm_results;
 pub mod wallet;
 
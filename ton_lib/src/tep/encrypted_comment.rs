@@ -0,0 +1,154 @@
+use crate::error::TLError;
+use crate::tep::snake_data::SnakeDataNoPrefix;
+use crate::wallet::KeyPair;
+use aes::cipher::block_padding::NoPadding;
+use aes::cipher::{BlockDecryptMut, BlockEncryptMut, KeyIvInit};
+use curve25519_dalek::edwards::CompressedEdwardsY;
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use sha2::{Digest, Sha512};
+use ton_lib_core::cell::TonHash;
+use ton_lib_core::types::TonAddress;
+use ton_lib_core::TLBDerive;
+use zeroize::Zeroizing;
+
+type Aes256CbcEnc = cbc::Encryptor<aes::Aes256>;
+type Aes256CbcDec = cbc::Decryptor<aes::Aes256>;
+
+///```raw
+/// encrypted_comment#2167da4b pub_xor:bits256 msg_key:bits128 ciphertext:SnakeData = InternalMsgBody;
+/// ```
+/// https://docs.ton.org/v3/documentation/smart-contracts/message-management/internal-messages#messages-with-encrypted-comments
+#[derive(Clone, Debug, TLBDerive, PartialEq)]
+#[tlb_derive(prefix = 0x2167da4b, bits_len = 32)]
+pub struct EncryptedCommentMsg {
+    /// pub_xor ++ msg_key ++ ciphertext
+    pub data: SnakeDataNoPrefix,
+}
+
+impl EncryptedCommentMsg {
+    /// `sender_address` is the address of sender's wallet, it's used as a salt
+    pub fn encrypt(
+        comment: &str,
+        sender_key_pair: &KeyPair,
+        receiver_public_key: &TonHash,
+        sender_address: &TonAddress,
+    ) -> Result<Self, TLError> {
+        // prefix: 16..31 random bytes, first byte is prefix length
+        let prefix_len = 16 + (16 - comment.len() % 16) % 16;
+        let mut data = Zeroizing::new(vec![0u8; prefix_len]);
+        rand::rng().fill_bytes(&mut data[1..]);
+        data[0] = prefix_len as u8;
+        data.extend_from_slice(comment.as_bytes());
+
+        let salt = sender_address.to_base64(true, true, true);
+        let msg_key = hmac_sha512(salt.as_bytes(), &data)?;
+        let msg_key = &msg_key[..16];
+
+        let shared_secret = shared_secret(sender_key_pair, receiver_public_key)?;
+        let x = hmac_sha512(shared_secret.as_slice(), msg_key)?;
+        let mut ciphertext = data.to_vec();
+        let data_len = ciphertext.len();
+        Aes256CbcEnc::new_from_slices(&x[..32], &x[32..48])
+            .map_err(|err| TLError::Custom(format!("EncryptedComment: {err}")))?
+            .encrypt_padded_mut::<NoPadding>(&mut ciphertext, data_len)
+            .map_err(|err| TLError::Custom(format!("EncryptedComment: {err}")))?;
+
+        let sender_public_key = TonHash::from_slice(&sender_key_pair.public_key)?;
+        let mut result = xor_keys(&sender_public_key, receiver_public_key).to_vec();
+        result.extend_from_slice(msg_key);
+        result.extend(ciphertext);
+        Ok(Self {
+            data: SnakeDataNoPrefix::new(result),
+        })
+    }
+
+    /// Works for both sender and receiver: the other party's key is restored from pub_xor
+    pub fn decrypt(&self, key_pair: &KeyPair, sender_address: &TonAddress) -> Result<String, TLError> {
+        let data = self.data.as_slice();
+        if data.len() < 48 + 16 || (data.len() - 48) % 16 != 0 {
+            return Err(TLError::DecryptionFailed(format!("unexpected data length: {}", data.len())));
+        }
+        let (pub_xor, msg_key, ciphertext) = (&data[..32], &data[32..48], &data[48..]);
+        let own_public_key = TonHash::from_slice(&key_pair.public_key)?;
+        let other_public_key = xor_keys(&TonHash::from_slice(pub_xor)?, &own_public_key);
+
+        let shared_secret = shared_secret(key_pair, &TonHash::from(other_public_key))?;
+        let x = hmac_sha512(shared_secret.as_slice(), msg_key)?;
+        let mut decrypted = Zeroizing::new(ciphertext.to_vec());
+        Aes256CbcDec::new_from_slices(&x[..32], &x[32..48])
+            .map_err(|err| TLError::DecryptionFailed(err.to_string()))?
+            .decrypt_padded_mut::<NoPadding>(&mut decrypted)
+            .map_err(|err| TLError::DecryptionFailed(err.to_string()))?;
+
+        let salt = sender_address.to_base64(true, true, true);
+        if &hmac_sha512(salt.as_bytes(), &decrypted)?[..16] != msg_key {
+            return Err(TLError::DecryptionFailed("msg_key mismatch".to_string()));
+        }
+        let prefix_len = decrypted[0] as usize;
+        if !(16..32).contains(&prefix_len) || prefix_len > decrypted.len() {
+            return Err(TLError::DecryptionFailed(format!("unexpected prefix length: {prefix_len}")));
+        }
+        Ok(String::from_utf8(decrypted[prefix_len..].to_vec())?)
+    }
+}
+
+// x25519 over keys converted from ed25519
+fn shared_secret(key_pair: &KeyPair, other_public_key: &TonHash) -> Result<Zeroizing<[u8; 32]>, TLError> {
+    let seed = key_pair.secret_key().get(..32).ok_or_else(|| TLError::Custom("secret_key is too short".to_string()))?;
+    let hash = Zeroizing::new(Sha512::digest(seed).to_vec());
+    let mut scalar = Zeroizing::new([0u8; 32]);
+    scalar.copy_from_slice(&hash[..32]);
+
+    let other_point = CompressedEdwardsY::from_slice(other_public_key.as_slice())
+        .ok()
+        .and_then(|point| point.decompress())
+        .ok_or_else(|| TLError::Custom(format!("invalid ed25519 public key: {other_public_key}")))?;
+    Ok(Zeroizing::new(other_point.to_montgomery().mul_clamped(*scalar).to_bytes()))
+}
+
+fn hmac_sha512(key: &[u8], data: &[u8]) -> Result<Vec<u8>, TLError> {
+    let mut mac = Hmac::<Sha512>::new_from_slice(key)?;
+    mac.update(data);
+    Ok(mac.finalize().into_bytes().to_vec())
+}
+
+fn xor_keys(a: &TonHash, b: &TonHash) -> [u8; 32] {
+    let mut result = [0u8; 32];
+    for (i, byte) in result.iter_mut().enumerate() {
+        *byte = a.as_slice()[i] ^ b.as_slice()[i];
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::{Mnemonic, TonWallet, WalletVersion};
+    use ton_lib_core::traits::tlb::TLB;
+
+    const MNEMONIC_STR: &str = "fancy carpet hello mandate penalty trial consider property top vicious exit rebuild tragic profit urban major total month holiday sudden rib gather media vicious";
+    const MNEMONIC_STR_V5: &str = "section garden tomato dinner season dice renew length useful spin trade intact use universe what post spike keen mandate behind concert egg doll rug";
+
+    #[test]
+    fn test_encrypted_comment() -> anyhow::Result<()> {
+        let sender_kp = Mnemonic::from_str(MNEMONIC_STR, None)?.to_key_pair()?;
+        let receiver_kp = Mnemonic::from_str(MNEMONIC_STR_V5, None)?.to_key_pair()?;
+        let sender = TonWallet::new(WalletVersion::V4R2, sender_kp.clone())?;
+        let receiver = TonWallet::new(WalletVersion::V5R1, receiver_kp.clone())?;
+
+        let long_comment = "encrypted ".repeat(40);
+        for comment in ["", "hello", "exactly16bytes!!", long_comment.as_str()] {
+            let msg = EncryptedCommentMsg::encrypt(comment, &sender_kp, &receiver.public_key, &sender.address)?;
+            let cell = msg.to_cell()?;
+            assert_eq!(cell.parser().read_num::<u32>(32)?, 0x2167da4b);
+
+            let parsed = EncryptedCommentMsg::from_cell(&cell)?;
+            assert_eq!((parsed.data.as_slice().len() - 48) % 16, 0);
+            assert_eq!(parsed.decrypt(&receiver_kp, &sender.address)?, comment);
+            assert_eq!(parsed.decrypt(&sender_kp, &sender.address)?, comment);
+            assert!(matches!(parsed.decrypt(&receiver_kp, &receiver.address), Err(TLError::DecryptionFailed(_))));
+        }
+        Ok(())
+    }
+}
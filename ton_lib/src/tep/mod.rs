@@ -2,6 +2,8 @@ pub mod jetton;
 pub mod nft;
 pub mod sbt;
 
+pub mod encrypted_comment;
 pub mod metadata;
 pub mod mintless_airdrop;
 pub mod snake_data;
+pub mod text_comment;
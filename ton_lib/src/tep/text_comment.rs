@@ -0,0 +1,39 @@
+use crate::tep::snake_data::SnakeDataNoPrefix;
+use std::borrow::Cow;
+use ton_lib_core::TLBDerive;
+
+///```raw
+/// text_comment#00000000 text:SnakeData = InternalMsgBody;
+/// ```
+#[derive(Clone, Debug, TLBDerive, PartialEq)]
+#[tlb_derive(prefix = 0x00000000, bits_len = 32)]
+pub struct TextCommentMsg {
+    pub text: SnakeDataNoPrefix,
+}
+
+impl TextCommentMsg {
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: SnakeDataNoPrefix::new(text.as_bytes().to_vec()),
+        }
+    }
+
+    pub fn as_str(&self) -> Cow<'_, str> { self.text.as_str() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ton_lib_core::traits::tlb::TLB;
+
+    #[test]
+    fn test_text_comment_msg() -> anyhow::Result<()> {
+        let long_text = "long comment ".repeat(30);
+        for text in ["", "hello", long_text.as_str()] {
+            let cell = TextCommentMsg::new(text).to_cell()?;
+            assert_eq!(cell.parser().read_num::<u32>(32)?, 0);
+            assert_eq!(TextCommentMsg::from_cell(&cell)?.as_str(), text);
+        }
+        Ok(())
+    }
+}
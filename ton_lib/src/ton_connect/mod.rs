@@ -0,0 +1,7 @@
+mod sign_data;
+mod ton_proof;
+mod wallet_public_key;
+
+pub use sign_data::*;
+pub use ton_proof::*;
+pub use wallet_public_key::*;
@@ -0,0 +1,132 @@
+use crate::error::TLError;
+use crate::tep::snake_data::SnakeDataNoPrefix;
+use crate::wallet::{verify_signature, Signer};
+use crc::{Crc, CRC_32_ISO_HDLC};
+use sha2::{Digest, Sha256};
+use ton_lib_core::cell::{TonCell, TonCellRef, TonHash};
+use ton_lib_core::traits::tlb::TLB;
+use ton_lib_core::types::TonAddress;
+
+pub const SIGN_DATA_PREFIX: &str = "ton-connect/sign-data/";
+pub const SIGN_DATA_CELL_PREFIX: u32 = 0x75569022;
+const CRC_32: Crc<u32> = Crc::<u32>::new(&CRC_32_ISO_HDLC);
+
+/// https://github.com/ton-blockchain/ton-connect/blob/main/requests-responses.md#sign-data
+#[derive(Debug, Clone, PartialEq)]
+pub enum SignDataPayload {
+    Text(String),
+    Binary(Vec<u8>),
+    Cell { schema: String, cell: TonCellRef },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignData {
+    pub address: TonAddress,
+    pub domain: String,
+    pub timestamp: u64,
+    pub payload: SignDataPayload,
+}
+
+impl SignData {
+    pub fn new(address: TonAddress, domain: String, timestamp: u64, payload: SignDataPayload) -> Self {
+        Self {
+            address,
+            domain,
+            timestamp,
+            payload,
+        }
+    }
+
+    pub fn signing_hash(&self) -> Result<TonHash, TLError> {
+        let (type_prefix, data) = match &self.payload {
+            SignDataPayload::Text(text) => (b"txt", text.as_bytes()),
+            SignDataPayload::Binary(bytes) => (b"bin", bytes.as_slice()),
+            SignDataPayload::Cell { schema, cell } => return self.cell_signing_hash(schema, cell),
+        };
+        let mut message = Sha256::new();
+        message.update([0xff, 0xff]);
+        message.update(SIGN_DATA_PREFIX.as_bytes());
+        message.update(self.address.workchain.to_be_bytes());
+        message.update(self.address.hash.as_slice());
+        message.update((self.domain.len() as u32).to_be_bytes());
+        message.update(self.domain.as_bytes());
+        message.update(self.timestamp.to_be_bytes());
+        message.update(type_prefix);
+        message.update((data.len() as u32).to_be_bytes());
+        message.update(data);
+        Ok(TonHash::from_slice(&message.finalize())?)
+    }
+
+    pub fn sign<S: Signer>(&self, signer: &S) -> Result<Vec<u8>, TLError> { signer.sign(&self.signing_hash()?) }
+
+    pub fn verify(&self, signature: &[u8], public_key: &TonHash) -> Result<(), TLError> {
+        verify_signature(&self.signing_hash()?, signature, public_key)
+    }
+
+    // message = 0x75569022 crc32(schema) timestamp:uint64 address:MsgAddress domain:^Cell payload:^Cell
+    fn cell_signing_hash(&self, schema: &str, cell: &TonCellRef) -> Result<TonHash, TLError> {
+        let mut builder = TonCell::builder();
+        builder.write_num(&SIGN_DATA_CELL_PREFIX, 32)?;
+        builder.write_num(&CRC_32.checksum(schema.as_bytes()), 32)?;
+        builder.write_num(&self.timestamp, 64)?;
+        self.address.to_msg_address_int().write(&mut builder)?;
+        builder.write_ref(SnakeDataNoPrefix::new(encode_domain_dns_like(&self.domain)).to_cell_ref()?)?;
+        builder.write_ref(cell.clone())?;
+        Ok(builder.build()?.cell_hash()?)
+    }
+}
+
+// "ton.org" -> "org\0ton\0"
+fn encode_domain_dns_like(domain: &str) -> Vec<u8> {
+    let mut result = Vec::with_capacity(domain.len() + 1);
+    for part in domain.split('.').rev() {
+        result.extend_from_slice(part.as_bytes());
+        result.push(0);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::{Mnemonic, TonWallet, WalletVersion};
+
+    const MNEMONIC_STR: &str = "fancy carpet hello mandate penalty trial consider property top vicious exit rebuild tragic profit urban major total month holiday sudden rib gather media vicious";
+
+    #[test]
+    fn test_sign_data() -> anyhow::Result<()> {
+        let key_pair = Mnemonic::from_str(MNEMONIC_STR, None)?.to_key_pair()?;
+        let wallet = TonWallet::new(WalletVersion::V5R1, key_pair.clone())?;
+
+        let mut builder = TonCell::builder();
+        builder.write_num(&42u32, 32)?;
+        let payloads = [
+            SignDataPayload::Text("hello".to_string()),
+            SignDataPayload::Binary(vec![1, 2, 3]),
+            SignDataPayload::Cell {
+                schema: "message#_ value:uint32 = Message;".to_string(),
+                cell: builder.build_ref()?,
+            },
+        ];
+        let mut hashes = vec![];
+        for payload in payloads {
+            let sign_data = SignData::new(wallet.address.clone(), "ton.org".to_string(), 1700000000, payload);
+            let signature = sign_data.sign(&key_pair)?;
+            sign_data.verify(&signature, &wallet.public_key)?;
+
+            let mut tampered = sign_data.clone();
+            tampered.timestamp += 1;
+            assert!(matches!(tampered.verify(&signature, &wallet.public_key), Err(TLError::SignatureInvalid(_))));
+            hashes.push(sign_data.signing_hash()?);
+        }
+        assert_ne!(hashes[0], hashes[1]);
+        assert_ne!(hashes[1], hashes[2]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_domain_dns_like() {
+        assert_eq!(encode_domain_dns_like("ton.org"), b"org\0ton\0");
+        assert_eq!(encode_domain_dns_like("localhost"), b"localhost\0");
+    }
+}
@@ -0,0 +1,141 @@
+use crate::block_tlb::StateInit;
+use crate::error::TLError;
+use crate::ton_connect::wallet_public_key_from_state_init;
+use crate::wallet::{verify_signature, Signer};
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use ton_lib_core::cell::TonHash;
+use ton_lib_core::types::TonAddress;
+
+pub const TON_PROOF_PREFIX: &str = "ton-proof-item-v2/";
+pub const TON_CONNECT_PREFIX: &str = "ton-connect";
+
+/// https://docs.ton.org/v3/guidelines/ton-connect/verifying-signed-in-users
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TonProofDomain {
+    pub length_bytes: u32,
+    pub value: String,
+}
+
+/// `proof` field of `ton_proof` item reply. Signature is base64-encoded
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TonProof {
+    pub timestamp: u64,
+    pub domain: TonProofDomain,
+    pub payload: String,
+    pub signature: String,
+}
+
+impl TonProof {
+    pub fn create<S: Signer>(
+        signer: &S,
+        address: &TonAddress,
+        domain: &str,
+        timestamp: u64,
+        payload: &str,
+    ) -> Result<Self, TLError> {
+        let mut proof = Self {
+            timestamp,
+            domain: TonProofDomain {
+                length_bytes: domain.len() as u32,
+                value: domain.to_string(),
+            },
+            payload: payload.to_string(),
+            signature: String::new(),
+        };
+        let signature = signer.sign(&proof.signing_hash(address)?)?;
+        proof.signature = BASE64_STANDARD.encode(signature);
+        Ok(proof)
+    }
+
+    /// sha256(0xffff ++ "ton-connect" ++ sha256(message))
+    pub fn signing_hash(&self, address: &TonAddress) -> Result<TonHash, TLError> {
+        if self.domain.length_bytes as usize != self.domain.value.len() {
+            return Err(TLError::UnexpectedValue {
+                expected: format!("domain.length_bytes: {}", self.domain.value.len()),
+                actual: self.domain.length_bytes.to_string(),
+            });
+        }
+        let mut message = Sha256::new();
+        message.update(TON_PROOF_PREFIX.as_bytes());
+        message.update(address.workchain.to_be_bytes());
+        message.update(address.hash.as_slice());
+        message.update(self.domain.length_bytes.to_le_bytes());
+        message.update(self.domain.value.as_bytes());
+        message.update(self.timestamp.to_le_bytes());
+        message.update(self.payload.as_bytes());
+
+        let mut full_message = Sha256::new();
+        full_message.update([0xff, 0xff]);
+        full_message.update(TON_CONNECT_PREFIX.as_bytes());
+        full_message.update(message.finalize());
+        Ok(TonHash::from_slice(&full_message.finalize())?)
+    }
+
+    pub fn verify(&self, address: &TonAddress, public_key: &TonHash) -> Result<(), TLError> {
+        let signature = BASE64_STANDARD.decode(&self.signature)?;
+        verify_signature(&self.signing_hash(address)?, &signature, public_key)
+    }
+
+    /// Takes public key from `walletStateInit` of the reply. Returns the key on success
+    pub fn verify_with_state_init(&self, address: &TonAddress, state_init: &StateInit) -> Result<TonHash, TLError> {
+        let public_key = wallet_public_key_from_state_init(address, state_init)?;
+        self.verify(address, &public_key)?;
+        Ok(public_key)
+    }
+
+    /// Signature doesn't protect from replays - backend must check domain and proof age as well
+    pub fn check_domain_and_timestamp(&self, allowed_domains: &[&str], now: u64, ttl_sec: u64) -> Result<(), TLError> {
+        if !allowed_domains.contains(&self.domain.value.as_str()) {
+            return Err(TLError::TonConnectWrongDomain(self.domain.value.clone()));
+        }
+        if self.timestamp > now || now - self.timestamp > ttl_sec {
+            return Err(TLError::TonConnectExpired {
+                timestamp: self.timestamp,
+                now,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::{Mnemonic, TonWallet, WalletVersion};
+
+    const MNEMONIC_STR: &str = "fancy carpet hello mandate penalty trial consider property top vicious exit rebuild tragic profit urban major total month holiday sudden rib gather media vicious";
+
+    #[test]
+    fn test_ton_proof() -> anyhow::Result<()> {
+        let key_pair = Mnemonic::from_str(MNEMONIC_STR, None)?.to_key_pair()?;
+        let wallet = TonWallet::new(WalletVersion::V4R2, key_pair.clone())?;
+
+        let proof = TonProof::create(&key_pair, &wallet.address, "ton.org", 1700000000, "nonce")?;
+        let json = serde_json::to_string(&proof)?;
+        assert!(json.contains(r#""domain":{"lengthBytes":7,"value":"ton.org"}"#));
+        let proof: TonProof = serde_json::from_str(&json)?;
+
+        proof.verify(&wallet.address, &wallet.public_key)?;
+        proof.check_domain_and_timestamp(&["ton.org"], 1700000100, 900)?;
+        assert!(matches!(
+            proof.check_domain_and_timestamp(&["evil.org"], 1700000100, 900),
+            Err(TLError::TonConnectWrongDomain(_))
+        ));
+        assert!(matches!(
+            proof.check_domain_and_timestamp(&["ton.org"], 1700001000, 900),
+            Err(TLError::TonConnectExpired { .. })
+        ));
+
+        let mut tampered = proof.clone();
+        tampered.payload = "other_nonce".to_string();
+        assert!(matches!(tampered.verify(&wallet.address, &wallet.public_key), Err(TLError::SignatureInvalid(_))));
+
+        let other_address = TonAddress::new(-1, wallet.address.hash.clone());
+        assert!(proof.verify(&other_address, &wallet.public_key).is_err());
+        Ok(())
+    }
+}
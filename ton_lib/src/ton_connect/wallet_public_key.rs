@@ -0,0 +1,64 @@
+use crate::block_tlb::StateInit;
+use crate::error::TLError;
+use crate::wallet::WalletVersion;
+use ton_lib_core::cell::TonHash;
+use ton_lib_core::traits::tlb::TLB;
+use ton_lib_core::types::TonAddress;
+
+#[cfg(feature = "tonlibjson")]
+use crate::contracts::{
+    client::contract_client::ContractClient, ton_contract::TonContract, ton_wallet::TonWalletContract,
+};
+
+/// Offline extraction: StateInit must match the address and contain known wallet code
+pub fn wallet_public_key_from_state_init(address: &TonAddress, state_init: &StateInit) -> Result<TonHash, TLError> {
+    let derived = state_init.derive_address(address.workchain)?;
+    if &derived != address {
+        return Err(TLError::UnexpectedValue {
+            expected: format!("state_init for address {address}"),
+            actual: format!("state_init for address {derived}"),
+        });
+    }
+    let (Some(code), Some(data)) = (&state_init.code, &state_init.data) else {
+        return Err(TLError::Custom(format!("state_init for {address} has no code or data")));
+    };
+    let version = WalletVersion::version_by_code(code.cell_hash()?)?;
+    Ok(WalletVersion::get_public_key(version, data)?)
+}
+
+/// Uses StateInit if provided, otherwise calls `get_public_key` of deployed wallet
+#[cfg(feature = "tonlibjson")]
+pub async fn resolve_wallet_public_key(
+    client: &ContractClient,
+    address: &TonAddress,
+    state_init: Option<&StateInit>,
+) -> Result<TonHash, TLError> {
+    if let Some(state_init) = state_init {
+        return wallet_public_key_from_state_init(address, state_init);
+    }
+    TonWalletContract::new(client, address.clone(), None).await?.get_public_key().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::{Mnemonic, TonWallet};
+
+    const MNEMONIC_STR: &str = "fancy carpet hello mandate penalty trial consider property top vicious exit rebuild tragic profit urban major total month holiday sudden rib gather media vicious";
+
+    #[test]
+    fn test_wallet_public_key_from_state_init() -> anyhow::Result<()> {
+        let key_pair = Mnemonic::from_str(MNEMONIC_STR, None)?.to_key_pair()?;
+        for version in [WalletVersion::V3R2, WalletVersion::V4R2, WalletVersion::V5R1] {
+            let wallet = TonWallet::new(version, key_pair.clone())?;
+            let code = WalletVersion::get_code(version)?.clone();
+            let data = WalletVersion::get_default_data(version, &key_pair, wallet.wallet_id)?;
+            let state_init = StateInit::new(code, data);
+            assert_eq!(wallet_public_key_from_state_init(&wallet.address, &state_init)?, wallet.public_key);
+
+            let other_address = TonAddress::new(0, TonHash::ZERO);
+            assert!(wallet_public_key_from_state_init(&other_address, &state_init).is_err());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,336 @@
+use crate::error::TLError;
+use crate::wallet::{KeyPair, Mnemonic, Signer};
+use aes_gcm::aead::{Aead, Payload};
+use aes_gcm::{Aes256Gcm, KeyInit, Nonce};
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use pbkdf2::pbkdf2_hmac;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::Sha512;
+use std::fs;
+use std::path::{Path, PathBuf};
+use ton_lib_core::cell::TonHash;
+use zeroize::{Zeroize, Zeroizing};
+
+pub const KEYSTORE_VERSION: u32 = 1;
+pub const KEYSTORE_DEFAULT_KDF_ITERATIONS: u32 = 100000;
+const SALT_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+
+/// Password-protected storage of wallet keys
+///
+/// Each entry is encrypted by AES-256-GCM with key derived from password by PBKDF2-HMAC-SHA512.
+/// File is json, every change is persisted immediately.
+pub struct Keystore {
+    path: PathBuf,
+    kdf_iterations: u32,
+    entries: Vec<KeystoreEntry>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeystoreEntryKind {
+    KeyPair,
+    Mnemonic,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KeystoreEntryInfo {
+    pub name: String,
+    pub kind: KeystoreEntryKind,
+    pub public_key: TonHash,
+}
+
+#[derive(Serialize, Deserialize)]
+struct KeystoreFile {
+    version: u32,
+    entries: Vec<KeystoreEntry>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct KeystoreEntry {
+    name: String,
+    kind: KeystoreEntryKind,
+    public_key: String,
+    kdf_iterations: u32,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct MnemonicSecret {
+    words: String,
+    password: Option<String>,
+}
+
+impl Drop for MnemonicSecret {
+    fn drop(&mut self) {
+        self.words.zeroize();
+        self.password.zeroize();
+    }
+}
+
+impl Keystore {
+    /// Creates empty keystore if file doesn't exist. File is not created until first change.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, TLError> {
+        let path = path.as_ref().to_path_buf();
+        let entries = match path.exists() {
+            true => {
+                let file: KeystoreFile = serde_json::from_slice(&fs::read(&path)?)?;
+                if file.version != KEYSTORE_VERSION {
+                    return Err(TLError::KeystoreUnsupportedVersion(file.version));
+                }
+                file.entries
+            }
+            false => vec![],
+        };
+        Ok(Self {
+            path,
+            kdf_iterations: KEYSTORE_DEFAULT_KDF_ITERATIONS,
+            entries,
+        })
+    }
+
+    /// Applies to new entries only
+    pub fn with_kdf_iterations(mut self, kdf_iterations: u32) -> Self {
+        self.kdf_iterations = kdf_iterations;
+        self
+    }
+
+    pub fn path(&self) -> &Path { &self.path }
+
+    pub fn list(&self) -> Result<Vec<KeystoreEntryInfo>, TLError> {
+        let mut result = Vec::with_capacity(self.entries.len());
+        for entry in &self.entries {
+            result.push(KeystoreEntryInfo {
+                name: entry.name.clone(),
+                kind: entry.kind,
+                public_key: TonHash::from_vec(hex::decode(&entry.public_key)?)?,
+            });
+        }
+        Ok(result)
+    }
+
+    pub fn add_key_pair(&mut self, name: &str, key_pair: &KeyPair, password: &str) -> Result<(), TLError> {
+        let public_key = key_pair.public_key()?;
+        self.add_entry(name, KeystoreEntryKind::KeyPair, public_key, key_pair.secret_key(), password)
+    }
+
+    pub fn add_mnemonic(&mut self, name: &str, mnemonic: &Mnemonic, password: &str) -> Result<(), TLError> {
+        let public_key = mnemonic.to_key_pair()?.public_key()?;
+        let secret = MnemonicSecret {
+            words: mnemonic.words().join(" "),
+            password: mnemonic.password().map(String::from),
+        };
+        let plaintext = Zeroizing::new(serde_json::to_vec(&secret)?);
+        self.add_entry(name, KeystoreEntryKind::Mnemonic, public_key, &plaintext, password)
+    }
+
+    pub fn remove(&mut self, name: &str) -> Result<(), TLError> {
+        let position = self.position(name)?;
+        let entry = self.entries.remove(position);
+        if let Err(err) = self.save() {
+            self.entries.insert(position, entry);
+            return Err(err);
+        }
+        Ok(())
+    }
+
+    pub fn unlock(&self, name: &str, password: &str) -> Result<KeyPair, TLError> {
+        let entry = &self.entries[self.position(name)?];
+        let salt = BASE64_STANDARD.decode(&entry.salt)?;
+        let nonce = BASE64_STANDARD.decode(&entry.nonce)?;
+        let ciphertext = BASE64_STANDARD.decode(&entry.ciphertext)?;
+        if nonce.len() != NONCE_LEN {
+            return Err(TLError::KeystoreDecryptFailed(entry.name.clone()));
+        }
+
+        let cipher = make_cipher(password, &salt, entry.kdf_iterations)?;
+        let aad = entry_aad(&entry.name, entry.kind, &entry.public_key);
+        let payload = Payload {
+            msg: &ciphertext,
+            aad: aad.as_bytes(),
+        };
+        let plaintext = Zeroizing::new(
+            cipher
+                .decrypt(Nonce::from_slice(&nonce), payload)
+                .map_err(|_| TLError::KeystoreDecryptFailed(entry.name.clone()))?,
+        );
+
+        let key_pair = match entry.kind {
+            KeystoreEntryKind::KeyPair => {
+                KeyPair::new(plaintext.get(32..).unwrap_or_default().to_vec(), plaintext.to_vec())
+            }
+            KeystoreEntryKind::Mnemonic => {
+                let secret: MnemonicSecret = serde_json::from_slice(&plaintext)?;
+                Mnemonic::from_str(&secret.words, secret.password.clone())?.to_key_pair()?
+            }
+        };
+        if hex::encode(&key_pair.public_key) != entry.public_key {
+            return Err(TLError::KeystoreDecryptFailed(entry.name.clone()));
+        }
+        Ok(key_pair)
+    }
+
+    fn add_entry(
+        &mut self,
+        name: &str,
+        kind: KeystoreEntryKind,
+        public_key: TonHash,
+        plaintext: &[u8],
+        password: &str,
+    ) -> Result<(), TLError> {
+        if self.entries.iter().any(|e| e.name == name) {
+            return Err(TLError::KeystoreEntryExists(name.to_string()));
+        }
+        let mut rng = rand::rng();
+        let mut salt = [0u8; SALT_LEN];
+        let mut nonce = [0u8; NONCE_LEN];
+        rng.fill_bytes(&mut salt);
+        rng.fill_bytes(&mut nonce);
+
+        let public_key = public_key.to_hex();
+        let cipher = make_cipher(password, &salt, self.kdf_iterations)?;
+        let aad = entry_aad(name, kind, &public_key);
+        let payload = Payload {
+            msg: plaintext,
+            aad: aad.as_bytes(),
+        };
+        let ciphertext = cipher
+            .encrypt(Nonce::from_slice(&nonce), payload)
+            .map_err(|err| TLError::Custom(format!("Keystore encryption failed: {err}")))?;
+
+        self.entries.push(KeystoreEntry {
+            name: name.to_string(),
+            kind,
+            public_key,
+            kdf_iterations: self.kdf_iterations,
+            salt: BASE64_STANDARD.encode(salt),
+            nonce: BASE64_STANDARD.encode(nonce),
+            ciphertext: BASE64_STANDARD.encode(ciphertext),
+        });
+        // keep memory in sync with disk
+        if let Err(err) = self.save() {
+            self.entries.pop();
+            return Err(err);
+        }
+        Ok(())
+    }
+
+    fn position(&self, name: &str) -> Result<usize, TLError> {
+        self.entries
+            .iter()
+            .position(|e| e.name == name)
+            .ok_or_else(|| TLError::KeystoreEntryNotFound(name.to_string()))
+    }
+
+    // write to tmp file & rename to avoid corrupted keystore on crash
+    fn save(&self) -> Result<(), TLError> {
+        let file = KeystoreFile {
+            version: KEYSTORE_VERSION,
+            entries: self.entries.clone(),
+        };
+        if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        let tmp_path = self.path.with_extension("tmp");
+        fs::write(&tmp_path, serde_json::to_vec_pretty(&file)?)?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&tmp_path, fs::Permissions::from_mode(0o600))?;
+        }
+        fs::rename(&tmp_path, &self.path)?;
+        Ok(())
+    }
+}
+
+fn make_cipher(password: &str, salt: &[u8], iterations: u32) -> Result<Aes256Gcm, TLError> {
+    let mut key = Zeroizing::new([0u8; 32]);
+    pbkdf2_hmac::<Sha512>(password.as_bytes(), salt, iterations, key.as_mut());
+    Ok(Aes256Gcm::new_from_slice(key.as_ref())?)
+}
+
+// binds ciphertext to entry metadata
+fn entry_aad(name: &str, kind: KeystoreEntryKind, public_key: &str) -> String {
+    format!("ton_lib_keystore:v{KEYSTORE_VERSION}:{kind:?}:{public_key}:{name}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::{TonWallet, WalletVersion};
+
+    const MNEMONIC_STR: &str = "fancy carpet hello mandate penalty trial consider property top vicious exit rebuild tragic profit urban major total month holiday sudden rib gather media vicious";
+
+    fn make_path() -> PathBuf {
+        std::env::temp_dir().join(format!("ton_lib_keystore_test_{}.json", rand::random::<u64>()))
+    }
+
+    #[test]
+    fn test_keystore_key_pair() -> anyhow::Result<()> {
+        let path = make_path();
+        let key_pair = Mnemonic::from_str(MNEMONIC_STR, None)?.to_key_pair()?;
+
+        let mut keystore = Keystore::open(&path)?.with_kdf_iterations(16);
+        keystore.add_key_pair("main", &key_pair, "pass")?;
+        assert!(matches!(keystore.add_key_pair("main", &key_pair, "pass"), Err(TLError::KeystoreEntryExists(_))));
+
+        let keystore = Keystore::open(&path)?;
+        let expected_info = KeystoreEntryInfo {
+            name: "main".to_string(),
+            kind: KeystoreEntryKind::KeyPair,
+            public_key: key_pair.public_key()?,
+        };
+        assert_eq!(keystore.list()?, vec![expected_info]);
+        assert_eq!(keystore.unlock("main", "pass")?, key_pair);
+        let wallet = TonWallet::new_with_keystore(WalletVersion::V4R2, &keystore, "main", "pass")?;
+        assert_eq!(wallet.address, TonWallet::new(WalletVersion::V4R2, key_pair.clone())?.address);
+        assert!(matches!(keystore.unlock("main", "wrong"), Err(TLError::KeystoreDecryptFailed(_))));
+        assert!(matches!(keystore.unlock("unknown", "pass"), Err(TLError::KeystoreEntryNotFound(_))));
+        assert!(!fs::read_to_string(&path)?.contains(&hex::encode(key_pair.secret_key())));
+        fs::remove_file(&path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_keystore_mnemonic() -> anyhow::Result<()> {
+        let path = make_path();
+        let mnemonic = Mnemonic::from_str(MNEMONIC_STR, None)?;
+
+        let mut keystore = Keystore::open(&path)?.with_kdf_iterations(16);
+        keystore.add_mnemonic("mnemonic", &mnemonic, "pass")?;
+        assert_eq!(keystore.list()?[0].kind, KeystoreEntryKind::Mnemonic);
+        assert_eq!(Keystore::open(&path)?.unlock("mnemonic", "pass")?, mnemonic.to_key_pair()?);
+
+        keystore.remove("mnemonic")?;
+        assert!(Keystore::open(&path)?.list()?.is_empty());
+        assert!(matches!(keystore.remove("mnemonic"), Err(TLError::KeystoreEntryNotFound(_))));
+        fs::remove_file(&path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_keystore_save_failed() -> anyhow::Result<()> {
+        let parent_file = make_path();
+        fs::write(&parent_file, "")?;
+        let key_pair = Mnemonic::from_str(MNEMONIC_STR, None)?.to_key_pair()?;
+
+        let mut keystore = Keystore::open(parent_file.join("keystore.json"))?.with_kdf_iterations(16);
+        assert!(keystore.add_key_pair("main", &key_pair, "pass").is_err());
+        assert!(keystore.list()?.is_empty());
+        fs::remove_file(&parent_file)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_keystore_version() -> anyhow::Result<()> {
+        let path = make_path();
+        fs::write(&path, r#"{"version": 999, "entries": []}"#)?;
+        assert!(matches!(Keystore::open(&path), Err(TLError::KeystoreUnsupportedVersion(999))));
+        fs::remove_file(&path)?;
+        Ok(())
+    }
+}
@@ -1,17 +1,24 @@
 use crate::error::TLError;
+use crate::wallet::Bip39Mnemonic;
 use hmac::{Hmac, Mac};
 use nacl::sign::generate_keypair;
 use pbkdf2::password_hash::Output;
 use pbkdf2::{pbkdf2_hmac, Params};
+use rand::Rng;
 use sha2::Sha512;
 use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
 use std::sync::LazyLock;
 use std::{cmp, fmt};
+use zeroize::{Zeroize, Zeroizing};
 
 const WORDLIST_EN: &str = include_str!("../../resources/mnemonics/wordlist_en.txt");
 const PBKDF_ITERATIONS: u32 = 100000;
 
 pub static WORDLIST_EN_SET: LazyLock<HashSet<&'static str>> =
+    LazyLock::new(|| WORDLIST_EN_LIST.iter().copied().collect());
+/// Ordered list - index of the word is meaningful for BIP39
+pub static WORDLIST_EN_LIST: LazyLock<Vec<&'static str>> =
     LazyLock::new(|| WORDLIST_EN.split('\n').filter(|w| !w.is_empty()).collect());
 
 pub struct Mnemonic {
@@ -19,10 +26,17 @@ pub struct Mnemonic {
     password: Option<String>,
 }
 
-#[derive(PartialEq, Eq, Clone, Hash)]
+#[derive(PartialEq, Eq, Clone)]
 pub struct KeyPair {
     pub public_key: Vec<u8>,
-    pub secret_key: Vec<u8>,
+    secret_key: Zeroizing<Vec<u8>>,
+}
+
+impl Hash for KeyPair {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.public_key.hash(state);
+        self.secret_key.as_slice().hash(state);
+    }
 }
 
 impl fmt::Debug for KeyPair {
@@ -34,7 +48,52 @@ impl fmt::Debug for KeyPair {
     }
 }
 
+impl Drop for Mnemonic {
+    fn drop(&mut self) {
+        self.words.zeroize();
+        self.password.zeroize();
+    }
+}
+
+impl KeyPair {
+    pub fn new(public_key: Vec<u8>, secret_key: Vec<u8>) -> Self {
+        Self {
+            public_key,
+            secret_key: Zeroizing::new(secret_key),
+        }
+    }
+
+    pub fn secret_key(&self) -> &[u8] { &self.secret_key }
+
+    /// Accepts both TON (24 words) and BIP39 (12/24 words) mnemonics.
+    ///
+    /// TON derivation has priority, BIP39 keys are derived at `m/44'/607'/0'` (Tonkeeper compatible)
+    pub fn from_phrase(phrase: &str, password: Option<String>) -> Result<KeyPair, TLError> {
+        match Mnemonic::from_str(phrase, password.clone()) {
+            Ok(mnemonic) => mnemonic.to_key_pair(),
+            Err(ton_err) => match Bip39Mnemonic::from_str(phrase, password) {
+                Ok(mnemonic) => mnemonic.to_key_pair(0),
+                Err(_) => Err(ton_err),
+            },
+        }
+    }
+}
+
 impl Mnemonic {
+    /// Generates new 24 words mnemonic, which passes all checks of [`Mnemonic::new`]
+    pub fn generate(password: Option<String>) -> Result<Mnemonic, TLError> {
+        let mut rng = rand::rng();
+        loop {
+            let words: Vec<&str> =
+                (0..24).map(|_| WORDLIST_EN_LIST[rng.random_range(0..WORDLIST_EN_LIST.len())]).collect();
+            match Mnemonic::new(words, password.clone()) {
+                Ok(mnemonic) => return Ok(mnemonic),
+                Err(TLError::MnemonicFirstByte(_) | TLError::MnemonicFirstBytePassless(_)) => continue,
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
     pub fn new(words: Vec<&str>, password: Option<String>) -> Result<Mnemonic, TLError> {
         let normalized_words: Vec<String> = words.iter().map(|w| w.trim().to_lowercase()).collect();
 
@@ -83,14 +142,15 @@ impl Mnemonic {
         Mnemonic::new(words, password)
     }
 
+    pub fn words(&self) -> &[String] { &self.words }
+
+    pub(super) fn password(&self) -> Option<&str> { self.password.as_deref() }
+
     pub fn to_key_pair(&self) -> Result<KeyPair, TLError> {
         let entropy = to_entropy(&self.words, self.password.as_ref())?;
         let seed = pbkdf2_sha512(entropy, "TON default seed", PBKDF_ITERATIONS, 64)?;
         let key_pair = generate_keypair(&seed.as_slice()[0..32]);
-        Ok(KeyPair {
-            public_key: key_pair.pkey.to_vec(),
-            secret_key: key_pair.skey.to_vec(),
-        })
+        Ok(KeyPair::new(key_pair.pkey.to_vec(), key_pair.skey.to_vec()))
     }
 }
 
@@ -104,7 +164,12 @@ fn to_entropy(words: &[String], password: Option<&String>) -> Result<Vec<u8>, TL
     Ok(code_bytes)
 }
 
-fn pbkdf2_sha512(key: Vec<u8>, salt: &str, rounds: u32, output_len_bytes: usize) -> Result<Vec<u8>, TLError> {
+pub(super) fn pbkdf2_sha512(
+    key: Vec<u8>,
+    salt: &str,
+    rounds: u32,
+    output_len_bytes: usize,
+) -> Result<Vec<u8>, TLError> {
     let params = Params {
         rounds,
         output_length: output_len_bytes,
@@ -172,4 +237,31 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn mnemonic_generate_works() -> anyhow::Result<()> {
+        let mnemonic = Mnemonic::generate(None)?;
+        assert_eq!(mnemonic.words().len(), 24);
+        let restored = Mnemonic::from_str(&mnemonic.words().join(" "), None)?;
+        assert_eq!(restored.to_key_pair()?, mnemonic.to_key_pair()?);
+
+        let password = Some("secret".to_string());
+        let mnemonic = Mnemonic::generate(password.clone())?;
+        assert!(Mnemonic::from_str(&mnemonic.words().join(" "), password).is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn key_pair_from_phrase_works() -> anyhow::Result<()> {
+        let ton_phrase = "dose ice enrich trigger test dove century still betray gas diet dune use other base gym mad law immense village world example praise game";
+        let expected = Mnemonic::from_str(ton_phrase, None)?.to_key_pair()?;
+        assert_eq!(KeyPair::from_phrase(ton_phrase, None)?, expected);
+
+        let bip39_phrase =
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let expected = Bip39Mnemonic::from_str(bip39_phrase, None)?.to_key_pair(0)?;
+        assert_eq!(KeyPair::from_phrase(bip39_phrase, None)?, expected);
+        assert!(KeyPair::from_phrase("abandon about", None).is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,153 @@
+use crate::error::TLError;
+use crate::wallet::mnemonic::pbkdf2_sha512;
+use crate::wallet::{KeyPair, WORDLIST_EN_LIST};
+use hmac::{Hmac, Mac};
+use nacl::sign::generate_keypair;
+use sha2::{Digest, Sha256, Sha512};
+
+const BIP39_PBKDF_ITERATIONS: u32 = 2048;
+const SLIP10_ED25519_CURVE: &[u8] = b"ed25519 seed";
+const HARDENED_OFFSET: u32 = 0x80000000;
+const TON_COIN_TYPE: u32 = 607;
+
+/// BIP39 mnemonic (12 or 24 words), used by Tonkeeper & other multi-chain wallets
+///
+/// Keys are derived by SLIP-0010 (ed25519) at `m/44'/607'/{account}'`
+pub struct Bip39Mnemonic {
+    words: Vec<String>,
+    passphrase: Option<String>,
+}
+
+impl Bip39Mnemonic {
+    pub fn new(words: Vec<&str>, passphrase: Option<String>) -> Result<Self, TLError> {
+        let normalized_words: Vec<String> = words.iter().map(|w| w.trim().to_lowercase()).collect();
+        if normalized_words.len() != 12 && normalized_words.len() != 24 {
+            return Err(TLError::MnemonicBip39WordsCount(normalized_words.len()));
+        }
+
+        let mut bits = Vec::with_capacity(normalized_words.len() * 11);
+        for word in &normalized_words {
+            let Some(index) = WORDLIST_EN_LIST.iter().position(|w| *w == word) else {
+                return Err(TLError::MnemonicWord(word.clone()));
+            };
+            bits.extend((0..11).rev().map(|i| (index >> i) & 1 == 1));
+        }
+
+        let entropy_bits_len = bits.len() * 32 / 33;
+        let entropy: Vec<u8> = bits[..entropy_bits_len].chunks(8).map(bits_to_byte).collect();
+        let checksum_bits = &bits[entropy_bits_len..];
+        let hash = Sha256::digest(&entropy);
+        let expected_bits = (0..checksum_bits.len()).map(|i| (hash[i / 8] >> (7 - i % 8)) & 1 == 1);
+        if !expected_bits.eq(checksum_bits.iter().copied()) {
+            return Err(TLError::MnemonicBip39Checksum);
+        }
+
+        Ok(Self {
+            words: normalized_words,
+            passphrase,
+        })
+    }
+
+    pub fn from_str(s: &str, passphrase: Option<String>) -> Result<Self, TLError> {
+        let words: Vec<&str> = s.split(' ').map(|w| w.trim()).filter(|w| !w.is_empty()).collect();
+        Self::new(words, passphrase)
+    }
+
+    pub fn words(&self) -> &[String] { &self.words }
+
+    /// 64 bytes BIP39 seed
+    pub fn to_seed(&self) -> Result<Vec<u8>, TLError> {
+        let salt = format!("mnemonic{}", self.passphrase.as_deref().unwrap_or_default());
+        pbkdf2_sha512(self.words.join(" ").into_bytes(), &salt, BIP39_PBKDF_ITERATIONS, 64)
+    }
+
+    /// Derives key at `m/44'/607'/{account}'`
+    pub fn to_key_pair(&self, account: u32) -> Result<KeyPair, TLError> {
+        self.to_key_pair_by_path(&[44, TON_COIN_TYPE, account])
+    }
+
+    /// All indexes are hardened (ed25519 supports only hardened derivation)
+    pub fn to_key_pair_by_path(&self, path: &[u32]) -> Result<KeyPair, TLError> {
+        let private_key = slip10_derive_ed25519(&self.to_seed()?, path)?;
+        let key_pair = generate_keypair(&private_key);
+        Ok(KeyPair::new(key_pair.pkey.to_vec(), key_pair.skey.to_vec()))
+    }
+}
+
+fn slip10_derive_ed25519(seed: &[u8], path: &[u32]) -> Result<[u8; 32], TLError> {
+    let (mut key, mut chain_code) = hmac_sha512_split(SLIP10_ED25519_CURVE, &[seed])?;
+    for index in path {
+        if *index >= HARDENED_OFFSET {
+            return Err(TLError::UnexpectedValue {
+                expected: format!("index < {HARDENED_OFFSET}"),
+                actual: index.to_string(),
+            });
+        }
+        let index_bytes = (index | HARDENED_OFFSET).to_be_bytes();
+        (key, chain_code) = hmac_sha512_split(&chain_code, &[&[0], &key, &index_bytes])?;
+    }
+    Ok(key)
+}
+
+fn hmac_sha512_split(key: &[u8], data: &[&[u8]]) -> Result<([u8; 32], [u8; 32]), TLError> {
+    let mut mac = Hmac::<Sha512>::new_from_slice(key)?;
+    data.iter().for_each(|chunk| mac.update(chunk));
+    let result = mac.finalize().into_bytes();
+    let mut left = [0u8; 32];
+    let mut right = [0u8; 32];
+    left.copy_from_slice(&result[..32]);
+    right.copy_from_slice(&result[32..]);
+    Ok((left, right))
+}
+
+fn bits_to_byte(bits: &[bool]) -> u8 { bits.iter().fold(0, |acc, bit| (acc << 1) | *bit as u8) }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // https://github.com/trezor/python-mnemonic/blob/master/vectors.json
+    #[test]
+    fn test_bip39_seed() -> anyhow::Result<()> {
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let mnemonic = Bip39Mnemonic::from_str(phrase, Some("TREZOR".to_string()))?;
+        let expected = "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04";
+        assert_eq!(hex::encode(mnemonic.to_seed()?), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bip39_validation() -> anyhow::Result<()> {
+        let bad_checksum =
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon";
+        assert!(matches!(Bip39Mnemonic::from_str(bad_checksum, None), Err(TLError::MnemonicBip39Checksum)));
+        assert!(matches!(Bip39Mnemonic::from_str("abandon about", None), Err(TLError::MnemonicBip39WordsCount(2))));
+        let phrase_24 = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art";
+        assert!(Bip39Mnemonic::from_str(phrase_24, None).is_ok());
+        Ok(())
+    }
+
+    // https://github.com/satoshilabs/slips/blob/master/slip-0010.md#test-vector-1-for-ed25519
+    #[test]
+    fn test_slip10_ed25519() -> anyhow::Result<()> {
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f")?;
+        let master = slip10_derive_ed25519(&seed, &[])?;
+        assert_eq!(hex::encode(master), "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7");
+        let child = slip10_derive_ed25519(&seed, &[0])?;
+        assert_eq!(hex::encode(child), "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3");
+        let public_key = generate_keypair(&child).pkey;
+        assert_eq!(hex::encode(public_key), "8c8a13df77a28f3445213a0f432fde644acaa215fc72dcdf300d5efaa85d350c");
+        Ok(())
+    }
+
+    #[test]
+    fn test_bip39_key_pair_accounts() -> anyhow::Result<()> {
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let mnemonic = Bip39Mnemonic::from_str(phrase, None)?;
+        let kp0 = mnemonic.to_key_pair(0)?;
+        assert_eq!(kp0, mnemonic.to_key_pair_by_path(&[44, 607, 0])?);
+        assert_ne!(kp0, mnemonic.to_key_pair(1)?);
+        assert!(mnemonic.to_key_pair_by_path(&[HARDENED_OFFSET]).is_err());
+        Ok(())
+    }
+}
@@ -1,12 +1,20 @@
+mod keystore;
 mod mnemonic;
+mod mnemonic_bip39;
+mod signer;
 mod ton_wallet;
+mod transfer_builder;
 mod wallet_code;
 mod wallet_constants;
 mod wallet_tlb;
 mod wallet_version;
 
+pub use keystore::*;
 pub use mnemonic::*;
+pub use mnemonic_bip39::*;
+pub use signer::*;
 pub use ton_wallet::*;
+pub use transfer_builder::*;
 pub use wallet_code::*;
 pub use wallet_constants::*;
 pub use wallet_tlb::*;
@@ -0,0 +1,74 @@
+use crate::error::TLError;
+use crate::wallet::KeyPair;
+use async_trait::async_trait;
+use nacl::sign::signature;
+use std::sync::Arc;
+use ton_lib_core::cell::TonHash;
+
+/// Produces ed25519 signatures for wallet messages.
+///
+/// Implement it to keep secret keys outside the process memory (HSM, KMS, etc).
+pub trait Signer: Send + Sync {
+    fn public_key(&self) -> Result<TonHash, TLError>;
+    /// Returns 64 bytes signature of the hash
+    fn sign(&self, hash: &TonHash) -> Result<Vec<u8>, TLError>;
+}
+
+/// Async version of [`Signer`] for remote signing services.
+///
+/// Implemented for every [`Signer`] automatically.
+#[async_trait]
+pub trait AsyncSigner: Send + Sync {
+    async fn public_key_async(&self) -> Result<TonHash, TLError>;
+    /// Returns 64 bytes signature of the hash
+    async fn sign_async(&self, hash: &TonHash) -> Result<Vec<u8>, TLError>;
+}
+
+impl Signer for KeyPair {
+    fn public_key(&self) -> Result<TonHash, TLError> { Ok(TonHash::from_slice(&self.public_key)?) }
+
+    fn sign(&self, hash: &TonHash) -> Result<Vec<u8>, TLError> {
+        signature(hash.as_slice(), self.secret_key()).map_err(|err| TLError::SignerError(format!("{err:?}")))
+    }
+}
+
+impl<T: Signer + ?Sized> Signer for Arc<T> {
+    fn public_key(&self) -> Result<TonHash, TLError> { self.as_ref().public_key() }
+    fn sign(&self, hash: &TonHash) -> Result<Vec<u8>, TLError> { self.as_ref().sign(hash) }
+}
+
+#[async_trait]
+impl<T: Signer> AsyncSigner for T {
+    async fn public_key_async(&self) -> Result<TonHash, TLError> { self.public_key() }
+    async fn sign_async(&self, hash: &TonHash) -> Result<Vec<u8>, TLError> { self.sign(hash) }
+}
+
+/// Checks ed25519 signature of the hash
+pub fn verify_signature(hash: &TonHash, signature: &[u8], public_key: &TonHash) -> Result<(), TLError> {
+    match nacl::sign::verify(signature, hash.as_slice(), public_key.as_slice()) {
+        Ok(true) => Ok(()),
+        _ => Err(TLError::SignatureInvalid(hash.clone())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::Mnemonic;
+
+    const MNEMONIC_STR: &str = "fancy carpet hello mandate penalty trial consider property top vicious exit rebuild tragic profit urban major total month holiday sudden rib gather media vicious";
+
+    #[tokio::test]
+    async fn test_signer_key_pair() -> anyhow::Result<()> {
+        let key_pair = Mnemonic::from_str(MNEMONIC_STR, None)?.to_key_pair()?;
+        let hash = TonHash::from([7u8; 32]);
+
+        let sign = key_pair.sign(&hash)?;
+        assert_eq!(sign.len(), 64);
+        assert_eq!(key_pair.sign_async(&hash).await?, sign);
+        assert_eq!(key_pair.public_key_async().await?.as_slice(), key_pair.public_key.as_slice());
+
+        assert!(nacl::sign::verify(&sign, hash.as_slice(), &key_pair.public_key).unwrap());
+        Ok(())
+    }
+}
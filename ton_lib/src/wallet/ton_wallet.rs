@@ -1,51 +1,50 @@
 use super::*;
 use crate::block_tlb::*;
 use crate::error::TLError;
-use nacl::sign::signature;
-use ton_lib_core::cell::{TonCell, TonCellRef};
+use ton_lib_core::cell::{TonCell, TonCellRef, TonHash};
 use ton_lib_core::traits::tlb::TLB;
 use ton_lib_core::types::tlb_core::{MsgAddressExt, TLBEitherRef};
 use ton_lib_core::types::TonAddress;
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
-pub struct TonWallet {
+pub struct TonWallet<S = KeyPair> {
     pub version: WalletVersion,
-    pub key_pair: KeyPair,
+    /// [`KeyPair`] by default, any [`Signer`] or [`AsyncSigner`] can be used
+    pub signer: S,
+    pub public_key: TonHash,
     pub address: TonAddress,
     pub wallet_id: i32,
 }
 
-impl TonWallet {
-    pub fn new(version: WalletVersion, key_pair: KeyPair) -> Result<Self, TLError> {
-        let wallet_id = match version {
-            WalletVersion::V5R1 => WALLET_V5R1_DEFAULT_ID,
-            _ => WALLET_DEFAULT_ID,
-        };
-        Self::new_with_params(version, key_pair, 0, wallet_id)
-    }
+impl TonWallet<KeyPair> {
+    /// Replacement for removed `key_pair` field: `wallet.key_pair` -> `wallet.key_pair()`
+    pub fn key_pair(&self) -> &KeyPair { &self.signer }
 
     pub fn new_with_creds(version: WalletVersion, seed: &str, pass: Option<String>) -> Result<Self, TLError> {
         Self::new(version, Mnemonic::from_str(seed, pass)?.to_key_pair()?)
     }
 
-    pub fn new_with_params(
+    pub fn new_with_keystore(
         version: WalletVersion,
-        key_pair: KeyPair,
-        workchain: i32,
-        wallet_id: i32,
+        keystore: &Keystore,
+        name: &str,
+        password: &str,
     ) -> Result<Self, TLError> {
-        let code = WalletVersion::get_code(version)?.clone();
-        let data = WalletVersion::get_default_data(version, &key_pair, wallet_id)?;
-        let address = StateInit::new(code, data).derive_address(workchain)?;
+        Self::new(version, keystore.unlock(name, password)?)
+    }
+}
 
-        Ok(TonWallet {
-            key_pair,
-            version,
-            address,
-            wallet_id,
-        })
+impl<S: Signer> TonWallet<S> {
+    pub fn new(version: WalletVersion, signer: S) -> Result<Self, TLError> {
+        Self::new_with_params(version, signer, 0, default_wallet_id(version))
+    }
+
+    pub fn new_with_params(version: WalletVersion, signer: S, workchain: i32, wallet_id: i32) -> Result<Self, TLError> {
+        let public_key = signer.public_key()?;
+        Self::new_with_public_key(version, signer, public_key, workchain, wallet_id)
     }
 
+    /// Messages are sent with [`SendMode::DEFAULT`], use `create_ext_in_msg_with_transfers` to specify modes
     pub fn create_ext_in_msg(
         &self,
         int_msgs: Vec<TonCellRef>,
@@ -53,28 +52,130 @@ impl TonWallet {
         expire_at: u32,
         add_state_init: bool,
     ) -> Result<TonCell, TLError> {
-        let body = self.create_ext_in_body(expire_at, seqno, int_msgs)?;
+        let transfers = int_msgs.into_iter().map(WalletTransfer::from).collect();
+        self.create_ext_in_msg_with_transfers(transfers, seqno, expire_at, add_state_init)
+    }
+
+    pub fn create_ext_in_msg_with_transfers(
+        &self,
+        transfers: Vec<WalletTransfer>,
+        seqno: u32,
+        expire_at: u32,
+        add_state_init: bool,
+    ) -> Result<TonCell, TLError> {
+        let body = self.create_ext_in_body_with_transfers(expire_at, seqno, transfers)?;
         let signed = self.sign_ext_in_body(&body)?;
         let external = self.create_ext_in_msg_from_body(signed, add_state_init)?;
         Ok(external)
     }
 
+    pub fn sign_ext_in_body(&self, ext_in_body: &TonCell) -> Result<TonCell, TLError> {
+        let sign = self.signer.sign(&ext_in_body.cell_hash()?)?;
+        self.sign_ext_in_body_with(ext_in_body, &sign)
+    }
+}
+
+impl<S: AsyncSigner> TonWallet<S> {
+    pub async fn new_async(version: WalletVersion, signer: S) -> Result<Self, TLError> {
+        let public_key = signer.public_key_async().await?;
+        Self::new_with_public_key(version, signer, public_key, 0, default_wallet_id(version))
+    }
+
+    pub async fn create_ext_in_msg_async(
+        &self,
+        int_msgs: Vec<TonCellRef>,
+        seqno: u32,
+        expire_at: u32,
+        add_state_init: bool,
+    ) -> Result<TonCell, TLError> {
+        let transfers = int_msgs.into_iter().map(WalletTransfer::from).collect();
+        self.create_ext_in_msg_with_transfers_async(transfers, seqno, expire_at, add_state_init).await
+    }
+
+    pub async fn create_ext_in_msg_with_transfers_async(
+        &self,
+        transfers: Vec<WalletTransfer>,
+        seqno: u32,
+        expire_at: u32,
+        add_state_init: bool,
+    ) -> Result<TonCell, TLError> {
+        let body = self.create_ext_in_body_with_transfers(expire_at, seqno, transfers)?;
+        let signed = self.sign_ext_in_body_async(&body).await?;
+        self.create_ext_in_msg_from_body(signed, add_state_init)
+    }
+
+    pub async fn sign_ext_in_body_async(&self, ext_in_body: &TonCell) -> Result<TonCell, TLError> {
+        let sign = self.signer.sign_async(&ext_in_body.cell_hash()?).await?;
+        self.sign_ext_in_body_with(ext_in_body, &sign)
+    }
+}
+
+impl<S> TonWallet<S> {
+    /// Doesn't call signer at all - use it if public_key is known in advance
+    pub fn new_with_public_key(
+        version: WalletVersion,
+        signer: S,
+        public_key: TonHash,
+        workchain: i32,
+        wallet_id: i32,
+    ) -> Result<Self, TLError> {
+        let code = WalletVersion::get_code(version)?.clone();
+        let data = WalletVersion::get_default_data_for_pubkey(version, public_key.clone(), wallet_id)?;
+        let address = StateInit::new(code, data).derive_address(workchain)?;
+
+        Ok(TonWallet {
+            version,
+            signer,
+            public_key,
+            address,
+            wallet_id,
+        })
+    }
+
+    /// Messages are sent with [`SendMode::DEFAULT`]
     pub fn create_ext_in_body(
         &self,
         expire_at: u32,
         seqno: u32,
         int_msgs: Vec<TonCellRef>,
     ) -> Result<TonCell, TLError> {
-        WalletVersion::build_ext_in_body(self.version, expire_at, seqno, self.wallet_id, int_msgs)
+        let transfers = int_msgs.into_iter().map(WalletTransfer::from).collect();
+        self.create_ext_in_body_with_transfers(expire_at, seqno, transfers)
     }
 
-    pub fn sign_ext_in_body(&self, ext_in_body: &TonCell) -> Result<TonCell, TLError> {
-        let message_hash = ext_in_body.cell_hash()?;
-        let sign = match signature(message_hash.as_slice(), self.key_pair.secret_key.as_slice()) {
-            Ok(signature) => signature,
-            Err(err) => return Err(TLError::Custom(format!("{err:?}"))),
-        };
-        WalletVersion::sign_msg(self.version, ext_in_body, &sign)
+    pub fn create_ext_in_body_with_transfers(
+        &self,
+        expire_at: u32,
+        seqno: u32,
+        transfers: Vec<WalletTransfer>,
+    ) -> Result<TonCell, TLError> {
+        let (msgs, modes) = transfers.into_iter().map(|t| (t.msg, t.mode.bits())).unzip();
+        WalletVersion::build_ext_in_body_with_modes(self.version, expire_at, seqno, self.wallet_id, msgs, modes)
+    }
+
+    /// Puts externally produced signature of `ext_in_body.cell_hash()` to the body
+    ///
+    /// Flow for external signers: `create_ext_in_body` -> sign body hash -> `sign_ext_in_body_with` -> `create_ext_in_msg_from_body`
+    pub fn sign_ext_in_body_with(&self, ext_in_body: &TonCell, signature: &[u8]) -> Result<TonCell, TLError> {
+        if signature.len() != 64 {
+            return Err(TLError::UnexpectedValue {
+                expected: "signature of 64 bytes".to_string(),
+                actual: format!("{} bytes", signature.len()),
+            });
+        }
+        WalletVersion::sign_msg(self.version, ext_in_body, signature)
+    }
+
+    /// Checks that msg is addressed to this wallet and signed by public_key
+    pub fn verify_ext_in_msg(&self, msg_cell: &TonCell, public_key: &TonHash) -> Result<WalletExtMsgBody, TLError> {
+        let dst = Msg::from_cell(msg_cell)?.dst();
+        if dst != self.address.to_msg_address() {
+            return Err(TLError::UnexpectedValue {
+                expected: format!("dst: {}", self.address),
+                actual: format!("dst: {dst:?}"),
+            });
+        }
+        WalletVersion::verify_ext_in_msg(self.version, msg_cell, public_key)
     }
 
     pub fn create_ext_in_msg_from_body(&self, signed_body: TonCell, add_state_init: bool) -> Result<TonCell, TLError> {
@@ -87,7 +188,8 @@ impl TonWallet {
         let mut message = Msg::new(msg_info, signed_body);
         if add_state_init {
             let code = WalletVersion::get_code(self.version)?.clone();
-            let data = WalletVersion::get_default_data(self.version, &self.key_pair, self.wallet_id)?;
+            let data =
+                WalletVersion::get_default_data_for_pubkey(self.version, self.public_key.clone(), self.wallet_id)?;
             let state_init = StateInit::new(code, data);
             message.init = Some(TLBEitherRef::new(state_init));
         }
@@ -95,10 +197,18 @@ impl TonWallet {
     }
 }
 
+fn default_wallet_id(version: WalletVersion) -> i32 {
+    match version {
+        WalletVersion::V5R1 => WALLET_V5R1_DEFAULT_ID,
+        _ => WALLET_DEFAULT_ID,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::str::FromStr;
+    use std::sync::Arc;
 
     const MNEMONIC_STR: &str = "fancy carpet hello mandate penalty trial consider property top vicious exit rebuild tragic profit urban major total month holiday sudden rib gather media vicious";
     const MNEMONIC_STR_V5: &str = "section garden tomato dinner season dice renew length useful spin trade intact use universe what post spike keen mandate behind concert egg doll rug";
@@ -128,24 +238,26 @@ mod tests {
         let wallet_v5 = TonWallet::new(WalletVersion::V5R1, key_pair_v5.clone())?;
         let expected_v5 = TonAddress::from_str("UQDv2YSmlrlLH3hLNOVxC8FcQf4F9eGNs4vb2zKma4txo6i3")?;
         assert_eq!(wallet_v5.address, expected_v5);
+        assert_eq!(wallet_v5.key_pair(), &key_pair_v5);
+
+        let arc_wallet = TonWallet::new(WalletVersion::V5R1, Arc::new(key_pair_v5))?;
+        assert_eq!(arc_wallet.address, wallet_v5.address);
         Ok(())
     }
 
     #[test]
     fn test_ton_wallet_debug() -> anyhow::Result<()> {
-        let key_pair = KeyPair {
-            public_key: vec![1, 2, 3],
-            secret_key: vec![4, 5, 6],
-        };
+        let key_pair = KeyPair::new(vec![1, 2, 3], vec![4, 5, 6]);
         let wallet = TonWallet {
-            key_pair,
+            signer: key_pair,
+            public_key: TonHash::ZERO,
             version: WalletVersion::V4R2,
             address: TonAddress::from_str("EQBiMfDMivebQb052Z6yR3jHrmwNhw1kQ5bcAUOBYsK_VPuK")?,
             wallet_id: 42,
         };
 
         let debug_output = format!("{wallet:?}");
-        let expected_output = "TonWallet { version: V4R2, key_pair: KeyPair { public_key: [1, 2, 3], secret_key: \"***REDACTED***\" }, address: TonAddress(\"EQBiMfDMivebQb052Z6yR3jHrmwNhw1kQ5bcAUOBYsK_VPuK\"), wallet_id: 42 }";
+        let expected_output = "TonWallet { version: V4R2, signer: KeyPair { public_key: [1, 2, 3], secret_key: \"***REDACTED***\" }, public_key: TonHash[0000000000000000000000000000000000000000000000000000000000000000], address: TonAddress(\"EQBiMfDMivebQb052Z6yR3jHrmwNhw1kQ5bcAUOBYsK_VPuK\"), wallet_id: 42 }";
         assert_eq!(debug_output, expected_output);
         Ok(())
     }
@@ -256,4 +368,79 @@ mod tests {
         }
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_ton_wallet_external_signer() -> anyhow::Result<()> {
+        let key_pair = make_keypair(MNEMONIC_STR_V5);
+        let wallet = TonWallet::new(WalletVersion::V5R1, key_pair.clone())?;
+        let msg = TonCell::builder().build()?.into_ref();
+        let expected = wallet.create_ext_in_msg(vec![msg.clone()], 3, 1, true)?;
+
+        // signer is unknown to wallet, public key is provided in advance
+        let public_key = TonHash::from_slice(&key_pair.public_key)?;
+        let remote_wallet =
+            TonWallet::new_with_public_key(WalletVersion::V5R1, (), public_key, 0, WALLET_V5R1_DEFAULT_ID)?;
+        assert_eq!(remote_wallet.address, wallet.address);
+        let body = remote_wallet.create_ext_in_body(1, 3, vec![msg.clone()])?;
+        let sign = key_pair.sign(&body.cell_hash()?)?;
+        let signed_body = remote_wallet.sign_ext_in_body_with(&body, &sign)?;
+        assert_eq!(remote_wallet.create_ext_in_msg_from_body(signed_body, true)?, expected);
+        assert!(remote_wallet.sign_ext_in_body_with(&body, &sign[..32]).is_err());
+
+        let async_wallet = TonWallet::new_async(WalletVersion::V5R1, key_pair).await?;
+        assert_eq!(async_wallet.create_ext_in_msg_async(vec![msg], 3, 1, true).await?, expected);
+
+        // deploy without messages
+        let deploy_msg = wallet.create_ext_in_msg(vec![], 0, 1, true)?;
+        assert!(wallet.verify_ext_in_msg(&deploy_msg, &wallet.public_key)?.msgs().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_ton_wallet_verify_ext_in_msg() -> anyhow::Result<()> {
+        let key_pair = make_keypair(MNEMONIC_STR);
+        let key_pair_other = make_keypair(MNEMONIC_STR_V5);
+        let msg = TonCell::builder().build()?.into_ref();
+
+        for version in [
+            WalletVersion::V1R1,
+            WalletVersion::V1R2,
+            WalletVersion::V1R3,
+            WalletVersion::V2R2,
+            WalletVersion::V3R1,
+            WalletVersion::V3R2,
+            WalletVersion::V4R1,
+            WalletVersion::V4R2,
+            WalletVersion::V5R1,
+        ] {
+            let wallet = TonWallet::new(version, key_pair.clone())?;
+            let ext_in_msg = wallet.create_ext_in_msg(vec![msg.clone()], 5, 100, true)?;
+
+            let body = wallet.verify_ext_in_msg(&ext_in_msg, &wallet.public_key)?;
+            assert_eq!(body.msg_seqno(), 5);
+            let expected_valid_until = match version {
+                WalletVersion::V1R1 | WalletVersion::V1R2 | WalletVersion::V1R3 => u32::MAX,
+                _ => 100,
+            };
+            assert_eq!(body.valid_until(), expected_valid_until);
+            assert_eq!(body.msgs(), std::slice::from_ref(&msg));
+
+            let other_pk = Signer::public_key(&key_pair_other)?;
+            let res = WalletVersion::verify_ext_in_msg(version, &ext_in_msg, &other_pk);
+            assert!(matches!(res, Err(TLError::SignatureInvalid(_))));
+
+            let other_wallet = TonWallet::new(version, key_pair_other.clone())?;
+            assert!(other_wallet.verify_ext_in_msg(&ext_in_msg, &wallet.public_key).is_err());
+
+            // signature from different body
+            let body = wallet.create_ext_in_body(100, 6, vec![msg.clone()])?;
+            let (_, sign) = WalletVersion::split_signed_body(version, &wallet.sign_ext_in_body(&body)?)?;
+            let forged_body = wallet.create_ext_in_body(100, 5, vec![msg.clone()])?;
+            let forged_msg =
+                wallet.create_ext_in_msg_from_body(wallet.sign_ext_in_body_with(&forged_body, &sign)?, false)?;
+            let res = WalletVersion::verify_ext_in_msg(version, &forged_msg, &wallet.public_key);
+            assert!(matches!(res, Err(TLError::SignatureInvalid(_))));
+        }
+        Ok(())
+    }
 }
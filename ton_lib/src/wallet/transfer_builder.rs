@@ -0,0 +1,179 @@
+use crate::block_tlb::*;
+use crate::error::TLError;
+use crate::tep::encrypted_comment::EncryptedCommentMsg;
+use crate::tep::text_comment::TextCommentMsg;
+use crate::wallet::KeyPair;
+use ton_lib_core::cell::{TonCell, TonCellRef, TonHash};
+use ton_lib_core::traits::tlb::TLB;
+use ton_lib_core::types::tlb_core::{MsgAddress, TLBEitherRef};
+use ton_lib_core::types::TonAddress;
+
+/// Internal message & its send mode - the item of wallet ext_in body
+#[derive(Debug, Clone, PartialEq)]
+pub struct WalletTransfer {
+    pub msg: TonCellRef,
+    pub mode: SendMode,
+}
+
+impl WalletTransfer {
+    pub fn new(msg: TonCellRef, mode: SendMode) -> Self { Self { msg, mode } }
+}
+
+impl From<TonCellRef> for WalletTransfer {
+    fn from(msg: TonCellRef) -> Self { Self::new(msg, SendMode::DEFAULT) }
+}
+
+/// Builds internal message for [`crate::wallet::TonWallet::create_ext_in_msg_with_transfers`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransferBuilder {
+    dst: TonAddress,
+    bounce: bool,
+    amount: Coins,
+    body: Option<TonCell>,
+    state_init: Option<StateInit>,
+    mode: SendMode,
+}
+
+impl TransferBuilder {
+    /// Message is bounceable by default
+    pub fn new<T: Into<Coins>>(dst: TonAddress, amount: T) -> Self {
+        Self {
+            dst,
+            bounce: true,
+            amount: amount.into(),
+            body: None,
+            state_init: None,
+            mode: SendMode::DEFAULT,
+        }
+    }
+
+    /// Bounce flag is taken from user-friendly address. Raw address is bounceable
+    pub fn from_str<T: Into<Coins>>(dst: &str, amount: T) -> Result<Self, TLError> {
+        let (address, bounce) = TonAddress::from_str_with_bounce(dst)?;
+        Ok(Self::new(address, amount).with_bounce(bounce.unwrap_or(true)))
+    }
+
+    pub fn with_bounce(mut self, bounce: bool) -> Self {
+        self.bounce = bounce;
+        self
+    }
+
+    /// Replaces body with text comment (op = 0)
+    pub fn with_comment(mut self, comment: &str) -> Result<Self, TLError> {
+        self.body = Some(TextCommentMsg::new(comment).to_cell()?);
+        Ok(self)
+    }
+
+    /// Replaces body with comment encrypted for receiver. `sender_address` - address of the sending wallet
+    pub fn with_encrypted_comment(
+        mut self,
+        comment: &str,
+        sender_key_pair: &KeyPair,
+        receiver_public_key: &TonHash,
+        sender_address: &TonAddress,
+    ) -> Result<Self, TLError> {
+        let msg = EncryptedCommentMsg::encrypt(comment, sender_key_pair, receiver_public_key, sender_address)?;
+        self.body = Some(msg.to_cell()?);
+        Ok(self)
+    }
+
+    pub fn with_body(mut self, body: TonCell) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    pub fn with_state_init(mut self, state_init: StateInit) -> Self {
+        self.state_init = Some(state_init);
+        self
+    }
+
+    pub fn with_mode(mut self, mode: SendMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn build_msg(&self) -> Result<Msg, TLError> {
+        if self.mode.contains(SendMode::CARRY_REMAINING_VALUE) && self.mode.contains(SendMode::CARRY_ALL_BALANCE) {
+            return Err(TLError::UnexpectedValue {
+                expected: "only one of CARRY_REMAINING_VALUE and CARRY_ALL_BALANCE".to_string(),
+                actual: format!("{:?}", self.mode),
+            });
+        }
+        let info = CommonMsgInfoInt {
+            ihr_disabled: true,
+            bounce: self.bounce,
+            bounced: false,
+            src: MsgAddress::NONE,
+            dst: self.dst.to_msg_address_int().into(),
+            value: CurrencyCollection::new(*self.amount),
+            ihr_fee: Coins::ZERO,
+            fwd_fee: Coins::ZERO,
+            created_lt: 0,
+            created_at: 0,
+        };
+        let mut msg = Msg::new(info, self.body.clone().unwrap_or(TonCell::EMPTY));
+        msg.init = self.state_init.clone().map(TLBEitherRef::new);
+        Ok(msg)
+    }
+
+    pub fn build(&self) -> Result<WalletTransfer, TLError> {
+        Ok(WalletTransfer::new(self.build_msg()?.to_cell_ref()?, self.mode))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::{Mnemonic, TonWallet, WalletV4ExtMsgBody, WalletVersion};
+    use std::str::FromStr;
+
+    const MNEMONIC_STR: &str = "fancy carpet hello mandate penalty trial consider property top vicious exit rebuild tragic profit urban major total month holiday sudden rib gather media vicious";
+
+    #[test]
+    fn test_transfer_builder_bounce() -> anyhow::Result<()> {
+        let address = TonAddress::from_str("EQDk2VTvn04SUKJrW7rXahzdF8_Qi6utb0wj43InCu9vdjrR")?;
+        let bounceable = TransferBuilder::from_str(&address.to_base64(true, true, true), 1u32)?.build_msg()?;
+        assert!(bounceable.info.as_int().unwrap().bounce);
+        let non_bounceable = TransferBuilder::from_str(&address.to_base64(true, false, true), 1u32)?.build_msg()?;
+        assert!(!non_bounceable.info.as_int().unwrap().bounce);
+        assert_eq!(non_bounceable.dst(), address.to_msg_address());
+        let raw = TransferBuilder::from_str(&address.to_hex(), 1u32)?.build_msg()?;
+        assert!(raw.info.as_int().unwrap().bounce);
+        Ok(())
+    }
+
+    #[test]
+    fn test_transfer_builder_msg() -> anyhow::Result<()> {
+        let address = TonAddress::from_str("EQDk2VTvn04SUKJrW7rXahzdF8_Qi6utb0wj43InCu9vdjrR")?;
+        let state_init = StateInit::new(TonCell::EMPTY.into_ref(), TonCell::EMPTY.into_ref());
+        let mode = SendMode::PAY_FEES_SEPARATELY | SendMode::BOUNCE_ON_ACTION_FAIL;
+        let transfer = TransferBuilder::new(address.clone(), 100u32)
+            .with_comment("hello")?
+            .with_state_init(state_init.clone())
+            .with_mode(mode)
+            .build()?;
+        assert_eq!(transfer.mode, mode);
+
+        let msg = Msg::from_cell(&transfer.msg)?;
+        assert_eq!(*msg.info.as_int().unwrap().value.grams, 100);
+        assert_eq!(msg.state_init(), Some(&state_init));
+        assert_eq!(TextCommentMsg::from_cell(&msg.body.value)?.as_str(), "hello");
+
+        let invalid_mode = SendMode::CARRY_ALL_BALANCE | SendMode::CARRY_REMAINING_VALUE;
+        assert!(TransferBuilder::new(address, 1u32).with_mode(invalid_mode).build().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_transfer_builder_wallet() -> anyhow::Result<()> {
+        let key_pair = Mnemonic::from_str(MNEMONIC_STR, None)?.to_key_pair()?;
+        let wallet = TonWallet::new(WalletVersion::V4R2, key_pair)?;
+        let transfer =
+            TransferBuilder::new(wallet.address.clone(), 1u32).with_mode(SendMode::CARRY_ALL_BALANCE).build()?;
+        let body = wallet.create_ext_in_body_with_transfers(13, 7, vec![transfer.clone()])?;
+        let parsed = WalletV4ExtMsgBody::from_cell(&body)?;
+        assert_eq!(parsed.msgs_modes, vec![128]);
+        assert_eq!(parsed.msgs, vec![transfer.msg]);
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+mod wallet_ext_msg_body;
 mod wallet_ext_msg_utils;
 mod wallet_hl_v2;
 mod wallet_v1_v2;
@@ -5,6 +6,7 @@ mod wallet_v3;
 mod wallet_v4;
 mod wallet_v5;
 
+pub use wallet_ext_msg_body::*;
 pub use wallet_hl_v2::*;
 pub use wallet_v1_v2::*;
 pub use wallet_v3::*;
@@ -0,0 +1,71 @@
+use crate::wallet::*;
+use ton_lib_core::cell::{TonCell, TonCellRef};
+use ton_lib_core::error::TLCoreError;
+use ton_lib_core::traits::tlb::TLB;
+
+/// Unsigned external message body of any supported wallet version
+#[derive(Debug, PartialEq, Clone)]
+pub enum WalletExtMsgBody {
+    V1(WalletV1ExtMsgBody),
+    V2(WalletV2ExtMsgBody),
+    V3(WalletV3ExtMsgBody),
+    V4(WalletV4ExtMsgBody),
+    V5(WalletV5ExtMsgBody),
+}
+
+impl WalletExtMsgBody {
+    /// Expects body without signature
+    pub fn from_cell_by_version(version: WalletVersion, body: &TonCell) -> Result<Self, TLCoreError> {
+        use WalletVersion::*;
+        let parsed = match version {
+            V1R1 | V1R2 | V1R3 => Self::V1(WalletV1ExtMsgBody::from_cell(body)?),
+            V2R1 | V2R2 => Self::V2(WalletV2ExtMsgBody::from_cell(body)?),
+            V3R1 | V3R2 => Self::V3(WalletV3ExtMsgBody::from_cell(body)?),
+            V4R1 | V4R2 => Self::V4(WalletV4ExtMsgBody::from_cell(body)?),
+            V5R1 => Self::V5(WalletV5ExtMsgBody::from_cell(body)?),
+            _ => return Err(TLCoreError::Custom(format!("ext_in_body parsing for {version:?} is unsupported"))),
+        };
+        Ok(parsed)
+    }
+
+    pub fn msg_seqno(&self) -> u32 {
+        match self {
+            Self::V1(body) => body.msg_seqno,
+            Self::V2(body) => body.msg_seqno,
+            Self::V3(body) => body.msg_seqno,
+            Self::V4(body) => body.msg_seqno,
+            Self::V5(body) => body.msg_seqno,
+        }
+    }
+
+    /// V1 has no expiration, u32::MAX is returned
+    pub fn valid_until(&self) -> u32 {
+        match self {
+            Self::V1(_) => u32::MAX,
+            Self::V2(body) => body.valid_until,
+            Self::V3(body) => body.valid_until,
+            Self::V4(body) => body.valid_until,
+            Self::V5(body) => body.valid_until,
+        }
+    }
+
+    pub fn msgs(&self) -> &[TonCellRef] {
+        match self {
+            Self::V1(body) => &body.msgs,
+            Self::V2(body) => &body.msgs,
+            Self::V3(body) => &body.msgs,
+            Self::V4(body) => &body.msgs,
+            Self::V5(body) => &body.msgs,
+        }
+    }
+
+    pub fn msgs_modes(&self) -> &[u8] {
+        match self {
+            Self::V1(body) => &body.msgs_modes,
+            Self::V2(body) => &body.msgs_modes,
+            Self::V3(body) => &body.msgs_modes,
+            Self::V4(body) => &body.msgs_modes,
+            Self::V5(body) => &body.msgs_modes,
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::wallet::wallet_tlb::wallet_ext_msg_utils::{read_up_to_4_msgs, write_up_to_4_msgs};
+use crate::wallet::wallet_tlb::wallet_ext_msg_utils::{read_up_to_4_msgs, validate_msgs_count, write_up_to_4_msgs};
 use ton_lib_core::cell::{CellBuilder, CellParser, TonCellRef, TonHash};
 use ton_lib_core::error::TLCoreError;
 use ton_lib_core::traits::tlb::TLB;
@@ -16,6 +16,42 @@ impl WalletV1V2Data {
     pub fn new(public_key: TonHash) -> Self { Self { seqno: 0, public_key } }
 }
 
+/// V1 has no valid_until and sends at most 1 message
+/// TLB parser doesn't expect signature in cell, check `read_signed()` instead
+#[derive(Debug, PartialEq, Clone)]
+pub struct WalletV1ExtMsgBody {
+    pub msg_seqno: u32,
+    pub msgs_modes: Vec<u8>,
+    pub msgs: Vec<TonCellRef>,
+}
+
+impl TLB for WalletV1ExtMsgBody {
+    fn read_definition(parser: &mut CellParser) -> Result<Self, TLCoreError> {
+        let msg_seqno = TLB::read(parser)?;
+        let (msgs_modes, msgs) = read_up_to_4_msgs(parser)?;
+        validate_msgs_count(&msgs, &msgs_modes, 1)?;
+        Ok(Self {
+            msg_seqno,
+            msgs_modes,
+            msgs,
+        })
+    }
+
+    fn write_definition(&self, dst: &mut CellBuilder) -> Result<(), TLCoreError> {
+        validate_msgs_count(&self.msgs, &self.msgs_modes, 1)?;
+        self.msg_seqno.write(dst)?;
+        write_up_to_4_msgs(dst, &self.msgs, &self.msgs_modes)?;
+        Ok(())
+    }
+}
+
+impl WalletV1ExtMsgBody {
+    pub fn read_signed(parser: &mut CellParser) -> Result<(Self, Vec<u8>), TLCoreError> {
+        let signature = parser.read_bits(512)?;
+        Ok((Self::read(parser)?, signature))
+    }
+}
+
 /// https://docs.ton.org/participate/wallets/contracts#wallet-v2
 /// TLB parser doesn't expect signature in cell, check `read_signed()` instead
 #[derive(Debug, PartialEq, Clone)]
@@ -1,3 +1,4 @@
+use crate::block_tlb::{CommonMsgInfo, Msg, SendMode};
 use crate::error::TLError;
 use crate::wallet::WalletVersion::*;
 use crate::wallet::*;
@@ -32,6 +33,14 @@ impl WalletVersion {
         wallet_id: i32,
     ) -> Result<TonCellRef, TLCoreError> {
         let public_key = TonHash::from_slice(&key_pair.public_key)?;
+        Self::get_default_data_for_pubkey(version, public_key, wallet_id)
+    }
+
+    pub fn get_default_data_for_pubkey(
+        version: WalletVersion,
+        public_key: TonHash,
+        wallet_id: i32,
+    ) -> Result<TonCellRef, TLCoreError> {
         match version {
             V1R1 | V1R2 | V1R3 | V2R1 | V2R2 => WalletV1V2Data::new(public_key).to_cell_ref(),
             V3R1 | V3R2 => WalletV3Data::new(wallet_id, public_key).to_cell_ref(),
@@ -44,6 +53,19 @@ impl WalletVersion {
         }
     }
 
+    pub fn get_public_key(version: WalletVersion, data: &TonCell) -> Result<TonHash, TLCoreError> {
+        match version {
+            V1R1 | V1R2 | V1R3 | V2R1 | V2R2 => Ok(WalletV1V2Data::from_cell(data)?.public_key),
+            V3R1 | V3R2 => Ok(WalletV3Data::from_cell(data)?.public_key),
+            V4R1 | V4R2 => Ok(WalletV4Data::from_cell(data)?.public_key),
+            V5R1 => Ok(WalletV5Data::from_cell(data)?.public_key),
+            HLV2R2 => Ok(WalletHLV2R2Data::from_cell(data)?.public_key),
+            HLV1R1 | HLV1R2 | HLV2 | HLV2R1 => {
+                bail_tl_core!("get_public_key for {version:?} is unsupported");
+            }
+        }
+    }
+
     pub fn get_code(version: WalletVersion) -> Result<&'static TonCellRef, TLCoreError> {
         TON_WALLET_CODE_BY_VERSION
             .get(&version)
@@ -70,11 +92,36 @@ impl WalletVersion {
         wallet_id: i32,
         msgs: Vec<TonCellRef>,
     ) -> Result<TonCell, TLError> {
+        let msgs_modes = vec![SendMode::DEFAULT.bits(); msgs.len()];
+        Self::build_ext_in_body_with_modes(version, valid_until, msg_seqno, wallet_id, msgs, msgs_modes)
+    }
+
+    pub fn build_ext_in_body_with_modes(
+        version: WalletVersion,
+        valid_until: u32,
+        msg_seqno: u32,
+        wallet_id: i32,
+        msgs: Vec<TonCellRef>,
+        msgs_modes: Vec<u8>,
+    ) -> Result<TonCell, TLError> {
+        if msgs.len() != msgs_modes.len() {
+            return Err(TLError::UnexpectedValue {
+                expected: format!("msgs_modes.len() == msgs.len() == {}", msgs.len()),
+                actual: msgs_modes.len().to_string(),
+            });
+        }
         let res = match version {
+            // valid_until is not supported by V1
+            V1R1 | V1R2 | V1R3 => WalletV1ExtMsgBody {
+                msg_seqno,
+                msgs_modes,
+                msgs,
+            }
+            .to_cell(),
             V2R1 | V2R2 => WalletV2ExtMsgBody {
                 msg_seqno,
                 valid_until,
-                msgs_modes: vec![3u8; msgs.len()],
+                msgs_modes,
                 msgs,
             }
             .to_cell(),
@@ -82,7 +129,7 @@ impl WalletVersion {
                 subwallet_id: wallet_id,
                 msg_seqno,
                 valid_until,
-                msgs_modes: vec![3u8; msgs.len()],
+                msgs_modes,
                 msgs,
             }
             .to_cell(),
@@ -91,7 +138,7 @@ impl WalletVersion {
                 valid_until,
                 msg_seqno,
                 opcode: 0,
-                msgs_modes: vec![3u8; msgs.len()],
+                msgs_modes,
                 msgs,
             }
             .to_cell(),
@@ -99,7 +146,7 @@ impl WalletVersion {
                 wallet_id,
                 valid_until,
                 msg_seqno,
-                msgs_modes: vec![3u8; msgs.len()],
+                msgs_modes,
                 msgs,
             }
             .to_cell(),
@@ -108,6 +155,55 @@ impl WalletVersion {
         res.map_err(TLError::from)
     }
 
+    /// Splits signed ext_in body into (unsigned_body, signature)
+    pub fn split_signed_body(version: WalletVersion, signed_body: &TonCell) -> Result<(TonCell, Vec<u8>), TLError> {
+        let mut parser = signed_body.parser();
+        match version {
+            // different order
+            V5R1 => {
+                let unsigned_bits_len =
+                    signed_body.data_bits_len.checked_sub(512).ok_or_else(|| TLError::UnexpectedValue {
+                        expected: "at least 512 bits in signed body".to_string(),
+                        actual: signed_body.data_bits_len.to_string(),
+                    })?;
+                let body = parser.read_cell_slice(0, unsigned_bits_len, 0, signed_body.refs.len())?;
+                let signature = parser.read_bits(512)?;
+                Ok((body, signature))
+            }
+            _ => {
+                let signature = parser.read_bits(512)?;
+                Ok((parser.read_cell()?, signature))
+            }
+        }
+    }
+
+    /// Checks signature of ext_in body against public_key and parses it
+    pub fn verify_ext_in_body(
+        version: WalletVersion,
+        signed_body: &TonCell,
+        public_key: &TonHash,
+    ) -> Result<WalletExtMsgBody, TLError> {
+        let (body, signature) = Self::split_signed_body(version, signed_body)?;
+        verify_signature(&body.cell_hash()?, &signature, public_key)?;
+        Ok(WalletExtMsgBody::from_cell_by_version(version, &body)?)
+    }
+
+    /// Accepts serialized external-in message (`Msg`) to the wallet of specified version
+    pub fn verify_ext_in_msg(
+        version: WalletVersion,
+        msg_cell: &TonCell,
+        public_key: &TonHash,
+    ) -> Result<WalletExtMsgBody, TLError> {
+        let msg = Msg::from_cell(msg_cell)?;
+        if !matches!(msg.info, CommonMsgInfo::ExtIn(_)) {
+            return Err(TLError::UnexpectedValue {
+                expected: "CommonMsgInfo::ExtIn".to_string(),
+                actual: format!("{:?}", msg.info),
+            });
+        }
+        Self::verify_ext_in_body(version, &msg.body.value, public_key)
+    }
+
     pub(super) fn sign_msg(version: WalletVersion, msg_cell: &TonCell, sign: &[u8]) -> Result<TonCell, TLError> {
         match version {
             // different order
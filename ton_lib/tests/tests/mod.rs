@@ -6,4 +6,6 @@ mod test_lite_client;
 mod test_metadata;
 #[cfg(feature = "tonlibjson")]
 mod test_tl_client;
+#[cfg(feature = "tonlibjson")]
+mod test_tx_replay;
 mod utils;
@@ -1,4 +1,4 @@
-use crate::tests::utils::make_tl_client;
+use crate::tests::utils::{make_fixture_provider, make_tl_client};
 use std::str::FromStr;
 use std::time::Duration;
 use ton_lib::contracts::client::contract_client::{ContractClient, ContractClientConfig};
@@ -22,11 +22,22 @@ async fn test_tl_provider() -> anyhow::Result<()> {
     assert_eq!(latest_state.address, usdt_master);
 
     let state_by_tx = tl_provider.load_state(usdt_master.clone(), Some(latest_state.last_tx_id.clone())).await?;
-    assert_eq!(state_by_tx, latest_state);
+    assert_eq!(state_by_tx.last_tx_id, latest_state.last_tx_id);
+    assert_eq!(state_by_tx.code_boc, latest_state.code_boc);
+    assert_eq!(state_by_tx.data_boc, latest_state.data_boc);
+    assert_eq!(state_by_tx.balance, latest_state.balance);
+    let tx_utime = tl_provider.load_tx_utime(usdt_master.clone(), latest_state.last_tx_id.clone()).await?;
+    assert_eq!(state_by_tx.sync_utime, Some(tx_utime));
 
     let bc_config = tl_provider.load_bc_config(None).await?;
     assert!(!bc_config.is_empty());
 
+    let key_block_seqno = tl_provider.load_key_block_seqno(None).await?;
+    assert_eq!(tl_provider.load_key_block_seqno(Some(key_block_seqno)).await?, key_block_seqno);
+    assert_eq!(tl_provider.load_key_block_seqno(Some(key_block_seqno + 1)).await?, key_block_seqno);
+    let key_block_config = tl_provider.load_bc_config(Some(key_block_seqno)).await?;
+    assert_eq!(key_block_config, bc_config);
+
     let lib_id = TonHash::from_str("A9338ECD624CA15D37E4A8D9BF677DDC9B84F0E98F05F2FB84C7AFE332A281B4")?;
     let libs = tl_provider.load_libs(vec![lib_id.clone()], None).await?;
     assert_eq!(libs.len(), 1);
@@ -73,19 +84,30 @@ async fn test_tl_provider() -> anyhow::Result<()> {
     );
     assert_eq!(latest_txs_per_address[&address], tx_id);
 
+    // txs above are committed by mc block 50140309
+    let state = tl_provider.load_state(address.clone(), Some(tx_id.clone())).await?;
+    assert_eq!(state.mc_seqno, Some(50140309));
+    assert_eq!(state.sync_utime, Some(tl_provider.load_tx_utime(address, tx_id).await?));
+
+    let address = TonAddress::from_str("EQBF0nJnIPRNlEtpLUBcfah2b0I7Xf09sGDk7EDZeafhBL1o")?;
+    let tx_id = TxIdLTHash::new(
+        59686385000060,
+        TonHash::from_str("964d5e59d55e99669306b8e3223fed8cc3b5b3440c7005de1276fe0f0be8a644")?,
+    );
+    let state = tl_provider.load_state(address.clone(), Some(tx_id.clone())).await?;
+    assert_eq!(state.mc_seqno, Some(50140309));
+    assert_eq!(state.sync_utime, Some(tl_provider.load_tx_utime(address, tx_id).await?));
     Ok(())
 }
 
 #[tokio::test]
 async fn test_contract_client_tl_provider() -> anyhow::Result<()> {
-    let tl_client = make_tl_client(true, true).await?;
     let config = ContractClientConfig {
         refresh_loop_idle_on_error: Duration::from_millis(100),
         cache_capacity: 1000,
         cache_ttl: Duration::from_secs(3600),
     };
-    let tl_provider = TLProvider::new(tl_client.clone());
-    let ctr_cli = ContractClient::new(config, tl_provider)?;
+    let ctr_cli = ContractClient::new(config, make_fixture_provider()?)?;
     let usdt_master = TonAddress::from_str("EQCxE6mUtQJKFnGfaROTKOt1lZbDiiX1kCixRv7Nw2Id_sDs")?;
 
     assert_eq!(ctr_cli.cache_stats().get("state_latest_req").copied(), Some(0));
@@ -1,9 +1,8 @@
-use crate::tests::utils::make_tl_client;
+use crate::tests::utils::make_fixture_provider;
 use std::str::FromStr;
 use std::time::Duration;
 use tokio_test::assert_ok;
 use ton_lib::contracts::client::contract_client::{ContractClient, ContractClientConfig};
-use ton_lib::contracts::client::tl_provider::TLProvider;
 use ton_lib::contracts::jetton_master::JettonMaster;
 use ton_lib::contracts::jetton_wallet::JettonWallet;
 use ton_lib::contracts::methods::get_jetton_data::GetJettonData;
@@ -16,10 +15,8 @@ use ton_lib_core::types::TonAddress;
 
 #[tokio::test]
 async fn test_contracts() -> anyhow::Result<()> {
-    let tl_client = make_tl_client(true, true).await?;
     let config = ContractClientConfig::new_no_cache(Duration::from_millis(100));
-    let data_provider = TLProvider::new(tl_client.clone());
-    let ctr_cli = ContractClient::new(config, data_provider)?;
+    let ctr_cli = ContractClient::new(config, make_fixture_provider()?)?;
 
     assert_jetton_wallet(&ctr_cli).await?;
     assert_jetton_master(&ctr_cli).await?;
@@ -45,7 +42,8 @@ async fn assert_jetton_master(ctr_cli: &ContractClient) -> anyhow::Result<()> {
 }
 
 async fn assert_wallet_contract(ctr_cli: &ContractClient) -> anyhow::Result<()> {
-    let wallet = TonAddress::from_str("UQAj-peZGPH-cC25EAv4Q-h8cBXszTmkch6ba6wXC8BM40qt")?;
+    // UQAj-peZGPH-cC25EAv4Q-h8cBXszTmkch6ba6wXC8BM40qt state isn't available offline, using wallet_v4r2.json fixture
+    let wallet = TonAddress::from_str("EQCS65EGyiApUTLOYXDs4jOLoQNCE0o8oNnkmfIcm0iX5AmW")?;
     let contract = TonWalletContract::new(ctr_cli, wallet, None).await?;
     let seqno = contract.seqno().await?;
     assert!(seqno > 0);
@@ -1,8 +1,11 @@
 use crate::tests::utils::make_lite_client;
 use std::str::FromStr;
+use ton_lib::block_tlb::Tx;
 use ton_lib::error::TLError;
 use ton_lib::unwrap_lite_response;
-use ton_lib_core::types::TonAddress;
+use ton_lib_core::cell::TonHash;
+use ton_lib_core::traits::tlb::TLB;
+use ton_lib_core::types::{TonAddress, TxIdLTHash};
 use ton_liteapi::tl::request::Request;
 use ton_liteapi::tl::response::Response;
 
@@ -29,6 +32,31 @@ async fn test_lite_client() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_lite_client_txs_and_config() -> anyhow::Result<()> {
+    let lite_client = make_lite_client(true).await?;
+    let address = TonAddress::from_str("EQBF0nJnIPRNlEtpLUBcfah2b0I7Xf09sGDk7EDZeafhBL1o")?;
+    let tx_id = TxIdLTHash::new(
+        59686385000060,
+        TonHash::from_str("964d5e59d55e99669306b8e3223fed8cc3b5b3440c7005de1276fe0f0be8a644")?,
+    );
+    let txs = lite_client.get_txs(&address, &tx_id, 2, None).await?;
+    assert_eq!(txs.len(), 2);
+    let (block_id, tx_cell) = &txs[0];
+    assert_eq!(tx_cell.hash()?, &tx_id.hash);
+    let tx = Tx::from_cell(tx_cell)?;
+    assert_eq!(tx.lt as i64, tx_id.lt);
+    assert_eq!(txs[1].0.shard_ident.workchain, block_id.shard_ident.workchain);
+
+    let account = lite_client.get_account_state_by_block(&address, block_id, None).await?;
+    assert!(account.as_account().is_some());
+
+    let mc_seqno = lite_client.get_mc_info().await?.last.seqno;
+    let config = lite_client.get_config(mc_seqno, None).await?;
+    assert!(config.global_version()?.version > 0);
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_lite_client_testnet() -> anyhow::Result<()> {
     let lite_client = make_lite_client(false).await?;
@@ -0,0 +1,24 @@
+use crate::tests::utils::make_lite_client;
+use std::str::FromStr;
+use ton_lib::emulators::tx::tx_replay::TXReplayer;
+use ton_lib_core::cell::TonHash;
+use ton_lib_core::types::{TonAddress, TxIdLTHash};
+
+#[tokio::test]
+async fn test_tx_replay() -> anyhow::Result<()> {
+    let lite_client = make_lite_client(true).await?;
+    ton_lib::sys_utils::sys_tonlib_set_verbosity_level(0);
+    let replayer = TXReplayer::new(lite_client).with_debug_enabled(true);
+
+    let address = TonAddress::from_str("EQBF0nJnIPRNlEtpLUBcfah2b0I7Xf09sGDk7EDZeafhBL1o")?;
+    let tx_id = TxIdLTHash::new(
+        59686385000060,
+        TonHash::from_str("964d5e59d55e99669306b8e3223fed8cc3b5b3440c7005de1276fe0f0be8a644")?,
+    );
+    let result = replayer.replay_tx(&address, &tx_id).await?;
+    assert_eq!(result.expected_hash, tx_id.hash);
+    assert!(result.state_update_matched());
+    assert!(result.hash_matched());
+    assert!(!result.vm_log.is_empty());
+    Ok(())
+}
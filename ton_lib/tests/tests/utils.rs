@@ -47,6 +47,13 @@ pub(crate) async fn make_tl_client(
     Ok(client)
 }
 
+/// Offline provider with USDT master, USDT jetton wallet & wallet v4r2 states
+#[cfg(feature = "tonlibjson")]
+pub(crate) fn make_fixture_provider() -> anyhow::Result<ton_lib::contracts::client::fixture_provider::FixtureProvider> {
+    let fixture_dir = concat!(env!("CARGO_MANIFEST_DIR"), "/resources/tests/contract_client");
+    Ok(ton_lib::contracts::client::fixture_provider::FixtureProvider::from_dir(fixture_dir)?)
+}
+
 pub async fn make_lite_client(mainnet: bool) -> anyhow::Result<LiteClient> {
     init_logging();
     log::info!("initializing lite_client with mainnet={mainnet}...");
@@ -56,6 +56,8 @@ impl BOC {
         Ok(BASE64_STANDARD.encode(self.to_bytes(add_crc32)?))
     }
 
+    pub fn into_roots(self) -> TonCellStorage { self.roots }
+
     pub fn single_root(mut self) -> Result<TonCellRef, TLCoreError> {
         if self.roots.len() != 1 {
             return Err(TLCoreError::BOCSingleRoot(self.roots.len()));
@@ -30,6 +30,27 @@ impl TonCellUtils {
         Ok(result)
     }
 
+    /// Counts unique cells and their data bits over cell trees: (cells, bits)
+    pub fn storage_stat<'a, I>(cells_iter: I) -> Result<(u64, u64), TLCoreError>
+    where
+        I: IntoIterator<Item = &'a TonCell>,
+    {
+        let (mut cells, mut bits) = (0, 0);
+        let mut visited = HashSet::new();
+
+        let mut queue = VecDeque::from_iter(cells_iter);
+
+        while let Some(cell) = queue.pop_front() {
+            if !visited.insert(cell.hash()?) {
+                continue;
+            }
+            cells += 1;
+            bits += cell.data_bits_len as u64;
+            queue.extend(cell.refs.iter().map(Deref::deref));
+        }
+        Ok((cells, bits))
+    }
+
     // Read lib_ids from library_cell
     pub fn read_lib_id(cell: &TonCell) -> Result<Option<TonHash>, TLCoreError> {
         if cell.cell_type != CellType::LibraryRef {
@@ -82,4 +103,21 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn test_storage_stat() -> Result<(), TLCoreError> {
+        let mut builder = TonCell::builder();
+        builder.write_num(&1u8, 5)?;
+        let leaf = builder.build()?.into_ref();
+        let mut builder = TonCell::builder();
+        builder.write_num(&7u16, 11)?;
+        builder.write_ref(leaf.clone())?;
+        builder.write_ref(leaf.clone())?;
+        let root = builder.build()?;
+
+        assert_eq!(TonCellUtils::storage_stat([&root])?, (2, 16));
+        assert_eq!(TonCellUtils::storage_stat([leaf.deref(), &root])?, (2, 16));
+        assert_eq!(TonCellUtils::storage_stat(root.refs.iter().map(Deref::deref))?, (1, 5));
+        Ok(())
+    }
 }
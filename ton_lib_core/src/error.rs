@@ -70,6 +70,8 @@ pub enum TLCoreError {
     // contracts
     #[error("ContractError: {0}")]
     ContractError(String),
+    #[error("ProviderUnsupported: {0}")]
+    ProviderUnsupported(String),
 
     // General errors
     #[error("Custom: {0}")]
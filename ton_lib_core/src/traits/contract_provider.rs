@@ -12,10 +12,28 @@ pub trait ContractProvider: Send + Sync + 'static {
     async fn load_state(&self, address: TonAddress, tx_id: Option<TxIdLTHash>) -> Result<ContractState, TLCoreError>;
     /// load latest blockchain config if mc_seqno is None
     async fn load_bc_config(&self, mc_seqno: Option<u32>) -> Result<Vec<u8>, TLCoreError>;
+    /// seqno of the latest key block at mc_seqno (mc_seqno itself if it's a key block), latest if mc_seqno is None
+    ///
+    /// Optional: callers fall back to load_bc_config(mc_seqno) on ProviderUnsupported
+    async fn load_key_block_seqno(&self, mc_seqno: Option<u32>) -> Result<u32, TLCoreError> {
+        Err(TLCoreError::ProviderUnsupported(format!("load_key_block_seqno for mc_seqno {mc_seqno:?}")))
+    }
     
     async fn load_libs(&self, lib_ids: Vec<TonHash>, mc_seqno: Option<u32>) -> Result<Vec<(TonHash, Vec<u8>)>, TLCoreError>;
     
     async fn load_latest_tx_per_address(&self, mc_seqno: u32) -> Result<HashMap<TonAddress, TxIdLTHash>, TLCoreError>;
+    /// unix time of the tx. Storage fees are charged in every tx, so it's also account last_paid after it
+    ///
+    /// Optional: emulation falls back to state sync_utime on ProviderUnsupported
+    async fn load_tx_utime(&self, address: TonAddress, tx_id: TxIdLTHash) -> Result<u32, TLCoreError> {
+        Err(TLCoreError::ProviderUnsupported(format!("load_tx_utime for {address} at tx_id {tx_id:?}")))
+    }
+    /// masterchain block used to build emulation context, latest if mc_seqno is None
+    ///
+    /// Optional: emulation falls back to current time & no prev blocks on ProviderUnsupported
+    async fn load_mc_block_info(&self, mc_seqno: Option<u32>) -> Result<McBlockInfo, TLCoreError> {
+        Err(TLCoreError::ProviderUnsupported(format!("load_mc_block_info for mc_seqno {mc_seqno:?}")))
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
@@ -27,4 +45,17 @@ pub struct ContractState {
     pub data_boc: Option<Vec<u8>>,
     pub frozen_hash: Option<TonHash>,
     pub balance: i64,
+    /// unix time of the block the state is taken from, None if unknown
+    pub sync_utime: Option<u32>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct McBlockInfo {
+    pub seqno: u32,
+    pub root_hash: TonHash,
+    pub file_hash: TonHash,
+    pub gen_utime: u32,
+    pub prev_key_block_seqno: u32,
+    /// None if provider doesn't expose it (e.g. tonlib & toncenter), emulators use root_hash instead
+    pub rand_seed: Option<TonHash>,
 }
@@ -3,6 +3,7 @@ mod tlb_cell;
 mod tlb_num;
 mod tlb_opt;
 mod tlb_ptr;
+mod tlb_unit;
 
 use crate::boc::BOC;
 use crate::cell::CellBuilder;
@@ -0,0 +1,10 @@
+use crate::cell::CellBuilder;
+use crate::cell::CellParser;
+use crate::error::TLCoreError;
+use crate::traits::tlb::TLB;
+
+/// `unit$_ = Unit;` / `true$_ = True;` - used as value in dicts which work as sets
+impl TLB for () {
+    fn read_definition(_parser: &mut CellParser) -> Result<Self, TLCoreError> { Ok(()) }
+    fn write_definition(&self, _builder: &mut CellBuilder) -> Result<(), TLCoreError> { Ok(()) }
+}
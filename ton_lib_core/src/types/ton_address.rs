@@ -42,6 +42,25 @@ impl TonAddress {
         }
     }
 
+    /// Returns bounce flag from user-friendly address tag. Raw (hex) address has no flag
+    pub fn from_str_with_bounce(s: &str) -> Result<(Self, Option<bool>), TLCoreError> {
+        if s.len() != 48 {
+            return Ok((from_hex(s)?, None));
+        }
+        let bytes = decode_base64(s)?;
+        let address = from_bytes(&bytes, s)?;
+        // 0x80 is testnet flag
+        let bounce = match bytes[0] & 0x7f {
+            0x11 => true,
+            0x51 => false,
+            tag => {
+                let err_msg = format!("unexpected tag {tag:#x}");
+                return Err(TLCoreError::TonAddressParseError(s.to_string(), err_msg));
+            }
+        };
+        Ok((address, Some(bounce)))
+    }
+
     pub fn to_hex(&self) -> String { format!("{}:{}", self.workchain, hex::encode(self.hash.as_slice())) }
 
     pub fn to_base64(&self, mainnet: bool, bounce: bool, urlsafe: bool) -> String {
@@ -143,10 +162,14 @@ impl TLB for TonAddress {
 
 fn from_base64<T: AsRef<str>>(addr: T) -> Result<TonAddress, TLCoreError> {
     let addr_str = addr.as_ref();
+    from_bytes(&decode_base64(addr_str)?, addr_str)
+}
+
+fn decode_base64(addr_str: &str) -> Result<Vec<u8>, TLCoreError> {
     if addr_str.chars().any(|c| c == '-' || c == '_') {
-        from_bytes(&URL_SAFE_NO_PAD.decode(addr_str)?, addr_str)
+        Ok(URL_SAFE_NO_PAD.decode(addr_str)?)
     } else {
-        from_bytes(&STANDARD.decode(addr_str)?, addr_str)
+        Ok(STANDARD.decode(addr_str)?)
     }
 }
 
@@ -254,6 +277,18 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_ton_address_from_str_with_bounce() -> anyhow::Result<()> {
+        let addr = TonAddress::from_str("0:e4d954ef9f4e1250a26b5bbad76a1cdd17cfd08babad6f4c23e372270aef6f76")?;
+        let raw = "0:e4d954ef9f4e1250a26b5bbad76a1cdd17cfd08babad6f4c23e372270aef6f76";
+        assert_eq!(TonAddress::from_str_with_bounce(raw)?, (addr.clone(), None));
+        for (mainnet, bounce) in [(true, true), (true, false), (false, true), (false, false)] {
+            let friendly = addr.to_base64(mainnet, bounce, true);
+            assert_eq!(TonAddress::from_str_with_bounce(&friendly)?, (addr.clone(), Some(bounce)));
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_ton_address_crc_error() -> anyhow::Result<()> {
         assert_err!(TonAddress::from_str("EQDk2VTvn04SUKJrW7rXahzdF8_Qi6utb0wj43InCu9vdjra"));
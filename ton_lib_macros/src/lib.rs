@@ -11,6 +11,9 @@ use proc_macro::TokenStream;
 #[proc_macro_derive(TLBDerive, attributes(tlb_derive))]
 pub fn tlb_derive(input: TokenStream) -> TokenStream { tlb_derive_impl(input).into() }
 
-/// Automatic `TonContract` implementation
+/// Automatic `TonContract` implementation for structs
+///
+/// For traits, generates get-methods from declarations like
+/// `#[get_method("get_wallet_address")] async fn get_wallet_address(&self, owner: &TonAddress) -> GetWalletAddressResult;`
 #[proc_macro_attribute]
 pub fn ton_contract(_attr: TokenStream, item: TokenStream) -> TokenStream { ton_contract_impl(_attr, item) }
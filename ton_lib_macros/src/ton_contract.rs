@@ -1,16 +1,10 @@
 use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
 use proc_macro_crate::{crate_name, FoundCrate};
 use quote::{format_ident, quote};
-use syn::{parse_macro_input, FieldsNamed, ItemStruct};
+use syn::{parse_macro_input, FieldsNamed, FnArg, Item, ItemStruct, ItemTrait, LitStr, Pat, ReturnType, TraitItem};
 
 pub(crate) fn ton_contract_impl(_attr: TokenStream, item: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(item as ItemStruct);
-    let struct_name = &input.ident;
-    let vis = &input.vis;
-    let attrs = &input.attrs;
-    let generics = &input.generics;
-    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
-
     let found_crate = crate_name("ton_lib").expect("ton_lib crate not found");
 
     let crate_path = match found_crate {
@@ -21,6 +15,20 @@ pub(crate) fn ton_contract_impl(_attr: TokenStream, item: TokenStream) -> TokenS
         }
     };
 
+    match parse_macro_input!(item as Item) {
+        Item::Struct(input) => ton_contract_struct(input, crate_path),
+        Item::Trait(input) => ton_contract_trait(input, crate_path),
+        _ => panic!("ton_contract supports only structs and traits"),
+    }
+}
+
+fn ton_contract_struct(input: ItemStruct, crate_path: TokenStream2) -> TokenStream {
+    let struct_name = &input.ident;
+    let vis = &input.vis;
+    let attrs = &input.attrs;
+    let generics = &input.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
     let mut new_fields = input.fields.clone();
     if let syn::Fields::Unnamed(_) = &new_fields {
         panic!("ton_contract derive does not support tuple structs, use named fields instead");
@@ -59,3 +67,56 @@ pub(crate) fn ton_contract_impl(_attr: TokenStream, item: TokenStream) -> TokenS
     };
     output.into()
 }
+
+/// Generates default implementations for `#[get_method]` declarations:
+/// arguments are pushed to the stack in order, result is parsed with `TVMResult`
+fn ton_contract_trait(mut input: ItemTrait, crate_path: TokenStream2) -> TokenStream {
+    input.supertraits.push(syn::parse_quote! { #crate_path::contracts::ton_contract::TonContract });
+
+    for item in &mut input.items {
+        let TraitItem::Fn(method) = item else {
+            continue;
+        };
+        let Some(attr_pos) = method.attrs.iter().position(|x| x.path().is_ident("get_method")) else {
+            continue;
+        };
+        let attr = method.attrs.remove(attr_pos);
+        let method_name = match &attr.meta {
+            syn::Meta::Path(_) => method.sig.ident.to_string(),
+            _ => attr.parse_args::<LitStr>().expect("expected #[get_method(\"method_name\")]").value(),
+        };
+        if method.sig.asyncness.is_none() || method.default.is_some() {
+            panic!("get_method {method_name} must be declared as async fn without body");
+        }
+        let result_type = match &method.sig.output {
+            ReturnType::Type(_, result_type) => result_type.clone(),
+            ReturnType::Default => panic!("get_method {method_name} must declare result type"),
+        };
+
+        let mut arg_names = vec![];
+        for arg in &method.sig.inputs {
+            match arg {
+                FnArg::Receiver(_) => {}
+                FnArg::Typed(arg) => match arg.pat.as_ref() {
+                    Pat::Ident(pat) => arg_names.push(pat.ident.clone()),
+                    _ => panic!("get_method {method_name} arguments must be simple identifiers"),
+                },
+            }
+        }
+
+        method.sig.output = syn::parse_quote! { -> Result<#result_type, #crate_path::error::TLError> };
+        method.default = Some(syn::parse_quote! {{
+            let mut stack = #crate_path::block_tlb::TVMStack::default();
+            #(#crate_path::block_tlb::TVMStackArg::push_to(&#arg_names, &mut stack)?;)*
+            let stack_boc = #crate_path::contracts::ton_contract::TonContract::emulate_get_method(self, #method_name, &stack).await?;
+            Ok(<#result_type as #crate_path::ton_lib_core::traits::tvm_result::TVMResult>::from_boc(&stack_boc)?)
+        }});
+        method.semi_token = None;
+    }
+
+    let output = quote! {
+        #[#crate_path::contracts::ton_contract::async_trait]
+        #input
+    };
+    output.into()
+}